@@ -312,6 +312,18 @@ declare_lint! {
     "warn about documentation intra links resolution failure"
 }
 
+declare_lint! {
+    pub INTRA_DOC_LINK_TO_DEPRECATED,
+    Warn,
+    "warn about documentation intra links pointing to deprecated items"
+}
+
+declare_lint! {
+    pub PUBLIC_TYPE_IN_PRIVATE_MODULE,
+    Warn,
+    "warn about types used in the public API which are only reachable through a private module"
+}
+
 declare_lint! {
     pub WHERE_CLAUSES_OBJECT_SAFETY,
     Warn,
@@ -395,6 +407,8 @@ impl LintPass for HardwiredLints {
             DUPLICATE_ASSOCIATED_TYPE_BINDINGS,
             DUPLICATE_MACRO_EXPORTS,
             INTRA_DOC_LINK_RESOLUTION_FAILURE,
+            INTRA_DOC_LINK_TO_DEPRECATED,
+            PUBLIC_TYPE_IN_PRIVATE_MODULE,
             WHERE_CLAUSES_OBJECT_SAFETY,
             PROC_MACRO_DERIVE_RESOLUTION_FALLBACK,
             MACRO_USE_EXTERN_CRATE,
@@ -184,6 +184,7 @@ impl<'a, 'tcx, 'rcx, 'cstore> AutoTraitFinder<'a, 'tcx, 'rcx, 'cstore> {
                     polarity,
                     synthetic: true,
                     blanket_impl: None,
+                    blanket_bounds: Vec::new(),
                 }),
             });
         }
@@ -8,10 +8,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use rustc::hir;
 use rustc::traits;
 use rustc::ty::ToPredicate;
-use rustc::ty::subst::Subst;
+use rustc::ty::fold::{BottomUpFolder, TypeFoldable};
+use rustc::ty::subst::{Kind, Subst, Substs, UnpackedKind};
 use rustc::infer::InferOk;
 use syntax_pos::DUMMY_SP;
 
@@ -65,12 +65,16 @@ impl<'a, 'tcx, 'rcx, 'cstore> BlanketImplFinder <'a, 'tcx, 'rcx, 'cstore> {
             return impls;
         }
         let ty = self.cx.tcx.type_of(def_id);
-        if self.cx.access_levels.borrow().is_doc_reachable(def_id) || ty.is_primitive() {
+        // The privacy pass marks the types used in the associated types of the public impls as
+        // reachable, so the types only reachable as such still get their blanket impls.
+        if self.cx.access_levels.borrow().is_reachable(def_id) || ty.is_primitive() {
             let generics = self.cx.tcx.generics_of(def_id);
             let real_name = name.clone().map(|name| Ident::from_str(&name));
             let param_env = self.cx.tcx.param_env(def_id);
             for &trait_def_id in self.cx.all_traits.iter() {
+                // A hidden trait stays hidden when it's publicly re-exported, like the type.
                 if !self.cx.access_levels.borrow().is_doc_reachable(trait_def_id) ||
+                   self.cx.tcx.get_attrs(trait_def_id).lists("doc").has_word("hidden") ||
                    self.cx.generated_synthetics
                           .borrow_mut()
                           .get(&(def_id, trait_def_id))
@@ -93,6 +97,7 @@ impl<'a, 'tcx, 'rcx, 'cstore> BlanketImplFinder <'a, 'tcx, 'rcx, 'cstore> {
                         let param_env = param_env.subst(infcx.tcx, substs);
 
                         let impl_substs = infcx.fresh_substs_for_item(DUMMY_SP, impl_def_id);
+                        let impl_trait_ref = trait_ref;
                         let trait_ref = trait_ref.subst(infcx.tcx, impl_substs);
 
                         // Require the type the impl is implemented on to match
@@ -114,12 +119,52 @@ impl<'a, 'tcx, 'rcx, 'cstore> BlanketImplFinder <'a, 'tcx, 'rcx, 'cstore> {
                             }
                             self.cx.generated_synthetics.borrow_mut()
                                                         .insert((def_id, trait_def_id));
-                            let trait_ = hir::TraitRef {
-                                path: get_path_for_type(infcx.tcx,
-                                                        trait_def_id,
-                                                        hir::def::Def::Trait),
-                                ref_id: ast::DUMMY_NODE_ID,
+
+                            // Display the trait with the arguments it gets for this type: the
+                            // impl parameters which were unified with the type are replaced,
+                            // the other ones are kept as-is.
+                            let item_params = Substs::identity_for_item(infcx.tcx, def_id);
+                            let resolved_substs = Substs::for_item(infcx.tcx, impl_def_id,
+                                                                   |param, _| {
+                                let kind = infcx.resolve_type_vars_if_possible(
+                                    &impl_substs[param.index as usize]);
+                                let kind = kind.fold_with(&mut BottomUpFolder {
+                                    tcx: infcx.tcx,
+                                    fldop: |ty| {
+                                        // Go back from the fresh variables of the type to its
+                                        // own generic parameters.
+                                        substs.iter()
+                                              .zip(item_params.iter())
+                                              .find(|&(fresh, _)| *fresh == Kind::from(ty))
+                                              .and_then(|(_, param)| match param.unpack() {
+                                                  UnpackedKind::Type(param) => Some(param),
+                                                  _ => None,
+                                              })
+                                              .unwrap_or(ty)
+                                    },
+                                    reg_op: |r| r,
+                                });
+                                if kind.needs_infer() {
+                                    infcx.tcx.mk_param_from_def(param)
+                                } else {
+                                    kind
+                                }
+                            });
+                            let trait_ = match impl_trait_ref.subst(infcx.tcx, resolved_substs)
+                                                             .clean(self.cx) {
+                                GenericBound::TraitBound(poly_trait, _) => poly_trait.trait_,
+                                GenericBound::Outlives(..) => unreachable!(),
                             };
+                            // The associated types are given for this type, like the trait.
+                            let items = infcx.tcx.associated_items(impl_def_id).map(|assoc| {
+                                let mut item = assoc.clean(self.cx);
+                                if let TypedefItem(ref mut tydef, true) = item.inner {
+                                    tydef.type_ = infcx.tcx.type_of(assoc.def_id)
+                                                           .subst(infcx.tcx, resolved_substs)
+                                                           .clean(self.cx);
+                                }
+                                item
+                            }).collect();
                             let provided_trait_methods =
                                 infcx.tcx.provided_trait_methods(trait_def_id)
                                          .into_iter()
@@ -128,6 +173,29 @@ impl<'a, 'tcx, 'rcx, 'cstore> BlanketImplFinder <'a, 'tcx, 'rcx, 'cstore> {
 
                             let ty = self.cx.get_real_ty(def_id, def_ctor, &real_name, generics);
                             let predicates = infcx.tcx.predicates_of(impl_def_id);
+                            // The bounds which make the impl apply to this type.
+                            let sized_trait = infcx.tcx.lang_items().sized_trait();
+                            let blanket_bounds = predicates.predicates.iter().filter(|pred| {
+                                match **pred {
+                                    ty::Predicate::Trait(ref pred) => {
+                                        pred.skip_binder().self_ty() ==
+                                            impl_trait_ref.self_ty() &&
+                                        Some(pred.def_id()) != sized_trait
+                                    }
+                                    _ => false,
+                                }
+                            }).map(|pred| {
+                                pred.subst(infcx.tcx, resolved_substs).clean(self.cx)
+                            }).collect();
+                            // Nightly users may rely on the impls of unstable traits, which are
+                            // marked as such with `--document-unstable-impls`.
+                            let stability = if self.cx.document_unstable_impls {
+                                get_stability(self.cx, trait_def_id).filter(|stab| {
+                                    stab.level == stability::Unstable
+                                })
+                            } else {
+                                None
+                            };
 
                             impls.push(Item {
                                 source: infcx.tcx.def_span(impl_def_id).clean(self.cx),
@@ -135,21 +203,21 @@ impl<'a, 'tcx, 'rcx, 'cstore> BlanketImplFinder <'a, 'tcx, 'rcx, 'cstore> {
                                 attrs: Default::default(),
                                 visibility: None,
                                 def_id: self.cx.next_def_id(impl_def_id.krate),
-                                stability: None,
+                                stability,
                                 deprecation: None,
                                 inner: ImplItem(Impl {
-                                    unsafety: hir::Unsafety::Normal,
+                                    unsafety: infcx.tcx.trait_def(trait_def_id).unsafety,
                                     generics: (t_generics, &predicates).clean(self.cx),
                                     provided_trait_methods,
-                                    trait_: Some(trait_.clean(self.cx)),
+                                    trait_: Some(trait_),
                                     for_: ty.clean(self.cx),
-                                    items: infcx.tcx.associated_items(impl_def_id)
-                                                    .collect::<Vec<_>>()
-                                                    .clean(self.cx),
-                                    polarity: None,
+                                    items,
+                                    polarity: Some(infcx.tcx.impl_polarity(impl_def_id)
+                                                            .clean(self.cx)),
                                     synthetic: false,
                                     blanket_impl: Some(infcx.tcx.type_of(impl_def_id)
                                                                 .clean(self.cx)),
+                                    blanket_bounds,
                                 }),
                             });
                         }
@@ -136,6 +136,29 @@ impl Cfg {
         }
     }
 
+    /// Removes the configurations hidden by `#![doc(cfg_hide(...))]` from the requirements.
+    ///
+    /// Returns `None` if there is nothing left to display.
+    pub(crate) fn without_hidden(&self, hidden: &[Cfg]) -> Option<Cfg> {
+        if hidden.contains(self) {
+            return None;
+        }
+        match *self {
+            Cfg::All(ref sub_cfgs) => {
+                let mut remaining = sub_cfgs.iter()
+                                            .filter(|sub_cfg| !hidden.contains(sub_cfg))
+                                            .cloned()
+                                            .collect::<Vec<_>>();
+                match remaining.len() {
+                    0 => None,
+                    1 => remaining.pop(),
+                    _ => Some(Cfg::All(remaining)),
+                }
+            }
+            _ => Some(self.clone()),
+        }
+    }
+
     /// Renders the configuration for human display, as a short HTML description.
     pub(crate) fn render_short_html(&self) -> String {
         let mut msg = Html(self, true).to_string();
@@ -828,4 +851,27 @@ mod test {
             );
         })
     }
+
+    #[test]
+    fn test_without_hidden() {
+        with_globals(|| {
+            let hidden = [name_value_cfg("feature", "std")];
+
+            assert_eq!(name_value_cfg("feature", "std").without_hidden(&hidden), None);
+            assert_eq!(word_cfg("unix").without_hidden(&hidden), Some(word_cfg("unix")));
+            assert_eq!(
+                (name_value_cfg("feature", "std") & word_cfg("unix")).without_hidden(&hidden),
+                Some(word_cfg("unix"))
+            );
+            assert_eq!(
+                (name_value_cfg("feature", "std") & word_cfg("unix") & word_cfg("windows"))
+                    .without_hidden(&hidden),
+                Some(word_cfg("unix") & word_cfg("windows"))
+            );
+            assert_eq!(
+                (name_value_cfg("feature", "std") | word_cfg("unix")).without_hidden(&hidden),
+                Some(name_value_cfg("feature", "std") | word_cfg("unix"))
+            );
+        })
+    }
 }
@@ -13,7 +13,7 @@
 use std::iter::once;
 
 use syntax::ast;
-use syntax::ext::base::MacroKind;
+use syntax::ext::base::{MacroKind, SyntaxExtension};
 use syntax_pos::Span;
 
 use rustc::hir;
@@ -106,15 +106,15 @@ pub fn try_inline(cx: &DocContext, def: Def, name: ast::Name, visited: &mut FxHa
             record_extern_fqn(cx, did, clean::TypeKind::Const);
             clean::ConstantItem(build_const(cx, did))
         }
-        // FIXME(misdreavus): if attributes/derives come down here we should probably document them
-        // separately
-        Def::Macro(did, MacroKind::Bang) => {
-            record_extern_fqn(cx, did, clean::TypeKind::Macro);
-            if let Some(mac) = build_macro(cx, did, name) {
-                clean::MacroItem(mac)
-            } else {
-                return None;
-            }
+        Def::Macro(did, kind) => {
+            let type_kind = match kind {
+                MacroKind::Bang => clean::TypeKind::Macro,
+                MacroKind::Attr => clean::TypeKind::Attr,
+                MacroKind::Derive => clean::TypeKind::Derive,
+                MacroKind::ProcMacroStub => return None,
+            };
+            record_extern_fqn(cx, did, type_kind);
+            build_macro(cx, did, name)
         }
         _ => return None,
     };
@@ -173,10 +173,11 @@ pub fn record_extern_fqn(cx: &DocContext, did: DefId, kind: clean::TypeKind) {
             None
         }
     });
-    let fqn = if let clean::TypeKind::Macro = kind {
-        vec![crate_name, relative.last().expect("relative was empty")]
-    } else {
-        once(crate_name).chain(relative).collect()
+    let fqn = match kind {
+        clean::TypeKind::Macro | clean::TypeKind::Attr | clean::TypeKind::Derive => {
+            vec![crate_name, relative.last().expect("relative was empty")]
+        }
+        _ => once(crate_name).chain(relative).collect(),
     };
     cx.renderinfo.borrow_mut().external_paths.insert(did, (fqn, kind));
 }
@@ -188,7 +189,7 @@ pub fn build_external_trait(cx: &DocContext, did: DefId) -> clean::Trait {
     let generics = (cx.tcx.generics_of(did), &predicates).clean(cx);
     let generics = filter_non_trait_generics(did, generics);
     let (generics, supertrait_bounds) = separate_supertrait_bounds(generics);
-    let is_spotlight = load_attrs(cx, did).has_doc_flag("spotlight");
+    let is_spotlight = load_attrs(cx, did).is_notable_trait();
     let is_auto = cx.tcx.trait_is_auto(did);
     clean::Trait {
         auto: auto_trait,
@@ -198,6 +199,7 @@ pub fn build_external_trait(cx: &DocContext, did: DefId) -> clean::Trait {
         bounds: supertrait_bounds,
         is_spotlight,
         is_auto,
+        object_safety_violations: clean::object_safety_violations(cx, did),
     }
 }
 
@@ -211,9 +213,12 @@ fn build_external_function(cx: &DocContext, did: DefId) -> clean::Function {
     };
 
     let predicates = cx.tcx.predicates_of(did);
+    let (generics, decl) = clean::enter_impl_trait(cx, || {
+        ((cx.tcx.generics_of(did), &predicates).clean(cx), (did, sig).clean(cx))
+    });
     clean::Function {
-        decl: (did, sig).clean(cx),
-        generics: (cx.tcx.generics_of(did), &predicates).clean(cx),
+        decl,
+        generics,
         header: hir::FnHeader {
             unsafety: sig.unsafety(),
             abi: sig.abi(),
@@ -416,9 +421,15 @@ pub fn build_impl(cx: &DocContext, did: DefId, ret: &mut Vec<clean::Item>) {
            .collect()
     }).unwrap_or(FxHashSet());
 
+    // Only positive impls of an `unsafe trait` are `unsafe` themselves.
+    let unsafety = match (trait_.def_id(), polarity) {
+        (Some(trait_did), hir::ImplPolarity::Positive) => tcx.trait_def(trait_did).unsafety,
+        _ => hir::Unsafety::Normal,
+    };
+
     ret.push(clean::Item {
         inner: clean::ImplItem(clean::Impl {
-            unsafety: hir::Unsafety::Normal,
+            unsafety,
             generics: (tcx.generics_of(did), &predicates).clean(cx),
             provided_trait_methods: provided,
             trait_,
@@ -427,6 +438,7 @@ pub fn build_impl(cx: &DocContext, did: DefId, ret: &mut Vec<clean::Item>) {
             polarity: Some(polarity.clean(cx)),
             synthetic: false,
             blanket_impl: None,
+            blanket_bounds: Vec::new(),
         }),
         source: tcx.def_span(did).clean(cx),
         name: None,
@@ -482,12 +494,21 @@ fn build_static(cx: &DocContext, did: DefId, mutable: bool) -> clean::Static {
     }
 }
 
-fn build_macro(cx: &DocContext, did: DefId, name: ast::Name) -> Option<clean::Macro> {
+fn build_macro(cx: &DocContext, did: DefId, name: ast::Name) -> clean::ItemEnum {
     let imported_from = cx.tcx.original_crate_name(did.krate);
     let def = match cx.cstore.load_macro_untracked(did, cx.sess()) {
         LoadedMacro::MacroDef(macro_def) => macro_def,
-        // FIXME(jseyfried): document proc macro re-exports
-        LoadedMacro::ProcMacro(..) => return None,
+        // The proc macros are only known by their kind, and the helper attributes of the derives.
+        LoadedMacro::ProcMacro(ext) => {
+            let helpers = match *ext {
+                SyntaxExtension::ProcMacroDerive(_, ref helpers, _) => helpers.clean(cx),
+                _ => Vec::new(),
+            };
+            return clean::ProcMacroItem(clean::ProcMacro {
+                kind: ext.kind(),
+                helpers,
+            });
+        }
     };
 
     let matchers: hir::HirVec<Span> = if let ast::ItemKind::MacroDef(ref def) = def.node {
@@ -503,7 +524,7 @@ fn build_macro(cx: &DocContext, did: DefId, name: ast::Name) -> Option<clean::Ma
                              format!("    {} => {{ ... }};\n", span.to_src(cx))
                          }).collect::<String>());
 
-    Some(clean::Macro {
+    clean::MacroItem(clean::Macro {
         source,
         imported_from: Some(imported_from).clean(cx),
     })
@@ -23,6 +23,7 @@ use syntax;
 use syntax::ast::{self, AttrStyle, Name, NodeId, Ident};
 use syntax::attr;
 use syntax::codemap::{dummy_spanned, Spanned};
+use syntax::ext::base::MacroKind;
 use syntax::feature_gate::UnstableFeatures;
 use syntax::ptr::P;
 use syntax::symbol::keywords::{self, Keyword};
@@ -157,6 +158,13 @@ impl<'a, 'tcx, 'rcx, 'cstore> Clean<Crate> for visit_ast::RustdocVisitor<'a, 'tc
             r.deref_trait_did = cx.tcx.lang_items().deref_trait();
             r.deref_mut_trait_did = cx.tcx.lang_items().deref_mut_trait();
             r.owned_box_did = cx.tcx.lang_items().owned_box();
+            r.sized_trait_did = cx.tcx.lang_items().sized_trait();
+            r.drop_trait_did = cx.tcx.lang_items().drop_trait();
+            r.future_trait_did = if cx.crate_name == Some("core".to_string()) {
+                path_to_def_local(&cx.tcx, &["future", "Future"])
+            } else {
+                path_to_def(&cx.tcx, &["core", "future", "Future"])
+            };
         }
 
         let mut externs = Vec::new();
@@ -170,18 +178,6 @@ impl<'a, 'tcx, 'rcx, 'cstore> Clean<Crate> for visit_ast::RustdocVisitor<'a, 'tc
         // Clean the crate, translating the entire libsyntax AST to one that is
         // understood by rustdoc.
         let mut module = self.module.clean(cx);
-        let mut masked_crates = FxHashSet();
-
-        match module.inner {
-            ModuleItem(ref module) => {
-                for it in &module.items {
-                    if it.is_extern_crate() && it.attrs.has_doc_flag("masked") {
-                        masked_crates.insert(it.def_id.krate);
-                    }
-                }
-            }
-            _ => unreachable!(),
-        }
 
         let ExternalCrate { name, src, primitives, keywords, .. } = LOCAL_CRATE.clean(cx);
         {
@@ -227,7 +223,7 @@ impl<'a, 'tcx, 'rcx, 'cstore> Clean<Crate> for visit_ast::RustdocVisitor<'a, 'tc
             primitives,
             access_levels: Arc::new(mem::replace(&mut access_levels, Default::default())),
             external_traits: mem::replace(&mut external_traits, Default::default()),
-            masked_crates,
+            masked_crates: cx.masked_crates.clone(),
         }
     }
 }
@@ -317,7 +313,14 @@ impl Clean<ExternalCrate> for CrateNum {
                             if keyword.is_some() {
                                 break
                             }
-                            // FIXME: should warn on unknown keywords?
+                            if def_id.is_local() {
+                                cx.sess().diagnostic()
+                                    .struct_span_warn(attr.span,
+                                                      &format!("`{}` is not a keyword, so this \
+                                                                module won't be documented as \
+                                                                one", v))
+                                    .emit();
+                            }
                         }
                     }
                 }
@@ -405,7 +408,7 @@ impl Item {
         self.attrs.collapsed_doc_value()
     }
 
-    pub fn links(&self) -> Vec<(String, String)> {
+    pub fn links(&self) -> Vec<(String, String, Option<String>)> {
         self.attrs.links(&self.def_id.krate)
     }
 
@@ -522,6 +525,7 @@ pub enum ItemEnum {
     StaticItem(Static),
     ConstantItem(Constant),
     TraitItem(Trait),
+    TraitAliasItem(TraitAlias),
     ImplItem(Impl),
     /// A method signature only. Used for required methods in traits (ie,
     /// non-default-methods).
@@ -537,9 +541,11 @@ pub enum ItemEnum {
     /// `type`s from an extern block
     ForeignTypeItem,
     MacroItem(Macro),
+    ProcMacroItem(ProcMacro),
     PrimitiveItem(PrimitiveType),
     AssociatedConstItem(Type, Option<String>),
-    AssociatedTypeItem(Vec<GenericBound>, Option<Type>),
+    /// The generics of an associated type carry the where clause written on it.
+    AssociatedTypeItem(Generics, Vec<GenericBound>, Option<Type>),
     /// An item that has been stripped by a rustdoc pass
     StrippedItem(Box<ItemEnum>),
     KeywordItem(String),
@@ -554,6 +560,7 @@ impl ItemEnum {
             ItemEnum::TypedefItem(ref t, _) => &t.generics,
             ItemEnum::ExistentialItem(ref t, _) => &t.generics,
             ItemEnum::TraitItem(ref t) => &t.generics,
+            ItemEnum::TraitAliasItem(ref ta) => &ta.generics,
             ItemEnum::ImplItem(ref i) => &i.generics,
             ItemEnum::TyMethodItem(ref i) => &i.generics,
             ItemEnum::MethodItem(ref i) => &i.generics,
@@ -617,12 +624,19 @@ impl Clean<Item> for doctree::Module {
         items.extend(self.foreigns.iter().flat_map(|x| x.clean(cx)));
         items.extend(self.mods.iter().map(|x| x.clean(cx)));
         items.extend(self.typedefs.iter().map(|x| x.clean(cx)));
+        if cx.expand_type_aliases {
+            for typedef in &self.typedefs {
+                expand_type_alias(cx, typedef);
+            }
+        }
         items.extend(self.existentials.iter().map(|x| x.clean(cx)));
         items.extend(self.statics.iter().map(|x| x.clean(cx)));
         items.extend(self.constants.iter().map(|x| x.clean(cx)));
         items.extend(self.traits.iter().map(|x| x.clean(cx)));
+        items.extend(self.trait_aliases.iter().map(|x| x.clean(cx)));
         items.extend(self.impls.iter().flat_map(|x| x.clean(cx)));
         items.extend(self.macros.iter().map(|x| x.clean(cx)));
+        items.extend(self.proc_macros.iter().map(|x| x.clean(cx)));
 
         cx.mod_ids.borrow_mut().pop();
 
@@ -866,6 +880,12 @@ impl Attributes {
         false
     }
 
+    /// Whether the trait these are the attributes of is marked with `#[doc(notable_trait)]`, or
+    /// with its older name `#[doc(spotlight)]`.
+    pub fn is_notable_trait(&self) -> bool {
+        self.has_doc_flag("notable_trait") || self.has_doc_flag("spotlight")
+    }
+
     pub fn from_ast(diagnostic: &::errors::Handler,
                     attrs: &[ast::Attribute]) -> Attributes {
         let mut doc_strings = vec![];
@@ -954,20 +974,28 @@ impl Attributes {
         }
     }
 
-    /// Get links as a vector
+    /// Get links as a vector, along with the summary of the items they point to when the
+    /// links are titled with `--link-summaries`
     ///
     /// Cache must be populated before call
-    pub fn links(&self, krate: &CrateNum) -> Vec<(String, String)> {
+    pub fn links(&self, krate: &CrateNum) -> Vec<(String, String, Option<String>)> {
         use html::format::href;
         self.links.iter().filter_map(|&(ref s, did, ref fragment)| {
             match did {
                 Some(did) => {
                     if let Some((mut href, ..)) = href(did) {
+                        // A fragment points into the page of `did`, not to `did` itself.
+                        let summary = match *fragment {
+                            Some(_) => None,
+                            None => cache().summaries.as_ref()
+                                                     .and_then(|s| s.get(&did))
+                                                     .cloned(),
+                        };
                         if let Some(ref fragment) = *fragment {
                             href.push_str("#");
                             href.push_str(fragment);
                         }
-                        Some((s.clone(), href))
+                        Some((s.clone(), href, summary))
                     } else {
                         None
                     }
@@ -987,7 +1015,8 @@ impl Attributes {
                               format!("{}{}std/primitive.{}.html",
                                       url,
                                       if !url.ends_with('/') { "/" } else { "" },
-                                      fragment)))
+                                      fragment),
+                              None))
                     } else {
                         panic!("This isn't a primitive?!");
                     }
@@ -1200,7 +1229,8 @@ fn resolve(cx: &DocContext, path_str: &str, is_val: bool) -> Result<(Def, Option
                 let item = cx.tcx.inherent_impls(did)
                                  .iter()
                                  .flat_map(|imp| cx.tcx.associated_items(*imp))
-                                 .find(|item| item.ident.name == item_name);
+                                 .find(|item| item.ident.name == item_name)
+                                 .or_else(|| trait_impl_item(cx, did, item_name));
                 if let Some(item) = item {
                     let out = match item.kind {
                         ty::AssociatedKind::Method if is_val => "method",
@@ -1265,6 +1295,25 @@ fn resolve(cx: &DocContext, path_str: &str, is_val: bool) -> Result<(Def, Option
     }
 }
 
+/// Looks for the associated item `item_name` in the trait impls of the type `did`, for the
+/// associated functions like `Vec::default` which are only written in the impl of a trait.
+fn trait_impl_item(cx: &DocContext, did: DefId, item_name: &str) -> Option<ty::AssociatedItem> {
+    let ty = cx.tcx.type_of(did);
+    let mut found = None;
+    for &trait_did in &cx.all_traits {
+        cx.tcx.for_each_relevant_impl(trait_did, ty, |impl_did| {
+            if found.is_none() {
+                found = cx.tcx.associated_items(impl_did)
+                              .find(|item| item.ident.name == item_name);
+            }
+        });
+        if found.is_some() {
+            break;
+        }
+    }
+    found
+}
+
 /// Resolve a string as a macro
 fn macro_resolve(cx: &DocContext, path_str: &str) -> Option<Def> {
     use syntax::ext::base::{MacroKind, SyntaxExtension};
@@ -1374,6 +1423,23 @@ fn resolution_failure(
     diag.emit();
 }
 
+fn deprecated_link(cx: &DocContext, attrs: &Attributes, link: &str, depr: &Deprecation) {
+    let sp = span_of_attrs(attrs);
+    let mut msg = format!("`[{}]` links to a deprecated item", link);
+    if !depr.since.is_empty() {
+        msg.push_str(&format!(" (deprecated since {})", depr.since));
+    }
+    let mut diag = cx.tcx.struct_span_lint_node(lint::builtin::INTRA_DOC_LINK_TO_DEPRECATED,
+                                                NodeId::new(0),
+                                                sp,
+                                                &msg);
+    if !depr.note.is_empty() {
+        diag.note(&depr.note);
+    }
+    diag.help("consider mentioning the item that replaces it instead");
+    diag.emit();
+}
+
 impl Clean<Attributes> for [ast::Attribute] {
     fn clean(&self, cx: &DocContext) -> Attributes {
         let mut attrs = Attributes::from_ast(cx.sess().diagnostic(), self);
@@ -1500,6 +1566,9 @@ impl Clean<Attributes> for [ast::Attribute] {
                     attrs.links.push((ori_link, None, fragment));
                 } else {
                     let id = register_def(cx, def);
+                    if let Some(depr) = get_deprecation(cx, id) {
+                        deprecated_link(cx, &attrs, &link, &depr);
+                    }
                     attrs.links.push((ori_link, Some(id), fragment));
                 }
             }
@@ -1590,12 +1659,11 @@ fn external_generic_args(cx: &DocContext, trait_did: Option<DefId>, has_self: bo
                     }
                 }
             };
-            let output = None;
-            // FIXME(#20299) return type comes from a projection now
-            // match types[1].sty {
-            //     ty::TyTuple(ref v) if v.is_empty() => None, // -> ()
-            //     _ => Some(types[1].clean(cx))
-            // };
+            // The return type comes from the `Output` projection, if we were given one
+            let output = bindings.into_iter()
+                                 .find(|binding| binding.name == "Output")
+                                 .map(|binding| binding.ty)
+                                 .filter(|ty| *ty != Type::Tuple(Vec::new()));
             GenericArgs::Parenthesized {
                 inputs,
                 output,
@@ -1634,21 +1702,28 @@ impl<'a, 'tcx> Clean<GenericBound> for (&'a ty::TraitRef<'tcx>, Vec<TypeBinding>
 
         debug!("ty::TraitRef\n  subst: {:?}\n", trait_ref.substs);
 
-        // collect any late bound regions
-        let mut late_bounds = vec![];
+        // collect any late bound regions, both the ones given directly as lifetime arguments
+        // (`for<'a> Trait<'a>`) and the ones in the arguments of `Fn` traits
+        let mut late_bound_regions = trait_ref.substs.regions().collect::<Vec<_>>();
         for ty_s in trait_ref.input_types().skip(1) {
             if let ty::TyTuple(ts) = ty_s.sty {
                 for &ty_s in ts {
-                    if let ty::TyRef(ref reg, _, _) = ty_s.sty {
-                        if let &ty::RegionKind::ReLateBound(..) = *reg {
-                            debug!("  hit an ReLateBound {:?}", reg);
-                            if let Some(Lifetime(name)) = reg.clean(cx) {
-                                late_bounds.push(GenericParamDef {
-                                    name,
-                                    kind: GenericParamDefKind::Lifetime,
-                                });
-                            }
-                        }
+                    if let ty::TyRef(reg, _, _) = ty_s.sty {
+                        late_bound_regions.push(reg);
+                    }
+                }
+            }
+        }
+        let mut late_bounds: Vec<GenericParamDef> = vec![];
+        for reg in late_bound_regions {
+            if let &ty::RegionKind::ReLateBound(..) = reg {
+                debug!("  hit an ReLateBound {:?}", reg);
+                if let Some(Lifetime(name)) = reg.clean(cx) {
+                    if !late_bounds.iter().any(|param| param.name == name) {
+                        late_bounds.push(GenericParamDef {
+                            name,
+                            kind: GenericParamDefKind::Lifetime,
+                        });
                     }
                 }
             }
@@ -1783,9 +1858,20 @@ impl Clean<WherePredicate> for hir::WherePredicate {
     fn clean(&self, cx: &DocContext) -> WherePredicate {
         match *self {
             hir::WherePredicate::BoundPredicate(ref wbp) => {
+                let mut bounds = wbp.bounds.clean(cx);
+                // A binder on the whole predicate (`for<'a> F: Fn(&'a T)`) applies to every
+                // trait bound, so carry it over to them so that it gets rendered.
+                if !wbp.bound_generic_params.is_empty() {
+                    let bound_params = wbp.bound_generic_params.clean(cx);
+                    for bound in &mut bounds {
+                        if let GenericBound::TraitBound(ref mut poly_trait, _) = *bound {
+                            poly_trait.generic_params.splice(0..0, bound_params.iter().cloned());
+                        }
+                    }
+                }
                 WherePredicate::BoundPredicate {
                     ty: wbp.bounded_ty.clean(cx),
-                    bounds: wbp.bounds.clean(cx)
+                    bounds,
                 }
             }
 
@@ -1917,7 +2003,7 @@ impl<'tcx> Clean<GenericParamDef> for ty::GenericParamDef {
             ty::GenericParamDefKind::Lifetime => {
                 (self.name.to_string(), GenericParamDefKind::Lifetime)
             }
-            ty::GenericParamDefKind::Type { has_default, .. } => {
+            ty::GenericParamDefKind::Type { has_default, synthetic, .. } => {
                 cx.renderinfo.borrow_mut().external_typarams
                              .insert(self.def_id, self.name.clean(cx));
                 let default = if has_default {
@@ -1929,7 +2015,7 @@ impl<'tcx> Clean<GenericParamDef> for ty::GenericParamDef {
                     did: self.def_id,
                     bounds: vec![], // These are filled in from the where-clauses.
                     default,
-                    synthetic: None,
+                    synthetic,
                 })
             }
         };
@@ -1985,6 +2071,98 @@ pub struct Generics {
     pub where_predicates: Vec<WherePredicate>,
 }
 
+/// Where the bounds of the type parameters are shown, as given by `--bounds-style`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BoundsStyle {
+    /// Show the bounds where they're written.
+    Source,
+    /// Show the bounds next to their parameter, as in `fn f<T: Clone>()`.
+    Inline,
+    /// Show the bounds in the where clause, as in `fn f<T>() where T: Clone`.
+    Where,
+}
+
+impl FromStr for BoundsStyle {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<BoundsStyle, ()> {
+        match s {
+            "inline" => Ok(BoundsStyle::Inline),
+            "where" => Ok(BoundsStyle::Where),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Generics {
+    /// Moves the bounds of the type parameters to the where clause or the other way around, as
+    /// asked by `style`. The bounds of the other types, and the ones of the synthetic parameters
+    /// of the `impl Trait` arguments, stay where they are.
+    fn with_bounds_style(mut self, style: BoundsStyle) -> Generics {
+        match style {
+            BoundsStyle::Source => {}
+            BoundsStyle::Inline => {
+                let params = &mut self.params;
+                self.where_predicates.retain(|pred| {
+                    let (name, bounds) = match *pred {
+                        WherePredicate::BoundPredicate { ty: Generic(ref name), ref bounds } => {
+                            (name, bounds)
+                        }
+                        _ => return true,
+                    };
+                    for param in params.iter_mut().filter(|param| &param.name == name) {
+                        if let GenericParamDefKind::Type {
+                            bounds: ref mut param_bounds, synthetic: None, ..
+                        } = param.kind {
+                            param_bounds.extend(bounds.iter().cloned());
+                            return false;
+                        }
+                    }
+                    true
+                });
+            }
+            BoundsStyle::Where => {
+                let mut where_predicates = Vec::with_capacity(self.where_predicates.len());
+                for param in &mut self.params {
+                    if let GenericParamDefKind::Type { ref mut bounds, synthetic: None, .. } =
+                        param.kind
+                    {
+                        if !bounds.is_empty() {
+                            where_predicates.push(WherePredicate::BoundPredicate {
+                                ty: Generic(param.name.clone()),
+                                bounds: mem::replace(bounds, Vec::new()),
+                            });
+                        }
+                    }
+                }
+                // The bounds written in both places are merged into one predicate.
+                for pred in mem::replace(&mut self.where_predicates, Vec::new()) {
+                    let merged = match pred {
+                        WherePredicate::BoundPredicate { ty: Generic(ref name), ref bounds } => {
+                            where_predicates.iter_mut().any(|existing| match *existing {
+                                WherePredicate::BoundPredicate {
+                                    ty: Generic(ref existing_name),
+                                    bounds: ref mut existing_bounds,
+                                } if existing_name == name => {
+                                    existing_bounds.extend(bounds.iter().cloned());
+                                    true
+                                }
+                                _ => false,
+                            })
+                        }
+                        _ => false,
+                    };
+                    if !merged {
+                        where_predicates.push(pred);
+                    }
+                }
+                self.where_predicates = where_predicates;
+            }
+        }
+        self
+    }
+}
+
 impl Clean<Generics> for hir::Generics {
     fn clean(&self, cx: &DocContext) -> Generics {
         // Synthetic type-parameters are inserted after normal ones.
@@ -2006,7 +2184,8 @@ impl Clean<Generics> for hir::Generics {
                 match param.kind {
                     GenericParamDefKind::Lifetime => unreachable!(),
                     GenericParamDefKind::Type { did, ref bounds, .. } => {
-                        cx.impl_trait_bounds.borrow_mut().insert(did, bounds.clone());
+                        cx.impl_trait_bounds.borrow_mut()
+                                            .insert(ImplTraitParam::DefId(did), bounds.clone());
                     }
                 }
                 param
@@ -2048,7 +2227,7 @@ impl Clean<Generics> for hir::Generics {
                 _ => continue,
             }
         }
-        generics
+        generics.with_bounds_style(cx.bounds_style)
     }
 }
 
@@ -2082,9 +2261,14 @@ impl<'a, 'tcx> Clean<Generics> for (&'a ty::Generics,
         // Note that associated types also have a sized bound by default, but we
         // don't actually know the set of associated types right here so that's
         // handled in cleaning associated types
+        //
+        // A `Self: Sized` bound on an associated item is never implied though: it makes the item
+        // unavailable on trait objects, so it's kept around.
         let mut sized_params = FxHashSet();
         where_predicates.retain(|pred| {
             match *pred {
+                WP::BoundPredicate { ty: Generic(ref g), .. }
+                    if gens.parent.is_some() && g == "Self" => true,
                 WP::BoundPredicate { ty: Generic(ref g), ref bounds } => {
                     if bounds.iter().any(|b| b.is_sized_bound(cx)) {
                         sized_params.insert(g.clone());
@@ -2111,6 +2295,30 @@ impl<'a, 'tcx> Clean<Generics> for (&'a ty::Generics,
         // It would be nice to collect all of the bounds on a type and recombine
         // them if possible, to avoid e.g. `where T: Foo, T: Bar, T: Sized, T: 'a`
         // and instead see `where T: Foo + Bar + Sized + 'a`
+        let mut where_predicates = simplify::where_clauses(cx, where_predicates);
+
+        // The bounds of the `impl Trait` arguments are shown in place of their synthetic type
+        // parameters, once their associated type bindings have been merged into them.
+        let impl_trait_params = gens.params.iter().filter_map(|param| match param.kind {
+            ty::GenericParamDefKind::Type { synthetic: Some(_), .. } => {
+                Some((param.name.to_string(), param.index))
+            }
+            _ => None,
+        }).collect::<FxHashMap<_, _>>();
+        where_predicates.retain(|pred| match *pred {
+            WP::BoundPredicate { ty: Generic(ref g), ref bounds } => {
+                match impl_trait_params.get(g) {
+                    Some(&index) => {
+                        cx.impl_trait_bounds.borrow_mut()
+                                            .insert(ImplTraitParam::ParamIndex(index),
+                                                    bounds.clone());
+                        false
+                    }
+                    None => true,
+                }
+            }
+            _ => true,
+        });
 
         Generics {
             params: gens.params
@@ -2120,8 +2328,8 @@ impl<'a, 'tcx> Clean<Generics> for (&'a ty::Generics,
                             ty::GenericParamDefKind::Type { .. } => None,
                         }).chain(simplify::ty_params(stripped_typarams).into_iter())
                         .collect(),
-            where_predicates: simplify::where_clauses(cx, where_predicates),
-        }
+            where_predicates,
+        }.with_bounds_style(cx.bounds_style)
     }
 }
 
@@ -2195,6 +2403,60 @@ impl FnDecl {
     pub fn self_type(&self) -> Option<SelfTy> {
         self.inputs.values.get(0).and_then(|v| v.to_self())
     }
+
+    /// Returns the declaration where `Self` is replaced by `self_type`. The `self` and `&self`
+    /// receivers are kept as they are.
+    pub fn replace_self(&self, self_type: &Type) -> FnDecl {
+        let values = self.inputs.values.iter().map(|arg| {
+            match arg.to_self() {
+                Some(SelfValue) | Some(SelfBorrowed(..)) => arg.clone(),
+                _ => Argument { type_: arg.type_.replace_self(self_type), name: arg.name.clone() },
+            }
+        }).collect();
+        let output = match self.output {
+            Return(ref ty) => Return(ty.replace_self(self_type)),
+            DefaultReturn => DefaultReturn,
+        };
+        FnDecl {
+            inputs: Arguments { values },
+            output,
+            variadic: self.variadic,
+            attrs: self.attrs.clone(),
+        }
+    }
+
+    /// Returns the declaration an `async fn` desugars to, where the declared return type `T`
+    /// becomes `impl Future<Output = T>`.
+    pub fn async_desugared(&self, future_trait: DefId) -> FnDecl {
+        let output = match self.output {
+            Return(ref ty) => ty.clone(),
+            DefaultReturn => Tuple(Vec::new()),
+        };
+        let future = ResolvedPath {
+            path: Path {
+                global: false,
+                def: Def::Trait(future_trait),
+                segments: vec![PathSegment {
+                    name: "Future".to_string(),
+                    args: GenericArgs::AngleBracketed {
+                        lifetimes: Vec::new(),
+                        types: Vec::new(),
+                        bindings: vec![TypeBinding { name: "Output".to_string(), ty: output }],
+                    },
+                }],
+            },
+            typarams: None,
+            did: future_trait,
+            is_generic: false,
+        };
+        FnDecl {
+            output: Return(ImplTrait(vec![GenericBound::TraitBound(PolyTrait {
+                trait_: future,
+                generic_params: Vec::new(),
+            }, hir::TraitBoundModifier::None)])),
+            ..self.clone()
+        }
+    }
 }
 
 #[derive(Clone, RustcEncodable, RustcDecodable, PartialEq, Eq, Debug, Hash)]
@@ -2220,6 +2482,42 @@ impl<'a> Clean<Arguments> for (&'a [hir::Ty], &'a [ast::Ident]) {
     }
 }
 
+/// How the arguments of functions which destructure them are shown.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DestructuredArgs {
+    /// Show the pattern, as in `(a, b): (u32, u32)`.
+    Pattern,
+    /// Show a made up name, as in `arg0: (u32, u32)`.
+    Name,
+    /// Show the type alone, as in `(u32, u32)`.
+    Type,
+}
+
+impl FromStr for DestructuredArgs {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<DestructuredArgs, ()> {
+        match s {
+            "pattern" => Ok(DestructuredArgs::Pattern),
+            "name" => Ok(DestructuredArgs::Name),
+            "type" => Ok(DestructuredArgs::Type),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Returns the name to show for the `index`th argument of a function, bound by `pat`.
+fn name_from_arg(cx: &DocContext, pat: &hir::Pat, index: usize) -> String {
+    match pat.node {
+        hir::PatKind::Binding(..) | hir::PatKind::Wild => name_from_pat(pat),
+        _ => match cx.destructured_args {
+            DestructuredArgs::Pattern => name_from_pat(pat),
+            DestructuredArgs::Name => format!("arg{}", index),
+            DestructuredArgs::Type => String::new(),
+        },
+    }
+}
+
 impl<'a> Clean<Arguments> for (&'a [hir::Ty], hir::BodyId) {
     fn clean(&self, cx: &DocContext) -> Arguments {
         let body = cx.tcx.hir.body(self.1);
@@ -2227,7 +2525,7 @@ impl<'a> Clean<Arguments> for (&'a [hir::Ty], hir::BodyId) {
         Arguments {
             values: self.0.iter().enumerate().map(|(i, ty)| {
                 Argument {
-                    name: name_from_pat(&body.arguments[i].pat),
+                    name: name_from_arg(cx, &body.arguments[i].pat, i),
                     type_: ty.clean(cx),
                 }
             }).collect()
@@ -2251,10 +2549,24 @@ impl<'a, A: Copy> Clean<FnDecl> for (&'a hir::FnDecl, A)
 impl<'a, 'tcx> Clean<FnDecl> for (DefId, ty::PolyFnSig<'tcx>) {
     fn clean(&self, cx: &DocContext) -> FnDecl {
         let (did, sig) = *self;
-        let mut names = if cx.tcx.hir.as_local_node_id(did).is_some() {
-            vec![].into_iter()
+        // Local functions don't go through the metadata, so pull the argument names
+        // straight out of the HIR body instead.
+        let mut names = if let Some(node_id) = cx.tcx.hir.as_local_node_id(did) {
+            cx.tcx.hir.maybe_body_owned_by(node_id).map_or(vec![], |body_id| {
+                cx.tcx.hir.body(body_id).arguments.iter()
+                                                  .enumerate()
+                                                  .map(|(i, arg)| name_from_arg(cx, &arg.pat, i))
+                                                  .collect()
+            }).into_iter()
         } else {
-            cx.tcx.fn_arg_names(did).into_iter()
+            // The metadata has no name for the destructured arguments.
+            cx.tcx.fn_arg_names(did).into_iter().enumerate().map(|(i, name)| {
+                match (&*name.as_str(), cx.destructured_args) {
+                    ("", DestructuredArgs::Pattern) => "_".to_string(),
+                    ("", DestructuredArgs::Name) => format!("arg{}", i),
+                    (_, _) => name.to_string(),
+                }
+            }).collect::<Vec<_>>().into_iter()
         };
 
         FnDecl {
@@ -2265,7 +2577,7 @@ impl<'a, 'tcx> Clean<FnDecl> for (DefId, ty::PolyFnSig<'tcx>) {
                 values: sig.skip_binder().inputs().iter().map(|t| {
                     Argument {
                         type_: t.clean(cx),
-                        name: names.next().map_or("".to_string(), |name| name.to_string()),
+                        name: names.next().unwrap_or_default(),
                     }
                 }).collect(),
             },
@@ -2336,18 +2648,33 @@ pub struct Trait {
     pub bounds: Vec<GenericBound>,
     pub is_spotlight: bool,
     pub is_auto: bool,
+    /// The reasons why the trait can't be made into an object, if any.
+    pub object_safety_violations: Vec<String>,
+}
+
+/// Returns the messages explaining why the given trait isn't object safe, without duplicates.
+pub fn object_safety_violations(cx: &DocContext, did: DefId) -> Vec<String> {
+    let mut violations = Vec::new();
+    for violation in cx.tcx.object_safety_violations(did) {
+        let msg = violation.error_msg().into_owned();
+        if !violations.contains(&msg) {
+            violations.push(msg);
+        }
+    }
+    violations
 }
 
 impl Clean<Item> for doctree::Trait {
     fn clean(&self, cx: &DocContext) -> Item {
         *cx.current_item_name.borrow_mut() = Some(self.name);
         let attrs = self.attrs.clean(cx);
-        let is_spotlight = attrs.has_doc_flag("spotlight");
+        let is_spotlight = attrs.is_notable_trait();
+        let def_id = cx.tcx.hir.local_def_id(self.id);
         Item {
             name: Some(self.name.clean(cx)),
             attrs: attrs,
             source: self.whence.clean(cx),
-            def_id: cx.tcx.hir.local_def_id(self.id),
+            def_id,
             visibility: self.vis.clean(cx),
             stability: self.stab.clean(cx),
             deprecation: self.depr.clean(cx),
@@ -2359,6 +2686,7 @@ impl Clean<Item> for doctree::Trait {
                 bounds: self.bounds.clean(cx),
                 is_spotlight: is_spotlight,
                 is_auto: self.is_auto.clean(cx),
+                object_safety_violations: object_safety_violations(cx, def_id),
             }),
         }
     }
@@ -2409,7 +2737,7 @@ impl Clean<Item> for hir::TraitItem {
                 })
             }
             hir::TraitItemKind::Type(ref bounds, ref default) => {
-                AssociatedTypeItem(bounds.clean(cx), default.clean(cx))
+                AssociatedTypeItem(self.generics.clean(cx), bounds.clean(cx), default.clean(cx))
             }
         };
         *cx.current_item_name.borrow_mut() = Some(self.ident.name);
@@ -2472,10 +2800,12 @@ impl<'tcx> Clean<Item> for ty::AssociatedItem {
                 AssociatedConstItem(ty.clean(cx), default)
             }
             ty::AssociatedKind::Method => {
-                let generics = (cx.tcx.generics_of(self.def_id),
-                                &cx.tcx.predicates_of(self.def_id)).clean(cx);
                 let sig = cx.tcx.fn_sig(self.def_id);
-                let mut decl = (self.def_id, sig).clean(cx);
+                let (generics, mut decl) = enter_impl_trait(cx, || {
+                    ((cx.tcx.generics_of(self.def_id),
+                      &cx.tcx.predicates_of(self.def_id)).clean(cx),
+                     (self.def_id, sig).clean(cx))
+                });
 
                 if self.method_has_self_argument {
                     let self_ty = match self.container {
@@ -2577,7 +2907,10 @@ impl<'tcx> Clean<Item> for ty::AssociatedItem {
                         None
                     };
 
-                    AssociatedTypeItem(bounds, ty.clean(cx))
+                    let generics = (cx.tcx.generics_of(self.def_id),
+                                    &cx.tcx.predicates_of(self.def_id)).clean(cx);
+
+                    AssociatedTypeItem(generics, bounds, ty.clean(cx))
                 } else {
                     TypedefItem(Typedef {
                         type_: cx.tcx.type_of(self.def_id).clean(cx),
@@ -2691,10 +3024,13 @@ pub enum TypeKind {
     Struct,
     Union,
     Trait,
+    TraitAlias,
     Variant,
     Typedef,
     Foreign,
     Macro,
+    Attr,
+    Derive,
 }
 
 pub trait GetDefId {
@@ -2740,6 +3076,42 @@ impl Type {
         }
     }
 
+    /// Returns this type where `Self` is replaced by `self_type`, as in the provided methods
+    /// of a trait which are shown on its impls.
+    pub fn replace_self(&self, self_type: &Type) -> Type {
+        match *self {
+            Generic(ref name) if name == "Self" => self_type.clone(),
+            ResolvedPath { ref path, ref typarams, did, is_generic } => ResolvedPath {
+                path: path.replace_self(self_type),
+                typarams: typarams.clone(),
+                did,
+                is_generic,
+            },
+            BareFunction(ref f) => BareFunction(box BareFunctionDecl {
+                unsafety: f.unsafety,
+                generic_params: f.generic_params.clone(),
+                decl: f.decl.replace_self(self_type),
+                abi: f.abi,
+            }),
+            Tuple(ref tys) => Tuple(tys.iter().map(|t| t.replace_self(self_type)).collect()),
+            Slice(ref t) => Slice(box t.replace_self(self_type)),
+            Array(ref t, ref n) => Array(box t.replace_self(self_type), n.clone()),
+            Unique(ref t) => Unique(box t.replace_self(self_type)),
+            RawPointer(mutability, ref t) => RawPointer(mutability, box t.replace_self(self_type)),
+            BorrowedRef { ref lifetime, mutability, ref type_ } => BorrowedRef {
+                lifetime: lifetime.clone(),
+                mutability,
+                type_: box type_.replace_self(self_type),
+            },
+            QPath { ref name, self_type: ref qself, ref trait_ } => QPath {
+                name: name.clone(),
+                self_type: box qself.replace_self(self_type),
+                trait_: trait_.clone(),
+            },
+            _ => self.clone(),
+        }
+    }
+
     pub fn generics(&self) -> Option<&[Type]> {
         match *self {
             ResolvedPath { ref path, .. } => {
@@ -2920,7 +3292,8 @@ impl Clean<Type> for hir::Ty {
                 }
 
                 if let Def::TyParam(did) = path.def {
-                    if let Some(bounds) = cx.impl_trait_bounds.borrow_mut().remove(&did) {
+                    let param = ImplTraitParam::DefId(did);
+                    if let Some(bounds) = cx.impl_trait_bounds.borrow_mut().remove(&param) {
                         return ImplTrait(bounds);
                     }
                 }
@@ -3019,6 +3392,20 @@ impl Clean<Type> for hir::Ty {
                 let ty = hir_ty_to_ty(cx.tcx, self);
                 if let ty::TyProjection(proj) = ty.sty {
                     def = Def::Trait(proj.trait_ref(cx.tcx).def_id);
+                } else if let TyKind::Path(hir::QPath::Resolved(None, ref p)) = qself.node {
+                    // `Self::Name` inside a trait impl may come back already resolved to the
+                    // concrete type; link it to the implemented trait's associated type.
+                    if let Def::SelfTy(_, Some(impl_did)) = p.def {
+                        let trait_did = cx.tcx.trait_id_of_impl(impl_did).filter(|&did| {
+                            cx.tcx.associated_items(did).any(|item| {
+                                item.kind == ty::AssociatedKind::Type &&
+                                item.ident.name == segment.ident.name
+                            })
+                        });
+                        if let Some(trait_did) = trait_did {
+                            def = Def::Trait(trait_did);
+                        }
+                    }
                 }
                 let trait_path = hir::Path {
                     span: self.span,
@@ -3172,7 +3559,13 @@ impl<'tcx> Clean<Type> for Ty<'tcx> {
 
             ty::TyProjection(ref data) => data.clean(cx),
 
-            ty::TyParam(ref p) => Generic(p.name.to_string()),
+            ty::TyParam(ref p) => {
+                let param = ImplTraitParam::ParamIndex(p.idx);
+                if let Some(bounds) = cx.impl_trait_bounds.borrow_mut().remove(&param) {
+                    return ImplTrait(bounds);
+                }
+                Generic(p.name.to_string())
+            }
 
             ty::TyAnon(def_id, substs) => {
                 // Grab the "TraitA + TraitB" from `impl TraitA + TraitB`,
@@ -3553,6 +3946,31 @@ impl Path {
     pub fn last_name(&self) -> &str {
         self.segments.last().expect("segments were empty").name.as_str()
     }
+
+    fn replace_self(&self, self_type: &Type) -> Path {
+        let segments = self.segments.iter().map(|segment| {
+            let args = match segment.args {
+                GenericArgs::AngleBracketed { ref lifetimes, ref types, ref bindings } => {
+                    GenericArgs::AngleBracketed {
+                        lifetimes: lifetimes.clone(),
+                        types: types.iter().map(|t| t.replace_self(self_type)).collect(),
+                        bindings: bindings.iter().map(|b| TypeBinding {
+                            name: b.name.clone(),
+                            ty: b.ty.replace_self(self_type),
+                        }).collect(),
+                    }
+                }
+                GenericArgs::Parenthesized { ref inputs, ref output } => {
+                    GenericArgs::Parenthesized {
+                        inputs: inputs.iter().map(|t| t.replace_self(self_type)).collect(),
+                        output: output.as_ref().map(|t| t.replace_self(self_type)),
+                    }
+                }
+            };
+            PathSegment { name: segment.name.clone(), args }
+        }).collect();
+        Path { global: self.global, def: self.def, segments }
+    }
 }
 
 impl Clean<Path> for hir::Path {
@@ -3725,6 +4143,52 @@ impl Clean<Item> for doctree::Typedef {
     }
 }
 
+/// Records the type named by a public type alias for `--expand-type-aliases`, and inlines the
+/// impls of that type when it comes from another crate so that they can be shown on the page of
+/// the alias. Those impls are set aside rather than added to the crate, as only the pages of the
+/// aliases show them.
+fn expand_type_alias(cx: &DocContext, typedef: &doctree::Typedef) {
+    if !typedef.vis.node.is_pub() {
+        return;
+    }
+    let def_id = cx.tcx.hir.local_def_id(typedef.id);
+    // `type_of` sees through the aliases of aliases, so this is the type which the impls are
+    // attached to, whatever the generic parameters of the alias.
+    let target = match cx.tcx.type_of(def_id).sty {
+        ty::TyAdt(def, _) => def.did,
+        _ => return,
+    };
+    let already_expanded = {
+        let mut renderinfo = cx.renderinfo.borrow_mut();
+        let expanded = renderinfo.alias_targets.values().any(|&did| did == target);
+        renderinfo.alias_targets.insert(def_id, target);
+        expanded
+    };
+    if target.is_local() || already_expanded {
+        return;
+    }
+    // Only the impls of the aliased type are inlined, where `inline::build_impls` would also
+    // bring in the trait impls of every other crate.
+    let tcx = cx.tcx;
+    let mut impls = Vec::new();
+    for &did in tcx.inherent_impls(target).iter() {
+        inline::build_impl(cx, did, &mut impls);
+    }
+    for &cnum in tcx.crates().iter() {
+        for &did in tcx.all_trait_implementations(cnum).iter() {
+            match tcx.type_of(did).sty {
+                ty::TyAdt(def, _) if def.did == target => {
+                    inline::build_impl(cx, did, &mut impls);
+                }
+                _ => {}
+            }
+        }
+    }
+    impls.extend(get_auto_traits_with_def_id(cx, target));
+    impls.extend(get_blanket_impls_with_def_id(cx, target));
+    cx.renderinfo.borrow_mut().alias_impls.insert(target, impls);
+}
+
 #[derive(Clone, RustcEncodable, RustcDecodable, Debug)]
 pub struct Existential {
     pub bounds: Vec<GenericBound>,
@@ -3749,6 +4213,30 @@ impl Clean<Item> for doctree::Existential {
     }
 }
 
+#[derive(Clone, RustcEncodable, RustcDecodable, Debug)]
+pub struct TraitAlias {
+    pub generics: Generics,
+    pub bounds: Vec<GenericBound>,
+}
+
+impl Clean<Item> for doctree::TraitAlias {
+    fn clean(&self, cx: &DocContext) -> Item {
+        Item {
+            name: Some(self.name.clean(cx)),
+            attrs: self.attrs.clean(cx),
+            source: self.whence.clean(cx),
+            def_id: cx.tcx.hir.local_def_id(self.id),
+            visibility: self.vis.clean(cx),
+            stability: self.stab.clean(cx),
+            deprecation: self.depr.clean(cx),
+            inner: TraitAliasItem(TraitAlias {
+                generics: self.generics.clean(cx),
+                bounds: self.bounds.clean(cx),
+            }),
+        }
+    }
+}
+
 #[derive(Clone, RustcEncodable, RustcDecodable, PartialEq, Eq, Debug, Hash)]
 pub struct BareFunctionDecl {
     pub unsafety: hir::Unsafety,
@@ -3868,6 +4356,9 @@ pub struct Impl {
     pub polarity: Option<ImplPolarity>,
     pub synthetic: bool,
     pub blanket_impl: Option<Type>,
+    /// The bounds on the type of a blanket impl, with the type it's shown on in place of its
+    /// parameter: `MyStruct: Clone` for the `T: Clone` of `impl<T: Clone> Foo for T`.
+    pub blanket_bounds: Vec<WherePredicate>,
 }
 
 pub fn get_auto_traits_with_node_id(cx: &DocContext, id: ast::NodeId, name: String) -> Vec<Item> {
@@ -3947,6 +4438,7 @@ impl Clean<Vec<Item>> for doctree::Impl {
                 polarity: Some(self.polarity.clean(cx)),
                 synthetic: false,
                 blanket_impl: None,
+                blanket_bounds: Vec::new(),
             })
         });
         ret
@@ -4272,10 +4764,13 @@ fn register_def(cx: &DocContext, def: Def) -> DefId {
         Def::Union(i) => (i, TypeKind::Union),
         Def::Mod(i) => (i, TypeKind::Module),
         Def::TyForeign(i) => (i, TypeKind::Foreign),
+        Def::TraitAlias(i) => (i, TypeKind::TraitAlias),
         Def::Const(i) => (i, TypeKind::Const),
         Def::Static(i, _) => (i, TypeKind::Static),
         Def::Variant(i) => (cx.tcx.parent_def_id(i).expect("cannot get parent def id"),
                             TypeKind::Enum),
+        Def::Macro(i, MacroKind::Attr) => (i, TypeKind::Attr),
+        Def::Macro(i, MacroKind::Derive) => (i, TypeKind::Derive),
         Def::Macro(i, _) => (i, TypeKind::Macro),
         Def::SelfTy(Some(def_id), _) => (def_id, TypeKind::Trait),
         Def::SelfTy(_, Some(impl_def_id)) => {
@@ -4332,6 +4827,30 @@ impl Clean<Item> for doctree::Macro {
     }
 }
 
+#[derive(Clone, RustcEncodable, RustcDecodable, Debug)]
+pub struct ProcMacro {
+    pub kind: MacroKind,
+    pub helpers: Vec<String>,
+}
+
+impl Clean<Item> for doctree::ProcMacro {
+    fn clean(&self, cx: &DocContext) -> Item {
+        Item {
+            name: Some(self.name.clean(cx)),
+            attrs: self.attrs.clean(cx),
+            source: self.whence.clean(cx),
+            visibility: Some(Public),
+            stability: self.stab.clean(cx),
+            deprecation: self.depr.clean(cx),
+            def_id: cx.tcx.hir.local_def_id(self.id),
+            inner: ProcMacroItem(ProcMacro {
+                kind: self.kind,
+                helpers: self.helpers.clean(cx),
+            }),
+        }
+    }
+}
+
 #[derive(Clone, RustcEncodable, RustcDecodable, Debug)]
 pub struct Stability {
     pub level: stability::StabilityLevel,
@@ -4423,6 +4942,14 @@ pub fn def_id_to_path(cx: &DocContext, did: DefId, name: Option<String>) -> Vec<
     once(crate_name).chain(relative).collect()
 }
 
+/// Identifies the synthetic type parameter of an `impl Trait` argument: by its `DefId` in the
+/// HIR of the local crate, or by its index in the types of the items of other crates.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ImplTraitParam {
+    DefId(DefId),
+    ParamIndex(u32),
+}
+
 pub fn enter_impl_trait<F, R>(cx: &DocContext, f: F) -> R
 where
     F: FnOnce() -> R,
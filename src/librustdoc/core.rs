@@ -9,13 +9,15 @@
 // except according to those terms.
 
 use rustc_lint;
+use rustc_codegen_utils::codegen_backend::CodegenBackend;
 use rustc_driver::{self, driver, target_features, abort_on_err};
 use rustc::session::{self, config};
 use rustc::hir::def_id::{DefId, DefIndex, DefIndexAddressSpace, CrateNum, LOCAL_CRATE};
+use rustc::hir::def_id::CRATE_DEF_INDEX;
 use rustc::hir::def::Def;
 use rustc::hir::{self, HirVec};
 use rustc::middle::cstore::CrateStore;
-use rustc::middle::privacy::AccessLevels;
+use rustc::middle::privacy::{AccessLevel, AccessLevels};
 use rustc::ty::{self, TyCtxt, AllArenas};
 use rustc::hir::map as hir_map;
 use rustc::lint::{self, LintPass};
@@ -34,6 +36,7 @@ use syntax::json::JsonEmitter;
 use syntax::ptr::P;
 use syntax::symbol::keywords;
 use syntax_pos::DUMMY_SP;
+use syntax_pos::hygiene::SyntaxContext;
 use errors;
 use errors::emitter::{Emitter, EmitterWriter};
 
@@ -44,6 +47,7 @@ use std::rc::Rc;
 use std::path::PathBuf;
 
 use visit_ast::RustdocVisitor;
+use span_map;
 use clean;
 use clean::{get_path_for_type, Clean, MAX_DEF_ID};
 use html::render::RenderInfo;
@@ -53,6 +57,29 @@ pub use rustc::session::search_paths::SearchPaths;
 
 pub type ExternalPaths = FxHashMap<DefId, (Vec<String>, clean::TypeKind)>;
 
+/// The options given on the command line which change how the crate is cleaned, or what is
+/// collected along with it for the rendering.
+pub struct DocOptions {
+    /// How to name the arguments which are destructured, as given by `--destructured-args`
+    pub destructured_args: clean::DestructuredArgs,
+    /// Whether the paths of the source pages link to their definition, as asked with
+    /// `--generate-link-to-definition`
+    pub generate_link_to_definition: bool,
+    /// Where the bounds of the type parameters are shown, as given by `--bounds-style`
+    pub bounds_style: clean::BoundsStyle,
+    /// Whether the public type aliases show the impls of the aliased type, as asked with
+    /// `--expand-type-aliases`
+    pub expand_type_aliases: bool,
+    /// Whether the blanket impls of unstable traits are marked as unstable, as asked with
+    /// `--document-unstable-impls`
+    pub document_unstable_impls: bool,
+    /// The dependencies whose traits and impls are left out, as given by `--mask-crate`
+    pub masked_crate_names: Vec<String>,
+    /// Whether the expanded source of the items generated by macros is collected, as asked with
+    /// `--render-macro-expansion`
+    pub render_macro_expansion: bool,
+}
+
 pub struct DocContext<'a, 'tcx: 'a, 'rcx: 'a, 'cstore: 'rcx> {
     pub tcx: TyCtxt<'a, 'tcx, 'tcx>,
     pub resolver: &'a RefCell<resolve::Resolver<'rcx, 'cstore>>,
@@ -80,8 +107,8 @@ pub struct DocContext<'a, 'tcx: 'a, 'rcx: 'a, 'cstore: 'rcx> {
     pub ty_substs: RefCell<FxHashMap<Def, clean::Type>>,
     /// Table node id of lifetime parameter definition -> substituted lifetime
     pub lt_substs: RefCell<FxHashMap<DefId, clean::Lifetime>>,
-    /// Table DefId of `impl Trait` in argument position -> bounds
-    pub impl_trait_bounds: RefCell<FxHashMap<DefId, Vec<clean::GenericBound>>>,
+    /// Table synthetic type parameter of `impl Trait` in argument position -> bounds
+    pub impl_trait_bounds: RefCell<FxHashMap<clean::ImplTraitParam, Vec<clean::GenericBound>>>,
     pub send_trait: Option<DefId>,
     pub fake_def_ids: RefCell<FxHashMap<CrateNum, DefId>>,
     pub all_fake_def_ids: RefCell<FxHashSet<DefId>>,
@@ -89,6 +116,19 @@ pub struct DocContext<'a, 'tcx: 'a, 'rcx: 'a, 'cstore: 'rcx> {
     pub generated_synthetics: RefCell<FxHashSet<(DefId, DefId)>>,
     pub current_item_name: RefCell<Option<Name>>,
     pub all_traits: Vec<DefId>,
+    /// How to name the arguments which are destructured, as given by `--destructured-args`
+    pub destructured_args: clean::DestructuredArgs,
+    /// Where the bounds of the type parameters are shown, as given by `--bounds-style`
+    pub bounds_style: clean::BoundsStyle,
+    /// Whether the public type aliases show the impls of the aliased type, as given by
+    /// `--expand-type-aliases`
+    pub expand_type_aliases: bool,
+    /// Whether the blanket impls of unstable traits are marked as unstable, as given by
+    /// `--document-unstable-impls`
+    pub document_unstable_impls: bool,
+    /// The dependencies whose traits and impls are left out, as given by `--mask-crate` or by
+    /// `#[doc(masked)]` on their `extern crate`
+    pub masked_crates: FxHashSet<CrateNum>,
 }
 
 impl<'a, 'tcx, 'rcx, 'cstore> DocContext<'a, 'tcx, 'rcx, 'cstore> {
@@ -308,6 +348,17 @@ pub fn new_handler(error_format: ErrorOutputType, codemap: Option<Lrc<codemap::C
     )
 }
 
+/// Builds the configuration used to strip `#[cfg]`'d items, exactly like rustc does: the `--cfg`
+/// flags given on the command line plus the configuration and features of the target. This is
+/// shared by the documentation and the doctests so that both see the same items.
+pub fn crate_config(sess: &session::Session,
+                    cfgs: Vec<String>,
+                    codegen_backend: &dyn CodegenBackend) -> ast::CrateConfig {
+    let mut cfg = config::build_configuration(sess, config::parse_cfgspecs(cfgs));
+    target_features::add_configuration(&mut cfg, sess, codegen_backend);
+    cfg
+}
+
 pub fn run_core(search_paths: SearchPaths,
                 cfgs: Vec<String>,
                 externs: config::Externs,
@@ -322,8 +373,19 @@ pub fn run_core(search_paths: SearchPaths,
                 error_format: ErrorOutputType,
                 cmd_lints: Vec<(String, lint::Level)>,
                 lint_cap: Option<lint::Level>,
-                describe_lints: bool) -> (clean::Crate, RenderInfo)
+                describe_lints: bool,
+                options: DocOptions) -> (clean::Crate, RenderInfo, bool)
 {
+    let DocOptions {
+        destructured_args,
+        generate_link_to_definition,
+        bounds_style,
+        expand_type_aliases,
+        document_unstable_impls,
+        masked_crate_names,
+        render_macro_expansion,
+    } = options;
+
     // Parse, resolve, and typecheck the given crate.
 
     let cpath = match input {
@@ -332,6 +394,8 @@ pub fn run_core(search_paths: SearchPaths,
     };
 
     let intra_link_resolution_failure_name = lint::builtin::INTRA_DOC_LINK_RESOLUTION_FAILURE.name;
+    let intra_link_to_deprecated_name = lint::builtin::INTRA_DOC_LINK_TO_DEPRECATED.name;
+    let private_module_types_name = lint::builtin::PUBLIC_TYPE_IN_PRIVATE_MODULE.name;
     let warnings_lint_name = lint::builtin::WARNINGS.name;
     let missing_docs = rustc_lint::builtin::MISSING_DOCS.name;
 
@@ -339,6 +403,8 @@ pub fn run_core(search_paths: SearchPaths,
     // command line, otherwise they'll get ignored and we don't want that.
     let mut whitelisted_lints = vec![warnings_lint_name.to_owned(),
                                      intra_link_resolution_failure_name.to_owned(),
+                                     intra_link_to_deprecated_name.to_owned(),
+                                     private_module_types_name.to_owned(),
                                      missing_docs.to_owned()];
 
     whitelisted_lints.extend(cmd_lints.iter().map(|(lint, _)| lint).cloned());
@@ -348,7 +414,9 @@ pub fn run_core(search_paths: SearchPaths,
                     .chain(rustc_lint::SoftLints.get_lints().into_iter())
                     .filter_map(|lint| {
                         if lint.name == warnings_lint_name ||
-                           lint.name == intra_link_resolution_failure_name {
+                           lint.name == intra_link_resolution_failure_name ||
+                           lint.name == intra_link_to_deprecated_name ||
+                           lint.name == private_module_types_name {
                             None
                         } else {
                             Some((lint.name_lower(), lint::Allow))
@@ -413,9 +481,7 @@ pub fn run_core(search_paths: SearchPaths,
         let cstore = Rc::new(CStore::new(codegen_backend.metadata_loader()));
         rustc_lint::register_builtins(&mut sess.lint_store.borrow_mut(), Some(&sess));
 
-        let mut cfg = config::build_configuration(&sess, config::parse_cfgspecs(cfgs));
-        target_features::add_configuration(&mut cfg, &sess, &*codegen_backend);
-        sess.parse_sess.config = cfg;
+        sess.parse_sess.config = crate_config(&sess, cfgs, &*codegen_backend);
 
         let control = &driver::CompileController::basic();
 
@@ -504,6 +570,8 @@ pub fn run_core(search_paths: SearchPaths,
                 clean::path_to_def(&tcx, &["core", "marker", "Send"])
             };
 
+            let masked_crates = masked_crates(tcx, &masked_crate_names);
+
             let ctxt = DocContext {
                 tcx,
                 resolver: &resolver,
@@ -523,7 +591,16 @@ pub fn run_core(search_paths: SearchPaths,
                 all_fake_def_ids: RefCell::new(FxHashSet()),
                 generated_synthetics: RefCell::new(FxHashSet()),
                 current_item_name: RefCell::new(None),
-                all_traits: tcx.all_traits(LOCAL_CRATE).to_vec(),
+                all_traits: tcx.all_traits(LOCAL_CRATE)
+                               .iter()
+                               .filter(|did| !masked_crates.contains(&did.krate))
+                               .cloned()
+                               .collect(),
+                destructured_args,
+                bounds_style,
+                expand_type_aliases,
+                document_unstable_impls,
+                masked_crates,
             };
             debug!("crate: {:?}", tcx.hir.krate());
 
@@ -533,7 +610,106 @@ pub fn run_core(search_paths: SearchPaths,
                 v.clean(&ctxt)
             };
 
-            (krate, ctxt.renderinfo.into_inner())
+            warn_private_module_types(&ctxt);
+            if generate_link_to_definition {
+                ctxt.renderinfo.borrow_mut().span_map = span_map::collect(tcx);
+            }
+            if render_macro_expansion {
+                ctxt.renderinfo.borrow_mut().macro_expansions = macro_expansions(tcx);
+            }
+
+            // The lints denied on the crate are reported as errors along the way.
+            (krate, ctxt.renderinfo.into_inner(), tcx.sess.has_errors())
         }), &sess)
     })
 }
+
+/// Returns the crates named by `--mask-crate` along with the ones whose `extern crate` has
+/// `#[doc(masked)]`.
+fn masked_crates(tcx: TyCtxt, names: &[String]) -> FxHashSet<CrateNum> {
+    use clean::{AttributesExt, NestedAttributesExt};
+
+    let mut masked_crates = FxHashSet();
+    for name in names {
+        match tcx.crates().iter().find(|&&cnum| tcx.crate_name(cnum) == &**name) {
+            Some(&cnum) => { masked_crates.insert(cnum); }
+            None => tcx.sess.warn(&format!("--mask-crate: there's no crate named `{}`", name)),
+        }
+    }
+    for item in tcx.hir.krate().items.values() {
+        if let hir::ItemKind::ExternCrate(_) = item.node {
+            if item.attrs.lists("doc").has_word("masked") {
+                let def_id = tcx.hir.local_def_id(item.id);
+                masked_crates.extend(tcx.extern_mod_stmt_cnum(def_id));
+            }
+        }
+    }
+    masked_crates
+}
+
+/// Returns the expanded source of the items of the crate which were generated by a macro,
+/// for the ones which get a page.
+fn macro_expansions(tcx: TyCtxt) -> FxHashMap<DefId, String> {
+    tcx.hir.krate().items.values().filter(|item| {
+        item.span.ctxt() != SyntaxContext::empty()
+    }).filter(|item| match item.node {
+        hir::ItemKind::ExternCrate(..) | hir::ItemKind::Use(..) | hir::ItemKind::Impl(..) |
+        hir::ItemKind::GlobalAsm(..) => false,
+        _ => true,
+    }).map(|item| {
+        (tcx.hir.local_def_id(item.id), tcx.hir.node_to_pretty_string(item.id))
+    }).collect()
+}
+
+/// Warns about types which appear in the public API of the crate but can't be named from the
+/// outside because one of their parent modules is private. Such types don't get a page of their
+/// own, so any link to them is lost.
+fn warn_private_module_types(cx: &DocContext) {
+    use rustc::ty::DefIdTree;
+
+    let access_levels = cx.access_levels.borrow();
+    let mut leaked = access_levels.map.iter()
+        .filter(|&(_, &level)| level == AccessLevel::Reachable)
+        .map(|(&did, _)| did)
+        .filter(|&did| match cx.tcx.describe_def(did) {
+            Some(Def::Struct(..)) | Some(Def::Enum(..)) | Some(Def::Union(..)) |
+            Some(Def::Trait(..)) | Some(Def::TyAlias(..)) => {
+                cx.tcx.visibility(did) == ty::Visibility::Public
+            }
+            _ => false,
+        })
+        .collect::<Vec<_>>();
+    // Keep the output order stable.
+    leaked.sort_by_key(|did| did.index);
+
+    for did in leaked {
+        let node_id = match cx.tcx.hir.as_local_node_id(did) {
+            Some(node_id) => node_id,
+            None => continue,
+        };
+        let mut parent = cx.tcx.parent(did);
+        while let Some(module) = parent {
+            if module.index == CRATE_DEF_INDEX {
+                break;
+            }
+            if cx.tcx.visibility(module) != ty::Visibility::Public {
+                let path = cx.tcx.item_path_str(did);
+                let mut diag = cx.tcx.struct_span_lint_node(
+                    lint::builtin::PUBLIC_TYPE_IN_PRIVATE_MODULE,
+                    node_id,
+                    cx.tcx.def_span(did),
+                    &format!("`{}` is part of the public API but is only reachable through a \
+                              private module",
+                             path));
+                diag.span_note(cx.tcx.def_span(module),
+                               &format!("module `{}` is private",
+                                        cx.tcx.item_path_str(module)));
+                diag.help(&format!("consider re-exporting `{}` from a public module",
+                                   cx.tcx.item_name(did)));
+                diag.emit();
+                break;
+            }
+            parent = cx.tcx.parent(module);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Documentation coverage statistics, displayed by `--show-coverage`.
+
+use std::collections::BTreeMap;
+use std::ops;
+
+use serialize::json::{Json, ToJson};
+
+use clean::{self, Item};
+use fold::DocFolder;
+use html::markdown;
+
+#[derive(Default, Copy, Clone)]
+struct ItemCount {
+    total: u64,
+    with_docs: u64,
+    with_examples: u64,
+}
+
+impl ItemCount {
+    fn count_item(&mut self, has_docs: bool, has_example: bool) {
+        self.total += 1;
+        if has_docs {
+            self.with_docs += 1;
+        }
+        if has_example {
+            self.with_examples += 1;
+        }
+    }
+
+    fn percentage(&self, count: u64) -> f64 {
+        if self.total > 0 {
+            (count as f64 * 100.0) / self.total as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+impl ops::AddAssign for ItemCount {
+    fn add_assign(&mut self, rhs: Self) {
+        self.total += rhs.total;
+        self.with_docs += rhs.with_docs;
+        self.with_examples += rhs.with_examples;
+    }
+}
+
+impl ToJson for ItemCount {
+    fn to_json(&self) -> Json {
+        let mut data = BTreeMap::new();
+        data.insert("total".to_owned(), self.total.to_json());
+        data.insert("with_docs".to_owned(), self.with_docs.to_json());
+        data.insert("with_examples".to_owned(), self.with_examples.to_json());
+        Json::Object(data)
+    }
+}
+
+/// Walks the (already stripped) crate and counts its items, grouped by the module they're
+/// declared in.
+#[derive(Default)]
+struct CoverageCalculator {
+    current_module: Vec<String>,
+    modules: BTreeMap<String, ItemCount>,
+}
+
+impl DocFolder for CoverageCalculator {
+    fn fold_item(&mut self, i: Item) -> Option<Item> {
+        match i.inner {
+            // Stripped items aren't part of the public API.
+            clean::StrippedItem(..) => return Some(i),
+            // Imports and extern crates don't get documentation of their own.
+            clean::ImportItem(..) | clean::ExternCrateItem(..) => return Some(i),
+            // Trait implementations inherit the documentation of the trait.
+            clean::ImplItem(ref impl_) if impl_.trait_.is_some() => return Some(i),
+            // Only the items of inherent impls are counted, not the impl block itself.
+            clean::ImplItem(..) => return self.fold_item_recur(i),
+            _ => {}
+        }
+
+        let is_module = i.is_mod();
+        if is_module {
+            self.current_module.push(i.name.clone().unwrap_or_default());
+        }
+
+        let doc = i.attrs.collapsed_doc_value().unwrap_or_default();
+        let has_docs = !doc.trim().is_empty();
+        let has_example = has_docs && markdown::contains_rust_example(&doc);
+        self.modules.entry(self.current_module.join("::"))
+                    .or_insert_with(ItemCount::default)
+                    .count_item(has_docs, has_example);
+
+        let ret = self.fold_item_recur(i);
+        if is_module {
+            self.current_module.pop();
+        }
+        ret
+    }
+}
+
+/// Computes the documentation coverage of the public API of the crate and prints it to stdout,
+/// either as a table or as JSON.
+pub fn print(krate: clean::Crate, as_json: bool) {
+    let mut calculator = CoverageCalculator::default();
+    // Only the crate's own module tree is walked: external traits aren't part of its API.
+    calculator.fold_item(krate.module.expect("crate without a module"));
+
+    if as_json {
+        let modules = calculator.modules.iter()
+                                        .map(|(name, count)| (name.clone(), count.to_json()))
+                                        .collect();
+        println!("{}", Json::Object(modules));
+        return;
+    }
+
+    fn print_line(name: &str, count: &ItemCount) {
+        // Long module paths are truncated from the left, their end being more meaningful.
+        let name = if name.chars().count() > 35 {
+            let skip = name.chars().count() - 32;
+            format!("...{}", name.chars().skip(skip).collect::<String>())
+        } else {
+            name.to_owned()
+        };
+        println!("| {:<35} | {:>10} | {:>10} | {:>9.1}% | {:>9.1}% |",
+                 name,
+                 count.with_docs,
+                 count.total,
+                 count.percentage(count.with_docs),
+                 count.percentage(count.with_examples));
+    }
+
+    let separator = format!("+-{0:-<35}-+-{0:-<10}-+-{0:-<10}-+-{0:-<10}-+-{0:-<10}-+", "");
+    println!("{}", separator);
+    println!("| {:<35} | {:>10} | {:>10} | {:>10} | {:>10} |",
+             "Module", "Documented", "Total", "Percentage", "Examples");
+    println!("{}", separator);
+    let mut total = ItemCount::default();
+    for (name, count) in &calculator.modules {
+        print_line(name, count);
+        total += *count;
+    }
+    println!("{}", separator);
+    print_line("Total", &total);
+    println!("{}", separator);
+}
@@ -17,6 +17,7 @@ use syntax::ast::{Name, NodeId};
 use syntax::attr;
 use syntax::ptr::P;
 use syntax::codemap::Spanned;
+use syntax::ext::base::MacroKind;
 use syntax_pos::{self, Span};
 
 use rustc::hir;
@@ -40,12 +41,14 @@ pub struct Module {
     pub statics: Vec<Static>,
     pub constants: Vec<Constant>,
     pub traits: Vec<Trait>,
+    pub trait_aliases: Vec<TraitAlias>,
     pub vis: hir::Visibility,
     pub stab: Option<attr::Stability>,
     pub depr: Option<attr::Deprecation>,
     pub impls: Vec<Impl>,
     pub foreigns: Vec<hir::ForeignMod>,
     pub macros: Vec<Macro>,
+    pub proc_macros: Vec<ProcMacro>,
     pub is_crate: bool,
 }
 
@@ -72,9 +75,11 @@ impl Module {
             statics    : Vec::new(),
             constants  : Vec::new(),
             traits     : Vec::new(),
+            trait_aliases: Vec::new(),
             impls      : Vec::new(),
             foreigns   : Vec::new(),
             macros     : Vec::new(),
+            proc_macros: Vec::new(),
             is_crate   : false,
         }
     }
@@ -215,6 +220,18 @@ pub struct Trait {
     pub depr: Option<attr::Deprecation>,
 }
 
+pub struct TraitAlias {
+    pub name: Name,
+    pub generics: hir::Generics,
+    pub bounds: hir::HirVec<hir::GenericBound>,
+    pub attrs: hir::HirVec<ast::Attribute>,
+    pub id: ast::NodeId,
+    pub whence: Span,
+    pub vis: hir::Visibility,
+    pub stab: Option<attr::Stability>,
+    pub depr: Option<attr::Deprecation>,
+}
+
 #[derive(Debug)]
 pub struct Impl {
     pub unsafety: hir::Unsafety,
@@ -245,6 +262,17 @@ pub struct Macro {
     pub imported_from: Option<Name>,
 }
 
+pub struct ProcMacro {
+    pub name: Name,
+    pub id: NodeId,
+    pub kind: MacroKind,
+    pub helpers: Vec<Name>,
+    pub attrs: hir::HirVec<ast::Attribute>,
+    pub whence: Span,
+    pub stab: Option<attr::Stability>,
+    pub depr: Option<attr::Deprecation>,
+}
+
 pub struct ExternCrate {
     pub name: Name,
     pub cnum: CrateNum,
@@ -0,0 +1,222 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The prefixing of the CSS classes of the documentation with `--css-class-prefix`, so that the
+//! pages can be embedded into a site whose stylesheets use the same class names.
+//!
+//! The pages and the stylesheets are prefixed once they're generated, rather than by each piece
+//! of the renderer writing a `class` attribute. The scripts get the prefix from
+//! `window.classPrefix`, which the pages set.
+
+/// Returns whether `prefix` can start the name of a class in the stylesheets without escaping.
+pub fn is_valid(prefix: &str) -> bool {
+    match prefix.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '-' => {}
+        _ => return false,
+    }
+    prefix.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Prefixes the classes of the `class` attributes of the tags of `html`. The comments and the
+/// contents of the scripts are left untouched.
+pub fn prefix_classes(html: &str, prefix: &str) -> String {
+    let mut out = String::with_capacity(html.len() + html.len() / 8);
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if rest.starts_with("<!--") {
+            let end = rest.find("-->").map_or(rest.len(), |end| end + 3);
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        if !rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            out.push('<');
+            rest = &rest[1..];
+            continue;
+        }
+        let end = tag_end(rest);
+        prefix_tag(&rest[..end], prefix, &mut out);
+        let is_script = rest.starts_with("<script");
+        rest = &rest[end..];
+        if is_script {
+            let end = rest.find("</script>").unwrap_or(rest.len());
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Returns the length of the tag which starts `html`, up to its closing `>`.
+fn tag_end(html: &str) -> usize {
+    let mut quote = None;
+    for (i, b) in html.bytes().enumerate() {
+        match quote {
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None if b == b'"' || b == b'\'' => quote = Some(b),
+            None if b == b'>' => return i + 1,
+            None => {}
+        }
+    }
+    html.len()
+}
+
+/// Writes `tag` to `out` with the classes of its `class` attribute prefixed.
+fn prefix_tag(tag: &str, prefix: &str, out: &mut String) {
+    let bytes = tag.as_bytes();
+    let mut copied = 0;
+    let mut quote = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        match quote {
+            Some(q) if bytes[i] == q => quote = None,
+            Some(_) => {}
+            None if bytes[i] == b'"' || bytes[i] == b'\'' => quote = Some(bytes[i]),
+            None if bytes[i..].starts_with(b"class=") && bytes[i - 1].is_ascii_whitespace() => {
+                let start = i + "class=".len();
+                let q = match bytes.get(start) {
+                    Some(&q) if q == b'"' || q == b'\'' => q,
+                    _ => {
+                        i += 1;
+                        continue;
+                    }
+                };
+                let end = match bytes[start + 1..].iter().position(|&b| b == q) {
+                    Some(len) => start + 1 + len,
+                    None => break,
+                };
+                out.push_str(&tag[copied..start + 1]);
+                let classes = tag[start + 1..end].split_whitespace()
+                                                 .map(|class| format!("{}{}", prefix, class))
+                                                 .collect::<Vec<_>>();
+                out.push_str(&classes.join(" "));
+                copied = end;
+                i = end;
+            }
+            None => {}
+        }
+        i += 1;
+    }
+    out.push_str(&tag[copied..]);
+}
+
+/// Prefixes the class selectors of the rules of the stylesheet `css`, including the ones nested
+/// in `@media` and `@supports` blocks. The declarations, strings and comments are left untouched.
+pub fn prefix_selectors(css: &str, prefix: &str) -> String {
+    let mut out = String::with_capacity(css.len() + css.len() / 8);
+    // For each open block, whether it holds declarations rather than rules.
+    let mut blocks = Vec::new();
+    // The selectors, or the at-rule, being read.
+    let mut prelude = String::new();
+    let mut chars = css.chars().peekable();
+    while let Some(c) = chars.next() {
+        out.push(c);
+        let in_declarations = blocks.last() == Some(&true);
+        match c {
+            '/' if chars.peek() == Some(&'*') => {
+                let mut last = ' ';
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+            }
+            '"' | '\'' => {
+                while let Some(next) = chars.next() {
+                    out.push(next);
+                    if next == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            out.push(escaped);
+                        }
+                    } else if next == c {
+                        break;
+                    }
+                }
+            }
+            '{' => {
+                let at_rule = prelude.trim_left();
+                blocks.push(!at_rule.starts_with("@media") && !at_rule.starts_with("@supports"));
+                prelude.clear();
+            }
+            '}' => {
+                blocks.pop();
+                prelude.clear();
+            }
+            ';' if !in_declarations => prelude.clear(),
+            '.' if !in_declarations && !prelude.trim_left().starts_with('@') => {
+                let starts_class = chars.peek().map_or(false, |&c| {
+                    c.is_ascii_alphabetic() || c == '_' || c == '-'
+                });
+                if starts_class {
+                    out.push_str(prefix);
+                }
+            }
+            _ if !in_declarations => prelude.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid, prefix_classes, prefix_selectors};
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid("rustdoc-"));
+        assert!(is_valid("_doc"));
+        assert!(!is_valid(""));
+        assert!(!is_valid("2doc-"));
+        assert!(!is_valid("doc prefix"));
+        assert!(!is_valid("doc."));
+    }
+
+    #[test]
+    fn test_prefix_classes() {
+        fn t(html: &str, expected: &str) {
+            assert_eq!(prefix_classes(html, "rd-"), expected);
+        }
+
+        t("<div class='docblock'>text</div>", "<div class='rd-docblock'>text</div>");
+        t("<tr class=\"struct result\">", "<tr class=\"rd-struct rd-result\">");
+        t("<a href='#class=' title=\"x\" class=' module-item'>",
+          "<a href='#class=' title=\"x\" class='rd-module-item'>");
+        t("<h3 id='impl' class='impl'>a &lt; b</h3>",
+          "<h3 id='impl' class='rd-impl'>a &lt; b</h3>");
+        t("<p>class='not-a-tag'</p>", "<p>class='not-a-tag'</p>");
+        t("<!-- <div class='hidden'> -->", "<!-- <div class='hidden'> -->");
+        t("<script>x = \"<div class='inner'>\";</script><span class='x'>",
+          "<script>x = \"<div class='inner'>\";</script><span class='rd-x'>");
+        t("<div data-class='a'>", "<div data-class='a'>");
+    }
+
+    #[test]
+    fn test_prefix_selectors() {
+        fn t(css: &str, expected: &str) {
+            assert_eq!(prefix_selectors(css, "rd-"), expected);
+        }
+
+        t(".docblock, .sidebar > .block a { opacity: .5; }",
+          ".rd-docblock, .rd-sidebar > .rd-block a { opacity: .5; }");
+        t("@media (max-width: 700px) { .sidebar:not(.mobile) { width: 0.5em; } }",
+          "@media (max-width: 700px) { .rd-sidebar:not(.rd-mobile) { width: 0.5em; } }");
+        t("@font-face { src: url(\"a.woff\") format('woff'); } body { margin: 0; }",
+          "@font-face { src: url(\"a.woff\") format('woff'); } body { margin: 0; }");
+        t("/* .comment */ a[href$=\".html\"] .x {}", "/* .comment */ a[href$=\".html\"] .rd-x {}");
+        t("@import url(a.css); .y {}", "@import url(a.css); .rd-y {}");
+    }
+}
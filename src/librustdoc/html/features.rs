@@ -0,0 +1,124 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The "Features" page of a crate, listing the feature flags given with `--crate-features`.
+//!
+//! rustdoc doesn't read `Cargo.toml`, so the features are described by a JSON object mapping
+//! each feature to either the list of the features and dependencies it enables, as in the
+//! `[features]` table, or to an object with an `enables` list and a Markdown `description`:
+//!
+//! ```json
+//! {
+//!     "default": ["std"],
+//!     "std": { "description": "Implements the traits of `std`.", "enables": [] }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serialize::json::{self, Json};
+
+use html::escape::Escape;
+use html::markdown::{ErrorCodes, IdMap, MarkdownHtml};
+
+pub struct Feature {
+    pub name: String,
+    pub description: String,
+    pub enables: Vec<String>,
+}
+
+/// Reads the features described in the JSON file at `path`, the `default` feature first.
+pub fn load(path: &Path) -> Result<Vec<Feature>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let json = json::from_str(&contents).map_err(|e| e.to_string())?;
+    let object = match json {
+        Json::Object(object) => object,
+        _ => return Err("expected an object mapping the features to their description".into()),
+    };
+
+    let mut features = Vec::with_capacity(object.len());
+    for (name, value) in object {
+        let (description, enables) = match value {
+            Json::Array(enables) => (None, Some(enables)),
+            Json::Object(mut fields) => {
+                let description = match fields.remove("description") {
+                    Some(Json::String(description)) => Some(description),
+                    None => None,
+                    Some(_) => {
+                        return Err(format!("the description of `{}` isn't a string", name));
+                    }
+                };
+                let enables = match fields.remove("enables") {
+                    Some(Json::Array(enables)) => Some(enables),
+                    None => None,
+                    Some(_) => return Err(format!("the `enables` of `{}` isn't a list", name)),
+                };
+                (description, enables)
+            }
+            _ => return Err(format!("`{}` is neither a list nor an object", name)),
+        };
+        let enables = enables.unwrap_or_default().into_iter().map(|enabled| {
+            match enabled {
+                Json::String(enabled) => Ok(enabled),
+                _ => Err(format!("`{}` enables something which isn't a string", name)),
+            }
+        }).collect::<Result<Vec<_>, _>>()?;
+        features.push(Feature { name, description: description.unwrap_or_default(), enables });
+    }
+    // The object is sorted by name, but `default` is what users get without asking.
+    features.sort_by_key(|feature| feature.name != "default");
+    Ok(features)
+}
+
+/// The contents of the `features.html` page of a crate.
+pub struct FeaturesPage<'a> {
+    pub krate: &'a str,
+    pub features: &'a [Feature],
+    pub codes: ErrorCodes,
+}
+
+impl<'a> fmt::Display for FeaturesPage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<h1 class='fqn'><span class='in-band'>Features of \
+                   <a class='mod' href='index.html'>{}</a></span></h1>", self.krate)?;
+        if self.features.is_empty() {
+            return write!(f, "<p>This crate has no features.</p>");
+        }
+        let mut ids = IdMap::new();
+        for feature in self.features {
+            write!(f, "<h3 id='feature.{name}' class='feature'><code>{name}</code>\
+                       <a href='#feature.{name}' class='anchor'></a></h3>",
+                   name = Escape(&feature.name))?;
+            if !feature.enables.is_empty() {
+                write!(f, "<p class='feature-enables'>Enables ")?;
+                for (i, enabled) in feature.enables.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    if self.features.iter().any(|feature| &feature.name == enabled) {
+                        write!(f, "<a href='#feature.{name}'><code>{name}</code></a>",
+                               name = Escape(enabled))?;
+                    } else {
+                        write!(f, "<code>{}</code>", Escape(enabled))?;
+                    }
+                }
+                write!(f, "</p>")?;
+            }
+            if !feature.description.is_empty() {
+                write!(f, "<div class='docblock'>{}</div>",
+                       MarkdownHtml(&feature.description, RefCell::new(&mut ids), self.codes))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ use rustc::hir;
 
 use clean::{self, PrimitiveType};
 use core::DocAccessLevels;
+use html::escape::Escape;
 use html::item_type::ItemType;
 use html::render::{self, cache, CURRENT_LOCATION_KEY};
 
@@ -30,6 +31,10 @@ use html::render::{self, cache, CURRENT_LOCATION_KEY};
 /// visibility is preset)
 #[derive(Copy, Clone)]
 pub struct VisSpace<'a>(pub &'a Option<clean::Visibility>);
+/// Like VisSpace, but only renders the restricted visibilities, such as `pub(crate)`, which are
+/// only documented along with the private items.
+#[derive(Copy, Clone)]
+pub struct RestrictedVisSpace<'a>(pub &'a Option<clean::Visibility>);
 /// Similarly to VisSpace, this structure is used to render a function style with a
 /// space after it.
 #[derive(Copy, Clone)]
@@ -406,6 +411,10 @@ pub fn href(did: DefId) -> Option<(String, ItemType, Vec<String>)> {
 
     let loc = CURRENT_LOCATION_KEY.with(|l| l.borrow().clone());
     let (fqp, shortty, mut url) = match cache.paths.get(&did) {
+        Some(&(ref fqp, shortty)) if render::SINGLE_PAGE_KEY.with(|s| s.get()) => {
+            let anchor = format!("#{}", render::single_page_anchor(fqp, shortty));
+            return Some((anchor, shortty, fqp.clone()));
+        }
         Some(&(ref fqp, shortty)) => {
             (fqp, shortty, "../".repeat(loc.len()))
         }
@@ -537,8 +546,13 @@ impl<'a> fmt::Display for HRef<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match href(self.did) {
             Some((url, shortty, fqp)) => if !f.alternate() {
-                write!(f, "<a class=\"{}\" href=\"{}\" title=\"{} {}\">{}</a>",
-                       shortty, url, shortty, fqp.join("::"), self.text)
+                write!(f, "<a class=\"{}\" href=\"{}\" title=\"{} {}",
+                       shortty, url, shortty, fqp.join("::"))?;
+                if let Some(summary) = cache().summaries.as_ref()
+                                              .and_then(|s| s.get(&self.did)) {
+                    write!(f, ": {}", Escape(summary))?;
+                }
+                write!(f, "\">{}</a>", self.text)
             } else {
                 write!(f, "{}", self.text)
             },
@@ -553,6 +567,10 @@ fn fmt_type(t: &clean::Type, f: &mut fmt::Formatter, use_absolute: bool) -> fmt:
             f.write_str(name)
         }
         clean::ResolvedPath{ did, ref typarams, ref path, is_generic } => {
+            // Trait objects are the only paths carrying bounds.
+            if typarams.is_some() {
+                f.write_str("dyn ")?;
+            }
             // Paths like T::Output and Self::Output should be rendered with all segments
             resolved_path(f, did, path, is_generic, use_absolute)?;
             tybounds(f, typarams)
@@ -742,9 +760,9 @@ fn fmt_impl(i: &clean::Impl,
             link_trait: bool,
             use_absolute: bool) -> fmt::Result {
     if f.alternate() {
-        write!(f, "impl{:#} ", i.generics)?;
+        write!(f, "{}impl{:#} ", UnsafetySpace(i.unsafety), i.generics)?;
     } else {
-        write!(f, "impl{} ", i.generics)?;
+        write!(f, "{}impl{} ", UnsafetySpace(i.unsafety), i.generics)?;
     }
 
     if let Some(ref ty) = i.trait_ {
@@ -752,6 +770,9 @@ fn fmt_impl(i: &clean::Impl,
             write!(f, "!")?;
         }
 
+        let with_output = trait_with_output(i, ty);
+        let ty = with_output.as_ref().unwrap_or(ty);
+
         if link_trait {
             fmt::Display::fmt(ty, f)?;
         } else {
@@ -777,6 +798,41 @@ fn fmt_impl(i: &clean::Impl,
     Ok(())
 }
 
+/// For the operator traits of `core::ops`, returns the implemented trait with the `Output`
+/// associated type of the impl bound inline, like `Add<Rhs, Output = O>`.
+fn trait_with_output(i: &clean::Impl, trait_: &clean::Type) -> Option<clean::Type> {
+    const OPERATOR_TRAITS: &[&str] = &["Add", "BitAnd", "BitOr", "BitXor", "Div", "Mul", "Neg",
+                                       "Not", "Rem", "Shl", "Shr", "Sub"];
+
+    let did = trait_.def_id()?;
+    let cache = cache();
+    let fqp = &cache.paths.get(&did).or_else(|| cache.external_paths.get(&did))?.0;
+    let is_operator = fqp.len() > 2 &&
+                      (fqp[0] == "core" || fqp[0] == "std") &&
+                      fqp[1] == "ops" &&
+                      OPERATOR_TRAITS.contains(&&*fqp[fqp.len() - 1]);
+    if !is_operator {
+        return None;
+    }
+
+    let output = i.items.iter().filter_map(|item| match item.inner {
+        clean::TypedefItem(ref tydef, true) if item.name == Some("Output".to_string()) => {
+            Some(tydef.type_.clone())
+        }
+        _ => None,
+    }).next()?;
+
+    let mut trait_ = trait_.clone();
+    if let clean::ResolvedPath { ref mut path, .. } = trait_ {
+        if let Some(segment) = path.segments.last_mut() {
+            if let clean::GenericArgs::AngleBracketed { ref mut bindings, .. } = segment.args {
+                bindings.push(clean::TypeBinding { name: "Output".to_string(), ty: output });
+            }
+        }
+    }
+    Some(trait_)
+}
+
 impl fmt::Display for clean::Impl {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt_impl(self, f, true, false)
@@ -950,6 +1006,17 @@ impl<'a> fmt::Display for VisSpace<'a> {
     }
 }
 
+impl<'a> fmt::Display for RestrictedVisSpace<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self.0 {
+            Some(clean::Visibility::Crate) | Some(clean::Visibility::Restricted(..)) => {
+                write!(f, "{}", VisSpace(self.0))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 impl fmt::Display for UnsafetySpace {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.get() {
@@ -21,6 +21,7 @@ use std::fmt::Display;
 use std::io;
 use std::io::prelude::*;
 
+use rustc::util::nodemap::FxHashMap;
 use syntax::codemap::{CodeMap, FilePathMapping};
 use syntax::parse::lexer::{self, TokenAndSpan};
 use syntax::parse::token;
@@ -31,6 +32,19 @@ use syntax_pos::{Span, FileName};
 pub fn render_with_highlighting(src: &str, class: Option<&str>,
                                 extension: Option<&str>,
                                 tooltip: Option<(&str, &str)>) -> String {
+    render(src, class, extension, tooltip, None)
+}
+
+/// Highlights the source code of a file, turning the identifiers found at the
+/// given byte ranges into links to the given URLs.
+pub fn render_source_with_links(src: &str, links: &FxHashMap<(u32, u32), String>) -> String {
+    render(src, None, None, None, Some(links))
+}
+
+fn render(src: &str, class: Option<&str>,
+          extension: Option<&str>,
+          tooltip: Option<(&str, &str)>,
+          links: Option<&FxHashMap<(u32, u32), String>>) -> String {
     debug!("highlighting: ================\n{}\n==============", src);
     let sess = parse::ParseSess::new(FilePathMapping::empty());
     let fm = sess.codemap().new_filemap(FileName::Custom("stdin".to_string()), src.to_string());
@@ -43,7 +57,8 @@ pub fn render_with_highlighting(src: &str, class: Option<&str>,
     }
     write_header(class, &mut out).unwrap();
 
-    let mut classifier = Classifier::new(lexer::StringReader::new(&sess, fm, None), sess.codemap());
+    let mut classifier = Classifier::new(lexer::StringReader::new(&sess, fm, None), sess.codemap(),
+                                         links);
     if let Err(_) = classifier.write_source(&mut out) {
         return format!("<pre>{}</pre>", src);
     }
@@ -61,6 +76,7 @@ pub fn render_with_highlighting(src: &str, class: Option<&str>,
 struct Classifier<'a> {
     lexer: lexer::StringReader<'a>,
     codemap: &'a CodeMap,
+    links: Option<&'a FxHashMap<(u32, u32), String>>,
 
     // State of the classifier.
     in_attribute: bool,
@@ -145,10 +161,13 @@ impl<U: Write> Writer for U {
 }
 
 impl<'a> Classifier<'a> {
-    fn new(lexer: lexer::StringReader<'a>, codemap: &'a CodeMap) -> Classifier<'a> {
+    fn new(lexer: lexer::StringReader<'a>,
+           codemap: &'a CodeMap,
+           links: Option<&'a FxHashMap<(u32, u32), String>>) -> Classifier<'a> {
         Classifier {
             lexer,
             codemap,
+            links,
             in_attribute: false,
             in_macro: false,
             in_macro_nonterminal: false,
@@ -333,11 +352,26 @@ impl<'a> Classifier<'a> {
             token::Tilde | token::At | token::DotEq | token::SingleQuote => Class::None,
         };
 
+        if klass == Class::Ident {
+            if let Some(url) = self.link(tas.sp) {
+                let link = format!("<a href=\"{}\">{}</a>", url, Escape(&self.snip(tas.sp)));
+                return out.string(link, klass);
+            }
+        }
+
         // Anything that didn't return above is the simple case where we the
         // class just spans a single token, so we can use the `string` method.
         out.string(Escape(&self.snip(tas.sp)), klass)
     }
 
+    // Returns the URL the identifier at the given span links to, if any.
+    fn link(&self, sp: Span) -> Option<&'a str> {
+        let links = self.links?;
+        let lo = self.codemap.lookup_byte_offset(sp.lo()).pos.0;
+        let hi = self.codemap.lookup_byte_offset(sp.hi()).pos.0;
+        links.get(&(lo, hi)).map(|url| &**url)
+    }
+
     // Helper function to get a snippet from the codemap.
     fn snip(&self, sp: Span) -> String {
         self.codemap.span_to_snippet(sp).unwrap()
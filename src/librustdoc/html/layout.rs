@@ -13,6 +13,8 @@ use std::io;
 use std::path::PathBuf;
 
 use externalfiles::ExternalHtml;
+use html::class_prefix;
+use html::escape::Escape;
 
 #[derive(Clone)]
 pub struct Layout {
@@ -20,12 +22,27 @@ pub struct Layout {
     pub favicon: String,
     pub external_html: ExternalHtml,
     pub krate: String,
+    /// The contents of `search-index.js`, to embed into the pages instead of loading it, as
+    /// asked with `--inline-search-index`.
+    pub search_index: Option<String>,
+    /// The version of the crate, as given by `--crate-version`, recorded in the metadata of the
+    /// pages.
+    pub crate_version: Option<String>,
+    /// Whether the search box and the search scripts are left out of the pages, as asked with
+    /// `--no-search`.
+    pub no_search: bool,
+    /// The prefix of the classes of the pages and of their stylesheets, as given by
+    /// `--css-class-prefix`.
+    pub class_prefix: Option<String>,
 }
 
 pub struct Page<'a> {
     pub title: &'a str,
     pub css_class: &'a str,
     pub root_path: &'a str,
+    /// The number of directories between the page and the root of the documentation, which
+    /// `root_path` doesn't tell when it's given by `--html-root-path`.
+    pub depth: usize,
     pub description: &'a str,
     pub keywords: &'a str,
     pub resource_suffix: &'a str,
@@ -35,6 +52,21 @@ pub fn render<T: fmt::Display, S: fmt::Display>(
     dst: &mut dyn io::Write, layout: &Layout, page: &Page, sidebar: &S, t: &T,
     css_file_extension: bool, themes: &[PathBuf])
     -> io::Result<()>
+{
+    let prefix = match layout.class_prefix {
+        Some(ref prefix) => prefix,
+        None => return render_page(dst, layout, page, sidebar, t, css_file_extension, themes),
+    };
+    let mut html = Vec::new();
+    render_page(&mut html, layout, page, sidebar, t, css_file_extension, themes)?;
+    let html = class_prefix::prefix_classes(&String::from_utf8_lossy(&html), prefix);
+    dst.write_all(html.as_bytes())
+}
+
+fn render_page<T: fmt::Display, S: fmt::Display>(
+    dst: &mut dyn io::Write, layout: &Layout, page: &Page, sidebar: &S, t: &T,
+    css_file_extension: bool, themes: &[PathBuf])
+    -> io::Result<()>
 {
     write!(dst,
 "<!DOCTYPE html>\
@@ -45,6 +77,7 @@ pub fn render<T: fmt::Display, S: fmt::Display>(
     <meta name=\"generator\" content=\"rustdoc\">\
     <meta name=\"description\" content=\"{description}\">\
     <meta name=\"keywords\" content=\"{keywords}\">\
+    {crate_version}\
     <title>{title}</title>\
     <link rel=\"stylesheet\" type=\"text/css\" href=\"{root_path}normalize{suffix}.css\">\
     <link rel=\"stylesheet\" type=\"text/css\" href=\"{root_path}rustdoc{suffix}.css\" \
@@ -53,6 +86,7 @@ pub fn render<T: fmt::Display, S: fmt::Display>(
     <link rel=\"stylesheet\" type=\"text/css\" href=\"{root_path}dark{suffix}.css\">\
     <link rel=\"stylesheet\" type=\"text/css\" href=\"{root_path}light{suffix}.css\" \
           id=\"themeStyle\">\
+    {class_prefix}\
     <script src=\"{root_path}storage{suffix}.js\"></script>\
     {css_extension}\
     {favicon}\
@@ -81,10 +115,7 @@ pub fn render<T: fmt::Display, S: fmt::Display>(
     <nav class=\"sub\">\
         <form class=\"search-form js-only\">\
             <div class=\"search-container\">\
-                <input class=\"search-input\" name=\"search\" \
-                       autocomplete=\"off\" \
-                       placeholder=\"Click or press ‘S’ to search, ‘?’ for more options…\" \
-                       type=\"search\">\
+                {search_input}\
                 <a id=\"settings-menu\" href=\"{root_path}settings.html\">\
                     <img src=\"{root_path}wheel{suffix}.svg\" width=\"18\" alt=\"Change settings\">\
                 </a>\
@@ -118,37 +149,18 @@ pub fn render<T: fmt::Display, S: fmt::Display>(
                     <dd>Collapse all sections</dd>\
                 </dl>\
             </div>\
-            <div class=\"infos\">\
-                <h2>Search Tricks</h2>\
-                <p>\
-                    Prefix searches with a type followed by a colon (e.g. \
-                    <code>fn:</code>) to restrict the search to a given type.\
-                </p>\
-                <p>\
-                    Accepted types are: <code>fn</code>, <code>mod</code>, \
-                    <code>struct</code>, <code>enum</code>, \
-                    <code>trait</code>, <code>type</code>, <code>macro</code>, \
-                    and <code>const</code>.\
-                </p>\
-                <p>\
-                    Search functions by type signature (e.g. \
-                    <code>vec -> usize</code> or <code>* -> vec</code>)\
-                </p>\
-                <p>\
-                    Search multiple things at once by splitting your query with comma (e.g. \
-                    <code>str,u8</code> or <code>String,struct:Vec,test</code>)\
-                </p>\
-            </div>\
+            {search_tricks}\
         </div>\
     </aside>\
     {after_content}\
     <script>\
         window.rootPath = \"{root_path}\";\
+        window.rootDepth = {depth};\
         window.currentCrate = \"{krate}\";\
     </script>\
-    <script src=\"{root_path}aliases.js\"></script>\
+    {aliases}\
     <script src=\"{root_path}main{suffix}.js\"></script>\
-    <script defer src=\"{root_path}search-index.js\"></script>\
+    {search_index}\
 </body>\
 </html>",
     css_extension = if css_file_extension {
@@ -159,7 +171,57 @@ pub fn render<T: fmt::Display, S: fmt::Display>(
         "".to_owned()
     },
     content   = *t,
+    class_prefix = match layout.class_prefix {
+        Some(ref prefix) => format!("<script>window.classPrefix = \"{}\";</script>", prefix),
+        None => String::new(),
+    },
+    search_input = if layout.no_search {
+        ""
+    } else {
+        "<input class=\"search-input\" name=\"search\" autocomplete=\"off\" \
+                placeholder=\"Click or press ‘S’ to search, ‘?’ for more options…\" \
+                type=\"search\">"
+    },
+    search_tricks = if layout.no_search {
+        ""
+    } else {
+        "<div class=\"infos\">\
+            <h2>Search Tricks</h2>\
+            <p>\
+                Prefix searches with a type followed by a colon (e.g. \
+                <code>fn:</code>) to restrict the search to a given type.\
+            </p>\
+            <p>\
+                Accepted types are: <code>fn</code>, <code>mod</code>, \
+                <code>struct</code>, <code>enum</code>, \
+                <code>trait</code>, <code>type</code>, <code>macro</code>, \
+                <code>const</code>, <code>static</code>, <code>union</code>, \
+                <code>primitive</code>, <code>keyword</code>, <code>attr</code>, \
+                <code>derive</code>, and <code>traitalias</code>.\
+            </p>\
+            <p>\
+                Search functions by type signature (e.g. \
+                <code>vec -> usize</code> or <code>* -> vec</code>)\
+            </p>\
+            <p>\
+                Search multiple things at once by splitting your query with comma (e.g. \
+                <code>str,u8</code> or <code>String,struct:Vec,test</code>)\
+            </p>\
+        </div>"
+    },
+    aliases = if layout.no_search {
+        String::new()
+    } else {
+        format!("<script src=\"{}aliases.js\"></script>", page.root_path)
+    },
+    search_index = match layout.search_index {
+        _ if layout.no_search => String::new(),
+        // Scripts can't contain `</`, which could otherwise end them early
+        Some(ref index) => format!("<script>{}</script>", index.replace("</", "<\\/")),
+        None => format!("<script defer src=\"{}search-index.js\"></script>", page.root_path),
+    },
     root_path = page.root_path,
+    depth = page.depth,
     css_class = page.css_class,
     logo      = if layout.logo.is_empty() {
         "".to_string()
@@ -172,6 +234,11 @@ pub fn render<T: fmt::Display, S: fmt::Display>(
     title     = page.title,
     description = page.description,
     keywords = page.keywords,
+    crate_version = match layout.crate_version {
+        Some(ref version) => format!("<meta name=\"crate-version\" content=\"{}\">",
+                                     Escape(version)),
+        None => String::new(),
+    },
     favicon   = if layout.favicon.is_empty() {
         "".to_string()
     } else {
@@ -37,6 +37,7 @@ use std::borrow::Cow;
 use std::ops::Range;
 use std::str;
 
+use html::escape::Escape;
 use html::toc::TocBuilder;
 use html::highlight;
 use test;
@@ -47,16 +48,18 @@ use pulldown_cmark::{Options, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TABLES};
 /// A unit struct which has the `fmt::Display` trait implemented. When
 /// formatted, this struct will emit the HTML corresponding to the rendered
 /// version of the contained markdown string.
-/// The second parameter is a list of link replacements
+/// The second parameter is a list of link replacements: the original link, the URL it's replaced
+/// with and the title of the link, if any.
 pub struct Markdown<'a>(
-    pub &'a str, pub &'a [(String, String)], pub RefCell<&'a mut IdMap>, pub ErrorCodes);
+    pub &'a str, pub &'a [(String, String, Option<String>)], pub RefCell<&'a mut IdMap>,
+    pub ErrorCodes);
 /// A unit struct like `Markdown`, that renders the markdown with a
 /// table of contents.
 pub struct MarkdownWithToc<'a>(pub &'a str, pub RefCell<&'a mut IdMap>, pub ErrorCodes);
 /// A unit struct like `Markdown`, that renders the markdown escaping HTML tags.
 pub struct MarkdownHtml<'a>(pub &'a str, pub RefCell<&'a mut IdMap>, pub ErrorCodes);
 /// A unit struct like `Markdown`, that renders only the first paragraph.
-pub struct MarkdownSummaryLine<'a>(pub &'a str, pub &'a [(String, String)]);
+pub struct MarkdownSummaryLine<'a>(pub &'a str, pub &'a [(String, String, Option<String>)]);
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum ErrorCodes {
@@ -151,6 +154,9 @@ thread_local!(pub static PLAYGROUND: RefCell<Option<(Option<String>, String)>> =
 struct CodeBlocks<'a, I: Iterator<Item = Event<'a>>> {
     inner: I,
     check_error_codes: ErrorCodes,
+    /// Whether the last emitted event was a rendered rust example, which an `output` code
+    /// block can be attached to.
+    after_rust_example: bool,
 }
 
 impl<'a, I: Iterator<Item = Event<'a>>> CodeBlocks<'a, I> {
@@ -158,6 +164,7 @@ impl<'a, I: Iterator<Item = Event<'a>>> CodeBlocks<'a, I> {
         CodeBlocks {
             inner: iter,
             check_error_codes: error_codes,
+            after_rust_example: false,
         }
     }
 }
@@ -167,10 +174,15 @@ impl<'a, I: Iterator<Item = Event<'a>>> Iterator for CodeBlocks<'a, I> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let event = self.inner.next();
+        let after_rust_example = self.after_rust_example;
+        self.after_rust_example = false;
         let compile_fail;
         let ignore;
         if let Some(Event::Start(Tag::CodeBlock(lang))) = event {
             let parse_result = LangString::parse(&lang, self.check_error_codes);
+            if parse_result.output && after_rust_example {
+                return Some(self.render_output_block());
+            }
             if !parse_result.rust {
                 return Some(Event::Start(Tag::CodeBlock(lang)));
             }
@@ -179,6 +191,7 @@ impl<'a, I: Iterator<Item = Event<'a>>> Iterator for CodeBlocks<'a, I> {
         } else {
             return event;
         }
+        self.after_rust_example = true;
 
         let mut origtext = String::new();
         for event in &mut self.inner {
@@ -254,14 +267,34 @@ impl<'a, I: Iterator<Item = Event<'a>>> Iterator for CodeBlocks<'a, I> {
     }
 }
 
+impl<'a, I: Iterator<Item = Event<'a>>> CodeBlocks<'a, I> {
+    /// Renders the contents of an `output` code block as the output of the example preceding
+    /// it. The contents are displayed as-is; they aren't checked against anything.
+    fn render_output_block(&mut self) -> Event<'a> {
+        let mut output = String::new();
+        for event in &mut self.inner {
+            match event {
+                Event::End(Tag::CodeBlock(..)) => break,
+                Event::Text(ref s) => output.push_str(s),
+                _ => {}
+            }
+        }
+        Event::Html(format!("\n<div class=\"example-output\">\
+                             <p class=\"example-output-header\">Output:</p>\
+                             <pre class=\"example-output\">{}</pre>\
+                             </div>",
+                            Escape(output.trim_right_matches('\n'))).into())
+    }
+}
+
 /// Make headings links with anchor ids and build up TOC.
 struct LinkReplacer<'a, 'b, I: Iterator<Item = Event<'a>>> {
     inner: I,
-    links: &'b [(String, String)],
+    links: &'b [(String, String, Option<String>)],
 }
 
 impl<'a, 'b, I: Iterator<Item = Event<'a>>> LinkReplacer<'a, 'b, I> {
-    fn new(iter: I, links: &'b [(String, String)]) -> Self {
+    fn new(iter: I, links: &'b [(String, String, Option<String>)]) -> Self {
         LinkReplacer {
             inner: iter,
             links,
@@ -275,9 +308,11 @@ impl<'a, 'b, I: Iterator<Item = Event<'a>>> Iterator for LinkReplacer<'a, 'b, I>
     fn next(&mut self) -> Option<Self::Item> {
         let event = self.inner.next();
         if let Some(Event::Start(Tag::Link(dest, text))) = event {
-            if let Some(&(_, ref replace)) = self.links.into_iter().find(|link| &*link.0 == &*dest)
+            if let Some(&(_, ref replace, ref title)) =
+                self.links.into_iter().find(|link| &*link.0 == &*dest)
             {
-                Some(Event::Start(Tag::Link(replace.to_owned().into(), text)))
+                let title = title.as_ref().map_or(text, |title| title.to_owned().into());
+                Some(Event::Start(Tag::Link(replace.to_owned().into(), title)))
             } else {
                 Some(Event::Start(Tag::Link(dest, text)))
             }
@@ -576,6 +611,7 @@ pub struct LangString {
     pub compile_fail: bool,
     pub error_codes: Vec<String>,
     pub allow_fail: bool,
+    pub output: bool,
 }
 
 impl LangString {
@@ -590,6 +626,7 @@ impl LangString {
             compile_fail: false,
             error_codes: Vec::new(),
             allow_fail: false,
+            output: false,
         }
     }
 
@@ -614,6 +651,7 @@ impl LangString {
                 "no_run" => { data.no_run = true; seen_rust_tags = !seen_other_tags; }
                 "ignore" => { data.ignore = true; seen_rust_tags = !seen_other_tags; }
                 "allow_fail" => { data.allow_fail = true; seen_rust_tags = !seen_other_tags; }
+                "output" => { data.output = true; seen_other_tags = true; }
                 "rust" => { data.rust = true; seen_rust_tags = true; }
                 "test_harness" => {
                     data.test_harness = true;
@@ -654,8 +692,10 @@ impl<'a> fmt::Display for Markdown<'a> {
         opts.insert(OPTION_ENABLE_FOOTNOTES);
 
         let replacer = |_: &str, s: &str| {
-            if let Some(&(_, ref replace)) = links.into_iter().find(|link| &*link.0 == s) {
-                Some((replace.clone(), s.to_owned()))
+            if let Some(&(_, ref replace, ref title)) =
+                links.into_iter().find(|link| &*link.0 == s)
+            {
+                Some((replace.clone(), title.clone().unwrap_or_else(|| s.to_owned())))
             } else {
                 None
             }
@@ -740,8 +780,10 @@ impl<'a> fmt::Display for MarkdownSummaryLine<'a> {
         if md.is_empty() { return Ok(()) }
 
         let replacer = |_: &str, s: &str| {
-            if let Some(&(_, ref replace)) = links.into_iter().find(|link| &*link.0 == s) {
-                Some((replace.clone(), s.to_owned()))
+            if let Some(&(_, ref replace, ref title)) =
+                links.into_iter().find(|link| &*link.0 == s)
+            {
+                Some((replace.clone(), title.clone().unwrap_or_else(|| s.to_owned())))
             } else {
                 None
             }
@@ -807,6 +849,49 @@ pub fn plain_summary_line(md: &str) -> String {
     s
 }
 
+/// Shortens the first paragraph of the given markdown to at most `length` characters of its
+/// rendered text, which is returned without any markup.
+///
+/// The text is cut at the end of the last complete sentence if there is one, or else at the end
+/// of the last complete word followed by an ellipsis.
+pub fn truncate_summary(md: &str, length: usize) -> String {
+    let mut paragraph = String::new();
+    for event in Parser::new(md) {
+        match event {
+            Event::Text(ref text) => paragraph.push_str(text),
+            Event::SoftBreak | Event::HardBreak => paragraph.push(' '),
+            Event::End(Tag::Paragraph) | Event::End(Tag::Header(_)) => break,
+            _ => {}
+        }
+    }
+    let end = match paragraph.char_indices().nth(length) {
+        Some((end, _)) => end,
+        None => return paragraph,
+    };
+    let (kept, rest) = paragraph.split_at(end);
+    if let Some(pos) = kept.rfind(". ") {
+        return kept[..pos + 1].to_owned();
+    }
+    if kept.ends_with('.') && rest.starts_with(char::is_whitespace) {
+        return kept.to_owned();
+    }
+    let kept = if rest.starts_with(char::is_whitespace) {
+        kept
+    } else {
+        kept.rfind(char::is_whitespace).map_or(kept, |pos| &kept[..pos])
+    };
+    format!("{}…", kept.trim_right())
+}
+
+/// Returns whether the given markdown contains a rust code block, i.e. an example which would be
+/// run as a doctest.
+crate fn contains_rust_example(md: &str) -> bool {
+    Parser::new(md).any(|event| match event {
+        Event::Start(Tag::CodeBlock(lang)) => LangString::parse(&lang, ErrorCodes::No).rust,
+        _ => false,
+    })
+}
+
 pub fn markdown_links(md: &str) -> Vec<(String, Option<Range<usize>>)> {
     if md.is_empty() {
         return vec![];
@@ -922,14 +1007,14 @@ fn test_unique_id() {
 #[cfg(test)]
 mod tests {
     use super::{ErrorCodes, LangString, Markdown, MarkdownHtml, IdMap};
-    use super::plain_summary_line;
+    use super::{plain_summary_line, truncate_summary};
     use std::cell::RefCell;
 
     #[test]
     fn test_lang_string_parse() {
         fn t(s: &str,
             should_panic: bool, no_run: bool, ignore: bool, rust: bool, test_harness: bool,
-            compile_fail: bool, allow_fail: bool, error_codes: Vec<String>) {
+            compile_fail: bool, allow_fail: bool, output: bool, error_codes: Vec<String>) {
             assert_eq!(LangString::parse(s, ErrorCodes::Yes), LangString {
                 should_panic,
                 no_run,
@@ -940,6 +1025,7 @@ mod tests {
                 error_codes,
                 original: s.to_owned(),
                 allow_fail,
+                output,
             })
         }
 
@@ -947,23 +1033,24 @@ mod tests {
             Vec::new()
         }
 
-        // marker                | should_panic| no_run| ignore| rust | test_harness| compile_fail
-        //                       | allow_fail | error_codes
-        t("",                      false,        false,  false,  true,  false, false, false, v());
-        t("rust",                  false,        false,  false,  true,  false, false, false, v());
-        t("sh",                    false,        false,  false,  false, false, false, false, v());
-        t("ignore",                false,        false,  true,   true,  false, false, false, v());
-        t("should_panic",          true,         false,  false,  true,  false, false, false, v());
-        t("no_run",                false,        true,   false,  true,  false, false, false, v());
-        t("test_harness",          false,        false,  false,  true,  true,  false, false, v());
-        t("compile_fail",          false,        true,   false,  true,  false, true,  false, v());
-        t("allow_fail",            false,        false,  false,  true,  false, false, true,  v());
-        t("{.no_run .example}",    false,        true,   false,  true,  false, false, false, v());
-        t("{.sh .should_panic}",   true,         false,  false,  false, false, false, false, v());
-        t("{.example .rust}",      false,        false,  false,  true,  false, false, false, v());
-        t("{.test_harness .rust}", false,        false,  false,  true,  true,  false, false, v());
-        t("text, no_run",          false,        true,   false,  false, false, false, false, v());
-        t("text,no_run",           false,        true,   false,  false, false, false, false, v());
+        // marker                | should_panic | no_run | ignore | rust | test_harness
+        //                       | compile_fail | allow_fail | output | error_codes
+        t("",                      false, false, false, true,  false, false, false, false, v());
+        t("rust",                  false, false, false, true,  false, false, false, false, v());
+        t("sh",                    false, false, false, false, false, false, false, false, v());
+        t("ignore",                false, false, true,  true,  false, false, false, false, v());
+        t("should_panic",          true,  false, false, true,  false, false, false, false, v());
+        t("no_run",                false, true,  false, true,  false, false, false, false, v());
+        t("test_harness",          false, false, false, true,  true,  false, false, false, v());
+        t("compile_fail",          false, true,  false, true,  false, true,  false, false, v());
+        t("allow_fail",            false, false, false, true,  false, false, true,  false, v());
+        t("{.no_run .example}",    false, true,  false, true,  false, false, false, false, v());
+        t("{.sh .should_panic}",   true,  false, false, false, false, false, false, false, v());
+        t("{.example .rust}",      false, false, false, true,  false, false, false, false, v());
+        t("{.test_harness .rust}", false, false, false, true,  true,  false, false, false, v());
+        t("text, no_run",          false, true,  false, false, false, false, false, false, v());
+        t("text,no_run",           false, true,  false, false, false, false, false, false, v());
+        t("output",                false, false, false, false, false, false, false, true,  v());
     }
 
     #[test]
@@ -1025,6 +1112,24 @@ mod tests {
         t("## header", "header");
     }
 
+    #[test]
+    fn test_truncate_summary() {
+        fn t(input: &str, length: usize, expect: &str) {
+            let output = truncate_summary(input, length);
+            assert_eq!(output, expect, "original: {}", input);
+        }
+
+        t("Short enough.", 20, "Short enough.");
+        t("Short enough.\n\nNot in the summary.", 20, "Short enough.");
+        t("First sentence. Second sentence.", 20, "First sentence.");
+        t("First sentence.", 15, "First sentence.");
+        t("Some words that do not fit", 12, "Some words…");
+        t("Some words that do not fit", 10, "Some words…");
+        t("Unbreakable", 5, "Unbre…");
+        t("Wrapped\nsummary line", 10, "Wrapped…");
+        t("A [`Vec`](Vec) of *many* words", 16, "A Vec of many…");
+    }
+
     #[test]
     fn test_markdown_html_escape() {
         fn t(input: &str, expect: &str) {
@@ -36,9 +36,9 @@
 pub use self::ExternalLocation::*;
 
 use std::borrow::Cow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
 use std::default::Default;
 use std::error;
 use std::fmt::{self, Display, Formatter, Write as FmtWrite};
@@ -54,30 +54,35 @@ use std::rc::Rc;
 
 use externalfiles::ExternalHtml;
 
-use serialize::json::{ToJson, Json, as_json};
+use serialize::json::{self, ToJson, Json, as_json};
 use syntax::ast;
 use syntax::codemap::FileName;
+use syntax::ext::base::MacroKind;
 use syntax::feature_gate::UnstableFeatures;
-use rustc::hir::def_id::{CrateNum, CRATE_DEF_INDEX, DefId};
+use rustc::hir::def_id::{CrateNum, CRATE_DEF_INDEX, DefId, LOCAL_CRATE};
 use rustc::middle::privacy::AccessLevels;
 use rustc::middle::stability;
 use rustc::hir;
 use rustc::util::nodemap::{FxHashMap, FxHashSet};
 use rustc_data_structures::flock;
 
-use clean::{self, AttributesExt, GetDefId, SelfTy, Mutability};
+use clean::{self, AttributesExt, GetDefId, NestedAttributesExt, SelfTy, Mutability};
+use clean::cfg::Cfg;
 use doctree;
 use fold::DocFolder;
+use html::class_prefix;
 use html::escape::Escape;
+use html::features::{Feature, FeaturesPage};
 use html::format::{AsyncSpace, ConstnessSpace};
 use html::format::{GenericBounds, WhereClause, href, AbiSpace};
-use html::format::{VisSpace, Method, UnsafetySpace, MutableSpace};
+use html::format::{VisSpace, RestrictedVisSpace, Method, UnsafetySpace, MutableSpace};
 use html::format::fmt_impl_for_trait_page;
 use html::item_type::ItemType;
 use html::markdown::{self, Markdown, MarkdownHtml, MarkdownSummaryLine, ErrorCodes, IdMap};
 use html::{highlight, layout};
 
 use minifier;
+use span_map::SpanMap;
 
 /// A pair of name and its optional document.
 pub type NameDoc = (String, Option<String>);
@@ -139,6 +144,49 @@ struct SharedContext {
     /// Suffix to be added on resource files (if suffix is "-v2" then "light.css" becomes
     /// "light-v2.css").
     pub resource_suffix: String,
+    /// Whether to also render the `impl Future` signature of `async fn`s, as given by the
+    /// `--show-async-desugaring` flag.
+    pub show_async_desugaring: bool,
+    /// Whether the whole crate is rendered into a single page, as given by the `--single-page`
+    /// flag.
+    pub single_page: bool,
+    /// The configurations whose portability badges are hidden crate-wide, as given by
+    /// `#![doc(cfg_hide(...))]`.
+    pub hidden_cfgs: Vec<Cfg>,
+    /// The maximum number of characters of the summaries in the module listings, as given by
+    /// the `--summary-length` flag.
+    pub summary_length: Option<usize>,
+    /// The attributes of the functions which are shown as badges: `track_caller` and the ones
+    /// given by the `--show-attributes` flag.
+    pub badge_attributes: Vec<String>,
+    /// Whether the method lists of the sidebar are packed together, as given by the
+    /// `--compact-sidebar` flag.
+    pub compact_sidebar: bool,
+    /// The feature flags of the crate, as given by the `--crate-features` flag, documented on
+    /// its `features.html` page.
+    pub crate_features: Option<Vec<Feature>>,
+    /// Whether the traits with a private supertrait are noted as sealed, as asked with the
+    /// `--detect-sealed-traits` flag.
+    pub detect_sealed_traits: bool,
+    /// Where the inherent impls of the types are shown relative to their trait impls, as given by
+    /// the `--impl-order` flag.
+    pub impl_order: ImplOrder,
+    /// Whether a JSON description of each item is written next to its page, as asked with the
+    /// `--emit-item-json` flag.
+    pub emit_item_json: bool,
+    /// Whether the auto trait implementations of a type are summed up on one line when they're
+    /// all unconditional, as asked with the `--trim-auto-traits` flag.
+    pub trim_auto_traits: bool,
+    /// Whether the `PhantomData` fields of the structs are explained, as asked with the
+    /// `--annotate-phantom-data` flag.
+    pub annotate_phantom_data: bool,
+    /// The path of the root of the documentation on the site hosting it, ending with a `/`, as
+    /// given by the `--html-root-path` flag. The pages refer to the root with it rather than
+    /// with a relative path.
+    pub html_root_path: Option<String>,
+    /// Whether the names of the private fields of the structs and unions are listed, as asked
+    /// with the `--show-private-field-names` flag.
+    pub show_private_field_names: bool,
 }
 
 impl SharedContext {
@@ -151,6 +199,12 @@ impl SharedContext {
 
         Ok(())
     }
+
+    /// Returns the path from a page to the root of the documentation: the one given by
+    /// `--html-root-path` when there's one, or else `relative`.
+    fn root_path(&self, relative: String) -> String {
+        self.html_root_path.clone().unwrap_or(relative)
+    }
 }
 
 impl SharedContext {
@@ -168,6 +222,100 @@ impl SharedContext {
             item.doc_value().map(|s| s.into())
         }
     }
+
+    /// Prefixes the class selectors of the stylesheet `css` like the classes of the pages, when
+    /// asked with `--css-class-prefix`.
+    fn stylesheet<'a>(&self, css: &'a str) -> Cow<'a, str> {
+        match self.layout.class_prefix {
+            Some(ref prefix) => class_prefix::prefix_selectors(css, prefix).into(),
+            None => css.into(),
+        }
+    }
+}
+
+/// The order of the impl sections on the pages of the types, as given by `--impl-order`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImplOrder {
+    /// The methods of the inherent impls, then the trait implementations.
+    InherentFirst,
+    /// The trait implementations, then the methods of the inherent impls.
+    TraitFirst,
+    /// The inherent impls and trait implementations together, in the order they were found.
+    Interleaved,
+}
+
+impl str::FromStr for ImplOrder {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<ImplOrder, ()> {
+        match s {
+            "inherent-first" => Ok(ImplOrder::InherentFirst),
+            "trait-first" => Ok(ImplOrder::TraitFirst),
+            "interleaved" => Ok(ImplOrder::Interleaved),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The options of the HTML output given on the command line, which don't change how the crate
+/// is cleaned.
+pub struct RenderOptions {
+    /// The favicon, as given by `--html-favicon-url`. It wins over `#![doc(html_favicon_url)]`.
+    pub favicon_url: Option<String>,
+    /// The logo, as given by `--html-logo-url`. It wins over `#![doc(html_logo_url)]`.
+    pub logo_url: Option<String>,
+    /// Whether the source files are rendered, unless `--html-no-source` is given.
+    pub include_sources: bool,
+    /// Whether the `impl Future` signature of the `async fn`s is shown, as asked with
+    /// `--show-async-desugaring`.
+    pub show_async_desugaring: bool,
+    /// Whether the whole crate is rendered into a single page, as asked with `--single-page`.
+    pub single_page: bool,
+    /// The documentation root of the dependencies, as given by the `--extern-locations` file.
+    pub extern_locations: BTreeMap<String, String>,
+    /// The maximum number of characters of the summaries, as given by `--summary-length`.
+    pub summary_length: Option<usize>,
+    /// Whether the search index is written into the pages, as asked with
+    /// `--inline-search-index`.
+    pub inline_search_index: bool,
+    /// The attributes shown as badges along with the default ones, as given by
+    /// `--show-attributes`.
+    pub shown_attributes: Vec<String>,
+    /// Whether the method lists of the sidebar are packed together, as asked with
+    /// `--compact-sidebar`.
+    pub compact_sidebar: bool,
+    /// Whether only the search index and the implementor lists are written, as asked with
+    /// `--emit-metadata-only`.
+    pub emit_metadata_only: bool,
+    /// Whether the search is left out, as asked with `--no-search`.
+    pub no_search: bool,
+    /// The feature flags of the crate, as given by `--crate-features`.
+    pub crate_features: Option<Vec<Feature>>,
+    /// Whether the links to the documented items show their summary when hovered, as asked with
+    /// `--link-summaries`.
+    pub link_summaries: bool,
+    /// Whether the sealed traits are noted, as asked with `--detect-sealed-traits`.
+    pub detect_sealed_traits: bool,
+    /// The order of the impl sections, as given by `--impl-order`.
+    pub impl_order: ImplOrder,
+    /// The prefix of the CSS classes, as given by `--css-class-prefix`.
+    pub css_class_prefix: Option<String>,
+    /// Whether a JSON description of each item is written, as asked with `--emit-item-json`.
+    pub emit_item_json: bool,
+    /// Whether the unconditional auto trait impls are summed up, as asked with
+    /// `--trim-auto-traits`.
+    pub trim_auto_traits: bool,
+    /// Whether the `PhantomData` fields are explained, as asked with `--annotate-phantom-data`.
+    pub annotate_phantom_data: bool,
+    /// The path of the root of the documentation on its site, as given by `--html-root-path`.
+    pub html_root_path: Option<String>,
+    /// Where the graph of the trait impls is written, as given by `--emit-trait-impls-graph`.
+    pub trait_impls_graph: Option<PathBuf>,
+    /// Whether the names of the private fields are listed, as asked with
+    /// `--show-private-field-names`.
+    pub show_private_field_names: bool,
+    /// Where the map of the redirects is written, as given by `--emit-redirect-map`.
+    pub redirect_map: Option<PathBuf>,
 }
 
 /// Indicates where an external crate can be found.
@@ -318,6 +466,10 @@ pub struct Cache {
     /// The version of the crate being documented, if given fron the `--crate-version` flag.
     pub crate_version: Option<String>,
 
+    /// The summaries of the documented items, which the links to them are titled with when
+    /// asked with `--link-summaries`. This is `None` otherwise.
+    pub summaries: Option<FxHashMap<DefId, String>>,
+
     // Private fields only used when initially crawling a crate to build a cache
 
     stack: Vec<String>,
@@ -328,6 +480,9 @@ pub struct Cache {
     deref_trait_did: Option<DefId>,
     deref_mut_trait_did: Option<DefId>,
     owned_box_did: Option<DefId>,
+    sized_trait_did: Option<DefId>,
+    future_trait_did: Option<DefId>,
+    drop_trait_did: Option<DefId>,
     masked_crates: FxHashSet<CrateNum>,
 
     // In rare case where a structure is defined in one module but implemented
@@ -340,6 +495,23 @@ pub struct Cache {
     /// Aliases added through `#[doc(alias = "...")]`. Since a few items can have the same alias,
     /// we need the alias element to have an array of items.
     aliases: FxHashMap<String, Vec<IndexItem>>,
+
+    /// The items referred to by the paths of each source file of the crate, used to link them to
+    /// their documentation from the source pages.
+    span_map: SpanMap,
+
+    /// The types named by the public type aliases, whose impls are shown on the pages of the
+    /// aliases with `--expand-type-aliases`.
+    alias_targets: FxHashMap<DefId, DefId>,
+
+    /// The impls of the types of other crates named by the public type aliases, inlined for
+    /// `--expand-type-aliases`. They're only shown on the pages of the aliases, so they're kept
+    /// apart from `impls`, which the rest of the documentation is made of.
+    alias_impls: FxHashMap<DefId, Vec<Impl>>,
+
+    /// The source of the items generated by a macro, as they were expanded, shown on their pages
+    /// with `--render-macro-expansion`.
+    macro_expansions: FxHashMap<DefId, String>,
 }
 
 /// Temporary storage for data obtained during `RustdocVisitor::clean()`.
@@ -353,6 +525,13 @@ pub struct RenderInfo {
     pub deref_trait_did: Option<DefId>,
     pub deref_mut_trait_did: Option<DefId>,
     pub owned_box_did: Option<DefId>,
+    pub sized_trait_did: Option<DefId>,
+    pub future_trait_did: Option<DefId>,
+    pub drop_trait_did: Option<DefId>,
+    pub span_map: SpanMap,
+    pub alias_targets: FxHashMap<DefId, DefId>,
+    pub alias_impls: FxHashMap<DefId, Vec<clean::Item>>,
+    pub macro_expansions: FxHashMap<DefId, String>,
 }
 
 /// Helper struct to render all source code to HTML pages
@@ -363,9 +542,17 @@ struct SourceCollector<'a> {
     dst: PathBuf,
 }
 
+/// Helper struct to list the source files of the local items, in the order in which they're
+/// first seen
+struct SourceFinder {
+    files: Vec<PathBuf>,
+    seen: FxHashSet<PathBuf>,
+}
+
 /// Wrapper struct to render the source code of a file. This will do things like
-/// adding line numbers to the left-hand side.
-struct Source<'a>(&'a str);
+/// adding line numbers to the left-hand side, and linking the paths at the given
+/// byte ranges to the documentation of the items they refer to.
+struct Source<'a>(&'a str, &'a FxHashMap<(u32, u32), String>);
 
 // Helper structs for rendering items/sidebars and carrying along contextual
 // information
@@ -455,6 +642,7 @@ impl ToJson for IndexItemFunctionType {
 
 thread_local!(static CACHE_KEY: RefCell<Arc<Cache>> = Default::default());
 thread_local!(pub static CURRENT_LOCATION_KEY: RefCell<Vec<String>> = RefCell::new(Vec::new()));
+thread_local!(pub static SINGLE_PAGE_KEY: Cell<bool> = Cell::new(false));
 
 pub fn initial_ids() -> Vec<String> {
     [
@@ -489,7 +677,35 @@ pub fn run(mut krate: clean::Crate,
            sort_modules_alphabetically: bool,
            themes: Vec<PathBuf>,
            enable_minification: bool,
+           options: RenderOptions,
            id_map: IdMap) -> Result<(), Error> {
+    let RenderOptions {
+        favicon_url,
+        logo_url,
+        include_sources,
+        show_async_desugaring,
+        single_page,
+        extern_locations,
+        summary_length,
+        inline_search_index,
+        shown_attributes,
+        compact_sidebar,
+        emit_metadata_only,
+        no_search,
+        crate_features,
+        link_summaries,
+        detect_sealed_traits,
+        impl_order,
+        css_class_prefix,
+        emit_item_json,
+        trim_auto_traits,
+        annotate_phantom_data,
+        html_root_path,
+        trait_impls_graph,
+        show_private_field_names,
+        redirect_map,
+    } = options;
+
     let src_root = match krate.src {
         FileName::Real(ref p) => match p.parent() {
             Some(p) => p.to_path_buf(),
@@ -500,7 +716,7 @@ pub fn run(mut krate: clean::Crate,
     let mut scx = SharedContext {
         src_root,
         passes,
-        include_sources: true,
+        include_sources,
         local_sources: FxHashMap(),
         issue_tracker_base_url: None,
         layout: layout::Layout {
@@ -508,12 +724,38 @@ pub fn run(mut krate: clean::Crate,
             favicon: "".to_string(),
             external_html: external_html.clone(),
             krate: krate.name.clone(),
+            search_index: None,
+            crate_version: krate.version.clone(),
+            no_search,
+            class_prefix: css_class_prefix,
         },
         css_file_extension: css_file_extension.clone(),
         created_dirs: RefCell::new(FxHashSet()),
         sort_modules_alphabetically,
         themes,
         resource_suffix,
+        show_async_desugaring,
+        single_page,
+        hidden_cfgs: Vec::new(),
+        summary_length,
+        badge_attributes: DEFAULT_BADGE_ATTRIBUTES.iter()
+                                                  .map(|s| s.to_string())
+                                                  .chain(shown_attributes)
+                                                  .collect(),
+        compact_sidebar,
+        crate_features,
+        detect_sealed_traits,
+        impl_order,
+        emit_item_json,
+        trim_auto_traits,
+        annotate_phantom_data,
+        html_root_path: html_root_path.map(|mut path| {
+            if !path.ends_with('/') {
+                path.push('/');
+            }
+            path
+        }),
+        show_private_field_names,
     };
 
     // If user passed in `--playground-url` arg, we fill in crate name here
@@ -547,19 +789,34 @@ pub fn run(mut krate: clean::Crate,
                 (Some("html_no_source"), None) if attr.is_word() => {
                     scx.include_sources = false;
                 }
+                (Some("cfg_hide"), None) => {
+                    for cfg_mi in attr.meta_item_list().into_iter().flat_map(|l| l) {
+                        if let Some(Ok(cfg)) = cfg_mi.meta_item().map(Cfg::parse) {
+                            scx.hidden_cfgs.push(cfg);
+                        }
+                    }
+                }
                 _ => {}
             }
         }
     }
+    // The favicon and logo given on the command line win over the crate attributes
+    if let Some(url) = favicon_url {
+        scx.layout.favicon = url;
+    }
+    if let Some(url) = logo_url {
+        scx.layout.logo = url;
+    }
     try_err!(fs::create_dir_all(&dst), &dst);
-    krate = render_sources(&dst, &mut scx, krate)?;
-    let cx = Context {
-        current: Vec::new(),
-        dst,
-        render_redirect_pages: false,
-        codes: ErrorCodes::from(UnstableFeatures::from_environment().is_nightly_build()),
-        id_map: Rc::new(RefCell::new(id_map)),
-        shared: Arc::new(scx),
+
+    // The sources are listed before the crate is folded into the cache, which takes the impls
+    // out of it.
+    let sources = if scx.include_sources && !emit_metadata_only {
+        let (k, sources) = find_sources(krate);
+        krate = k;
+        sources
+    } else {
+        Vec::new()
     };
 
     // Crawl the crate to build various caches used for the output
@@ -571,6 +828,13 @@ pub fn run(mut krate: clean::Crate,
         deref_trait_did,
         deref_mut_trait_did,
         owned_box_did,
+        sized_trait_did,
+        future_trait_did,
+        drop_trait_did,
+        span_map,
+        alias_targets,
+        alias_impls,
+        macro_expansions,
     } = renderinfo;
 
     let external_paths = external_paths.into_iter()
@@ -592,14 +856,22 @@ pub fn run(mut krate: clean::Crate,
         stripped_mod: false,
         access_levels: krate.access_levels.clone(),
         crate_version: krate.version.take(),
+        summaries: if link_summaries { Some(FxHashMap()) } else { None },
         orphan_impl_items: Vec::new(),
         traits: mem::replace(&mut krate.external_traits, FxHashMap()),
         deref_trait_did,
         deref_mut_trait_did,
         owned_box_did,
+        sized_trait_did,
+        future_trait_did,
+        drop_trait_did,
         masked_crates: mem::replace(&mut krate.masked_crates, FxHashSet()),
         typarams: external_typarams,
         aliases: FxHashMap(),
+        span_map,
+        alias_targets,
+        alias_impls: FxHashMap(),
+        macro_expansions,
     };
 
     // Cache where all our extern crates are located
@@ -611,8 +883,9 @@ pub fn run(mut krate: clean::Crate,
             },
             _ => PathBuf::new(),
         };
+        let extern_url = extern_locations.get(&e.name).map(|url| &url[..]);
         cache.extern_locations.insert(n, (e.name.clone(), src_root,
-                                          extern_location(e, &cx.dst)));
+                                          extern_location(e, extern_url, &dst)));
 
         let did = DefId { krate: n, index: CRATE_DEF_INDEX };
         cache.external_paths.insert(did, (vec![e.name.to_string()], ItemType::Module));
@@ -634,21 +907,156 @@ pub fn run(mut krate: clean::Crate,
     cache.stack.push(krate.name.clone());
     krate = cache.fold_crate(krate);
 
+    // The impls inlined for `--expand-type-aliases` are only shown on the pages of the aliases,
+    // so they're kept out of the crate, its implementor lists, its search index and its graph.
+    for (target, impls) in alias_impls {
+        for impl_item in impls {
+            if let Some(generics) = impl_item.inner.generics() {
+                cache.generics(generics);
+            }
+            cache.alias_impls.entry(target).or_insert(vec![]).push(Impl { impl_item });
+        }
+    }
+
+    // The paths of the index are shortened as it's built.
+    if let Some(ref path) = redirect_map {
+        write_redirect_map(&krate.name, &cache, path)?;
+    }
+
     // Build our search index
-    let index = build_index(&krate, &mut cache);
+    let index = if no_search {
+        String::new()
+    } else {
+        build_index(&krate, &mut cache)
+    };
 
     // Freeze the cache now that the index has been built. Put an Arc into TLS
     // for future parallelization opportunities
     let cache = Arc::new(cache);
     CACHE_KEY.with(|v| *v.borrow_mut() = cache.clone());
     CURRENT_LOCATION_KEY.with(|s| s.borrow_mut().clear());
+    SINGLE_PAGE_KEY.with(|s| s.set(single_page));
+
+    // The source pages link to the documentation of the items they use, so they are rendered
+    // once the cache knows where that documentation is.
+    render_sources(&dst, &mut scx, &krate.name, &sources)?;
+    let mut cx = Context {
+        current: Vec::new(),
+        dst,
+        render_redirect_pages: false,
+        codes: ErrorCodes::from(UnstableFeatures::from_environment().is_nightly_build()),
+        id_map: Rc::new(RefCell::new(id_map)),
+        shared: Arc::new(scx),
+    };
 
-    write_shared(&cx, &krate, &*cache, index, enable_minification)?;
+    let search_index = write_shared(&cx, &krate, &*cache, index, enable_minification)?;
+    if let Some(ref path) = trait_impls_graph {
+        write_trait_impls_graph(&*cache, path)?;
+    }
+    if emit_metadata_only {
+        // The search index and the implementor lists are all the other crates link to.
+        return Ok(());
+    }
+    if inline_search_index {
+        // Nothing else holds onto the shared context before the crate gets rendered
+        Arc::get_mut(&mut cx.shared).expect("shared context is still unique")
+                                    .layout.search_index = Some(search_index);
+    }
 
     // And finally render the whole crate's documentation
     cx.krate(krate)
 }
 
+/// Writes to `dst` the `--emit-trait-impls-graph` edges between the types and the traits they
+/// implement, as a JSON array of `{"type", "trait", "kind"}` objects. The kind tells apart the
+/// impls written in the code, the synthetic impls of auto traits and the blanket impls. The
+/// negative impls aren't edges.
+fn write_trait_impls_graph(cache: &Cache, dst: &Path) -> Result<(), Error> {
+    let fqp = |did: DefId| {
+        cache.paths.get(&did).or_else(|| cache.external_paths.get(&did))
+                   .map(|&(ref fqp, _)| fqp.join("::"))
+    };
+
+    // A set to get a sorted output without duplicates.
+    let mut edges = BTreeSet::new();
+    for (&did, impls) in &cache.impls {
+        for i in impls {
+            let impl_ = i.inner_impl();
+            let trait_ = match impl_.trait_ {
+                Some(ref trait_) if impl_.polarity != Some(clean::ImplPolarity::Negative) => {
+                    trait_
+                }
+                _ => continue,
+            };
+            let kind = if impl_.blanket_impl.is_some() {
+                "blanket"
+            } else if impl_.synthetic {
+                "synthetic"
+            } else {
+                "explicit"
+            };
+            let ty = fqp(did).unwrap_or_else(|| format!("{:#}", impl_.for_));
+            let trait_ = trait_.def_id().and_then(&fqp)
+                               .unwrap_or_else(|| format!("{:#}", trait_));
+            edges.insert((ty, trait_, kind));
+        }
+    }
+
+    let edges = edges.into_iter().map(|(ty, trait_, kind)| {
+        let mut edge = BTreeMap::new();
+        edge.insert("type".to_string(), Json::String(ty));
+        edge.insert("trait".to_string(), Json::String(trait_));
+        edge.insert("kind".to_string(), Json::String(kind.to_string()));
+        Json::Object(edge)
+    }).collect();
+    write(dst.to_path_buf(), Json::Array(edges).to_string().as_bytes())
+}
+
+/// Writes to `dst` the `--emit-redirect-map` of the crate, a JSON object mapping the path of
+/// each item the search index knows of to the URL of its documentation, relative to the root of
+/// the documentation. The associated items map to the anchor they have on the page of their
+/// parent.
+fn write_redirect_map(krate: &str, cache: &Cache, dst: &Path) -> Result<(), Error> {
+    let assoc_entry = |name: &str, ty: ItemType, parent: DefId| {
+        cache.paths.get(&parent).map(|&(ref fqp, parent_ty)| {
+            (format!("{}::{}", fqp.join("::"), name),
+             format!("{}/{}.{}.html#{}.{}",
+                     fqp[..fqp.len() - 1].join("/"), parent_ty, fqp[fqp.len() - 1], ty, name))
+        })
+    };
+
+    let mut map = BTreeMap::new();
+    map.insert(krate.to_string(), Json::String(format!("{}/index.html", krate)));
+    for item in &cache.search_index {
+        let dir = item.path.replace("::", "/");
+        let (path, url) = match (item.ty, item.parent) {
+            (ItemType::ExternCrate, _) => continue,
+            (ItemType::Module, _) => {
+                (format!("{}::{}", item.path, item.name),
+                 format!("{}/{}/index.html", dir, item.name))
+            }
+            (_, Some(parent)) => match assoc_entry(&item.name, item.ty, parent) {
+                Some(entry) => entry,
+                None => continue,
+            },
+            (_, None) => {
+                (format!("{}::{}", item.path, item.name),
+                 format!("{}/{}.{}.html", dir, item.ty, item.name))
+            }
+        };
+        // The first of the items sharing a path, like the methods of several trait impls, gets
+        // the anchor without a suffix.
+        map.entry(path).or_insert(Json::String(url));
+    }
+    // The methods of the impls seen before their type are only added to the index as it's built.
+    for &(parent, ref item) in &cache.orphan_impl_items {
+        if let Some((path, url)) = assoc_entry(item.name.as_ref().unwrap(), item.type_(), parent) {
+            map.entry(path).or_insert(Json::String(url));
+        }
+    }
+    write(dst.to_path_buf(), Json::Object(map).to_string().as_bytes())
+}
+
 /// Build the search index from the collected metadata
 fn build_index(krate: &clean::Crate, cache: &mut Cache) -> String {
     let mut nodeid_to_pathid = FxHashMap();
@@ -719,11 +1127,13 @@ fn build_index(krate: &clean::Crate, cache: &mut Cache) -> String {
             Json::Object(crate_data))
 }
 
+/// Writes out the files shared by all the pages, returning the contents of the search index
+/// script.
 fn write_shared(cx: &Context,
                 krate: &clean::Crate,
                 cache: &Cache,
                 search_index: String,
-                enable_minification: bool) -> Result<(), Error> {
+                enable_minification: bool) -> Result<String, Error> {
     // Write out the shared files. Note that these are shared among all rustdoc
     // docs placed in the output directory, so this needs to be a synchronized
     // operation with respect to all other rustdocs running around.
@@ -733,10 +1143,10 @@ fn write_shared(cx: &Context,
     // overwrite them anyway to make sure that they're fresh and up-to-date.
 
     write_minify(cx.dst.join(&format!("rustdoc{}.css", cx.shared.resource_suffix)),
-                 include_str!("static/rustdoc.css"),
+                 &cx.shared.stylesheet(include_str!("static/rustdoc.css")),
                  enable_minification)?;
     write_minify(cx.dst.join(&format!("settings{}.css", cx.shared.resource_suffix)),
-                 include_str!("static/settings.css"),
+                 &cx.shared.stylesheet(include_str!("static/settings.css")),
                  enable_minification)?;
 
     // To avoid "light.css" to be overwritten, we'll first run over the received themes and only
@@ -750,8 +1160,9 @@ fn write_shared(cx: &Context,
         try_err!(f.read_to_end(&mut content), &entry);
         let theme = try_none!(try_none!(entry.file_stem(), &entry).to_str(), &entry);
         let extension = try_none!(try_none!(entry.extension(), &entry).to_str(), &entry);
+        let content = String::from_utf8_lossy(&content);
         write(cx.dst.join(format!("{}{}.{}", theme, cx.shared.resource_suffix, extension)),
-              content.as_slice())?;
+              cx.shared.stylesheet(&content).as_bytes())?;
         themes.insert(theme.to_owned());
     }
 
@@ -760,11 +1171,11 @@ fn write_shared(cx: &Context,
     write(cx.dst.join(&format!("wheel{}.svg", cx.shared.resource_suffix)),
           include_bytes!("static/wheel.svg"))?;
     write_minify(cx.dst.join(&format!("light{}.css", cx.shared.resource_suffix)),
-                 include_str!("static/themes/light.css"),
+                 &cx.shared.stylesheet(include_str!("static/themes/light.css")),
                  enable_minification)?;
     themes.insert("light".to_owned());
     write_minify(cx.dst.join(&format!("dark{}.css", cx.shared.resource_suffix)),
-                 include_str!("static/themes/dark.css"),
+                 &cx.shared.stylesheet(include_str!("static/themes/dark.css")),
                  enable_minification)?;
     themes.insert("dark".to_owned());
 
@@ -837,18 +1248,18 @@ themePicker.onblur = handleThemeButtonsBlur;
 
     if let Some(ref css) = cx.shared.css_file_extension {
         let out = cx.dst.join(&format!("theme{}.css", cx.shared.resource_suffix));
-        if !enable_minification {
+        if !enable_minification && cx.shared.layout.class_prefix.is_none() {
             try_err!(fs::copy(css, out), css);
         } else {
             let mut f = try_err!(File::open(css), css);
             let mut buffer = String::with_capacity(1000);
 
             try_err!(f.read_to_string(&mut buffer), css);
-            write_minify(out, &buffer, enable_minification)?;
+            write_minify(out, &cx.shared.stylesheet(&buffer), enable_minification)?;
         }
     }
     write_minify(cx.dst.join(&format!("normalize{}.css", cx.shared.resource_suffix)),
-                 include_str!("static/normalize.css"),
+                 &cx.shared.stylesheet(include_str!("static/normalize.css")),
                  enable_minification)?;
     write(cx.dst.join("FiraSans-Regular.woff"),
           include_bytes!("static/FiraSans-Regular.woff"))?;
@@ -906,45 +1317,53 @@ themePicker.onblur = handleThemeButtonsBlur;
                 })
     }
 
-    let dst = cx.dst.join("aliases.js");
-    {
-        let mut all_aliases = try_err!(collect(&dst, &krate.name, "ALIASES"), &dst);
-        let mut w = try_err!(File::create(&dst), &dst);
-        let mut output = String::with_capacity(100);
-        for (alias, items) in &cache.aliases {
-            if items.is_empty() {
-                continue
+    // With `--no-search`, neither the search index nor the aliases it's looked up with are
+    // written.
+    let search_index_js = if cx.shared.layout.no_search {
+        String::new()
+    } else {
+        let dst = cx.dst.join("aliases.js");
+        {
+            let mut all_aliases = try_err!(collect(&dst, &krate.name, "ALIASES"), &dst);
+            let mut w = try_err!(File::create(&dst), &dst);
+            let mut output = String::with_capacity(100);
+            for (alias, items) in &cache.aliases {
+                if items.is_empty() {
+                    continue
+                }
+                output.push_str(&format!("\"{}\":[{}],",
+                                         alias,
+                                         items.iter()
+                                              .map(|v| show_item(v, &krate.name))
+                                              .collect::<Vec<_>>()
+                                              .join(",")));
             }
-            output.push_str(&format!("\"{}\":[{}],",
-                                     alias,
-                                     items.iter()
-                                          .map(|v| show_item(v, &krate.name))
-                                          .collect::<Vec<_>>()
-                                          .join(",")));
-        }
-        all_aliases.push(format!("ALIASES['{}'] = {{{}}};", krate.name, output));
-        all_aliases.sort();
-        try_err!(writeln!(&mut w, "var ALIASES = {{}};"), &dst);
-        for aliases in &all_aliases {
-            try_err!(writeln!(&mut w, "{}", aliases), &dst);
-        }
-    }
-
-    // Update the search index
-    let dst = cx.dst.join("search-index.js");
-    let mut all_indexes = try_err!(collect(&dst, &krate.name, "searchIndex"), &dst);
-    all_indexes.push(search_index);
-    // Sort the indexes by crate so the file will be generated identically even
-    // with rustdoc running in parallel.
-    all_indexes.sort();
-    let mut w = try_err!(File::create(&dst), &dst);
-    try_err!(writeln!(&mut w, "var N = null;var searchIndex = {{}};"), &dst);
-    for index in &all_indexes {
-        try_err!(write_minify_replacer(&mut w, &*index, enable_minification,
-                                       &[(minifier::js::Keyword::Null, "N")]),
-                 &dst);
-    }
-    try_err!(writeln!(&mut w, "initSearch(searchIndex);"), &dst);
+            all_aliases.push(format!("ALIASES['{}'] = {{{}}};", krate.name, output));
+            all_aliases.sort();
+            try_err!(writeln!(&mut w, "var ALIASES = {{}};"), &dst);
+            for aliases in &all_aliases {
+                try_err!(writeln!(&mut w, "{}", aliases), &dst);
+            }
+        }
+
+        // Update the search index
+        let dst = cx.dst.join("search-index.js");
+        let mut all_indexes = try_err!(collect(&dst, &krate.name, "searchIndex"), &dst);
+        all_indexes.push(search_index);
+        // Sort the indexes by crate so the file will be generated identically even
+        // with rustdoc running in parallel.
+        all_indexes.sort();
+        let mut w = Vec::new();
+        try_err!(writeln!(&mut w, "var N = null;var searchIndex = {{}};"), &dst);
+        for index in &all_indexes {
+            try_err!(write_minify_replacer(&mut w, &*index, enable_minification,
+                                           &[(minifier::js::Keyword::Null, "N")]),
+                     &dst);
+        }
+        try_err!(writeln!(&mut w, "initSearch(searchIndex);"), &dst);
+        try_err!(fs::write(&dst, &w), &dst);
+        String::from_utf8(w).expect("the search index is valid UTF-8")
+    };
 
     // Update the list of all implementors for traits
     let dst = cx.dst.join("implementors");
@@ -976,8 +1395,17 @@ themePicker.onblur = handleThemeButtonsBlur;
             // should add it.
             if !imp.impl_item.def_id.is_local() { continue }
             have_impls = true;
+            let mut text = imp.inner_impl().to_string();
+            // The links of the single page point to its sections, so they're made to point to the
+            // page from the pages of the other crates.
+            if cx.shared.single_page {
+                text = text.replace("href=\"#", &format!("href=\"{}/index.html#", krate.name));
+            }
+            if let Some(ref prefix) = cx.shared.layout.class_prefix {
+                text = class_prefix::prefix_classes(&text, prefix);
+            }
             write!(implementors, "{{text:{},synthetic:{},types:{}}},",
-                   as_json(&imp.inner_impl().to_string()),
+                   as_json(&text),
                    imp.inner_impl().synthetic,
                    as_json(&collect_paths_for_type(imp.inner_impl().for_.clone()))).unwrap();
         }
@@ -1019,19 +1447,46 @@ themePicker.onblur = handleThemeButtonsBlur;
         "), &mydst);
         try_err!(writeln!(&mut f, r"}})()"), &mydst);
     }
-    Ok(())
+    Ok(search_index_js)
+}
+
+fn find_sources(krate: clean::Crate) -> (clean::Crate, Vec<PathBuf>) {
+    let mut finder = SourceFinder {
+        files: Vec::new(),
+        seen: FxHashSet(),
+    };
+    let krate = finder.fold_crate(krate);
+    (krate, finder.files)
 }
 
-fn render_sources(dst: &Path, scx: &mut SharedContext,
-                  krate: clean::Crate) -> Result<clean::Crate, Error> {
+fn render_sources(dst: &Path, scx: &mut SharedContext, krate: &str,
+                  files: &[PathBuf]) -> Result<(), Error> {
+    if files.is_empty() {
+        return Ok(());
+    }
     info!("emitting source files");
-    let dst = dst.join("src").join(&krate.name);
+    let dst = dst.join("src").join(krate);
     try_err!(fs::create_dir_all(&dst), &dst);
-    let mut folder = SourceCollector {
+    let mut collector = SourceCollector {
         dst,
         scx,
     };
-    Ok(folder.fold_crate(krate))
+    for file in files {
+        // If it turns out that we couldn't read this file, then we probably
+        // can't read any of the files (generating html output from json or
+        // something like that), so just don't include sources for the
+        // entire crate. The other option is maintaining this mapping on a
+        // per-file basis, but that's probably not worth it...
+        if let Err(e) = collector.emit_source(file) {
+            println!("warning: source code was requested to be rendered, \
+                      but processing `{}` had an error: {}",
+                     file.display(), e);
+            println!("         skipping rendering of source code");
+            collector.scx.include_sources = false;
+            break;
+        }
+    }
+    Ok(())
 }
 
 /// Writes the entire contents of a string to a destination, not attempting to
@@ -1096,7 +1551,17 @@ fn clean_srcpath<F>(src_root: &Path, p: &Path, keep_filename: bool, mut f: F) wh
 
 /// Attempts to find where an external crate is located, given that we're
 /// rendering in to the specified source destination.
-fn extern_location(e: &clean::ExternalCrate, dst: &Path) -> ExternalLocation {
+fn extern_location(e: &clean::ExternalCrate, extern_url: Option<&str>,
+                   dst: &Path) -> ExternalLocation {
+    // A location given with `--extern-locations` always wins
+    if let Some(url) = extern_url {
+        let mut url = url.to_string();
+        if !url.ends_with("/") {
+            url.push('/');
+        }
+        return Remote(url);
+    }
+
     // See if there's documentation generated into the local directory
     let local_location = dst.join(&e.name);
     if local_location.is_dir() {
@@ -1117,44 +1582,21 @@ fn extern_location(e: &clean::ExternalCrate, dst: &Path) -> ExternalLocation {
     }).next().unwrap_or(Unknown) // Well, at least we tried.
 }
 
-impl<'a> DocFolder for SourceCollector<'a> {
+impl DocFolder for SourceFinder {
     fn fold_item(&mut self, item: clean::Item) -> Option<clean::Item> {
-        // If we're including source files, and we haven't seen this file yet,
-        // then we need to render it out to the filesystem.
-        if self.scx.include_sources
-            // skip all invalid or macro spans
-            && item.source.filename.is_real()
-            // skip non-local items
-            && item.def_id.is_local() {
-
-            // If it turns out that we couldn't read this file, then we probably
-            // can't read any of the files (generating html output from json or
-            // something like that), so just don't include sources for the
-            // entire crate. The other option is maintaining this mapping on a
-            // per-file basis, but that's probably not worth it...
-            self.scx
-                .include_sources = match self.emit_source(&item.source.filename) {
-                Ok(()) => true,
-                Err(e) => {
-                    println!("warning: source code was requested to be rendered, \
-                              but processing `{}` had an error: {}",
-                             item.source.filename, e);
-                    println!("         skipping rendering of source code");
-                    false
-                }
-            };
+        // skip all invalid or macro spans, and non-local items
+        if let FileName::Real(ref file) = item.source.filename {
+            if item.def_id.is_local() && self.seen.insert(file.clone()) {
+                self.files.push(file.clone());
+            }
         }
         self.fold_item_recur(item)
     }
 }
 
 impl<'a> SourceCollector<'a> {
-    /// Renders the given filename into its corresponding HTML source file.
-    fn emit_source(&mut self, filename: &FileName) -> io::Result<()> {
-        let p = match *filename {
-            FileName::Real(ref file) => file,
-            _ => return Ok(()),
-        };
+    /// Renders the given file into its corresponding HTML source file.
+    fn emit_source(&mut self, p: &PathBuf) -> io::Result<()> {
         if self.scx.local_sources.contains_key(&**p) {
             // We've already emitted this source
             return Ok(());
@@ -1171,12 +1613,12 @@ impl<'a> SourceCollector<'a> {
 
         // Create the intermediate directories
         let mut cur = self.dst.clone();
-        let mut root_path = String::from("../../");
+        let mut depth = 2;
         let mut href = String::new();
         clean_srcpath(&self.scx.src_root, &p, false, |component| {
             cur.push(component);
             fs::create_dir_all(&cur).unwrap();
-            root_path.push_str("../");
+            depth += 1;
             href.push_str(component);
             href.push('/');
         });
@@ -1186,27 +1628,75 @@ impl<'a> SourceCollector<'a> {
         fname.push(".html");
         cur.push(&fname);
         href.push_str(&fname.to_string_lossy());
+        let root_path = self.scx.root_path("../".repeat(depth));
+
+        // The cache's location is empty while the sources are rendered, so the links to the
+        // documentation are relative to its root. The items of other crates are linked to their
+        // source if it can be found, and to their documentation otherwise.
+        let cache = cache();
+        let links = cache.span_map.paths.get(&**p).map(|spans| {
+            spans.iter().filter_map(|(&range, &did)| {
+                self.extern_src_href(&cache, did, &root_path).or_else(|| {
+                    ::html::format::href(did).map(|(url, ..)| format!("{}{}", root_path, url))
+                }).map(|url| (range, url))
+            }).collect()
+        }).unwrap_or_else(FxHashMap);
 
         let mut w = BufWriter::new(File::create(&cur)?);
         let title = format!("{} -- source", cur.file_name().unwrap()
                                                .to_string_lossy());
-        let desc = format!("Source to the Rust file `{}`.", filename);
+        let desc = format!("Source to the Rust file `{}`.", p.display());
         let page = layout::Page {
             title: &title,
             css_class: "source",
             root_path: &root_path,
+            depth,
             description: &desc,
             keywords: BASIC_KEYWORDS,
             resource_suffix: &self.scx.resource_suffix,
         };
         layout::render(&mut w, &self.scx.layout,
-                       &page, &(""), &Source(contents),
+                       &page, &(""), &Source(contents, &links),
                        self.scx.css_file_extension.is_some(),
                        &self.scx.themes)?;
         w.flush()?;
         self.scx.local_sources.insert(p.clone(), href);
         Ok(())
     }
+
+    /// Returns the URL of the source page defining the given item of another crate, if the
+    /// location of its definition was recorded and the source pages of its crate can be found.
+    fn extern_src_href(&self, cache: &Cache, did: DefId, root_path: &str) -> Option<String> {
+        let &(ref file, loline, hiline) = cache.span_map.extern_defs.get(&did)?;
+        let (krate, src_root, remote) = match cache.extern_locations.get(&did.krate)? {
+            &(ref name, ref src, Local) => (name, src, None),
+            &(ref name, ref src, Remote(ref s)) => (name, src, Some(s)),
+            &(_, _, Unknown) => return None,
+        };
+
+        let mut path = String::new();
+        clean_srcpath(src_root, file, false, |component| {
+            path.push_str(component);
+            path.push('/');
+        });
+        let mut fname = file.file_name()?.to_os_string();
+        fname.push(".html");
+        path.push_str(&fname.to_string_lossy());
+
+        // Only link to the local source pages which were actually generated, the crate may have
+        // been documented without them.
+        if remote.is_none() && !self.dst.parent()?.join(krate).join(&path).is_file() {
+            return None;
+        }
+
+        let lines = if loline == hiline {
+            loline.to_string()
+        } else {
+            format!("{}-{}", loline, hiline)
+        };
+        Some(format!("{}src/{}/{}#{}",
+                     remote.map_or(root_path, |s| &s[..]), krate, path, lines))
+    }
 }
 
 impl DocFolder for Cache {
@@ -1300,7 +1790,9 @@ impl DocFolder for Cache {
                     // A crate has a module at its root, containing all items,
                     // which should not be indexed. The crate-item itself is
                     // inserted later on when serializing the search-index.
-                    if item.def_id.index != CRATE_DEF_INDEX {
+                    // The items inlined from a masked crate aren't indexed either.
+                    if item.def_id.index != CRATE_DEF_INDEX &&
+                       !self.masked_crates.contains(&item.def_id.krate) {
                         self.search_index.push(IndexItem {
                             ty: item.type_(),
                             name: s.to_string(),
@@ -1332,11 +1824,12 @@ impl DocFolder for Cache {
 
         match item.inner {
             clean::StructItem(..) | clean::EnumItem(..) |
-            clean::TypedefItem(..) | clean::TraitItem(..) |
+            clean::TypedefItem(..) | clean::TraitItem(..) | clean::TraitAliasItem(..) |
             clean::FunctionItem(..) | clean::ModuleItem(..) |
             clean::ForeignFunctionItem(..) | clean::ForeignStaticItem(..) |
             clean::ConstantItem(..) | clean::StaticItem(..) |
-            clean::UnionItem(..) | clean::ForeignTypeItem | clean::MacroItem(..)
+            clean::UnionItem(..) | clean::ForeignTypeItem | clean::MacroItem(..) |
+            clean::ProcMacroItem(..)
             if !self.stripped_mod => {
                 // Re-exported items mean that the same id can show up twice
                 // in the rustdoc ast that we're looking at. We know,
@@ -1351,6 +1844,11 @@ impl DocFolder for Cache {
                                       (self.stack.clone(), item.type_()));
                 }
                 self.add_aliases(&item);
+                if let Some(ref mut summaries) = self.summaries {
+                    if let Some(doc) = item.doc_value() {
+                        summaries.insert(item.def_id, plain_summary_line(Some(doc)));
+                    }
+                }
             }
             // Link variants to their parent enum because pages aren't emitted
             // for each variant.
@@ -1544,7 +2042,10 @@ struct AllTypes {
     unions: HashSet<ItemEntry>,
     primitives: HashSet<ItemEntry>,
     traits: HashSet<ItemEntry>,
+    trait_aliases: HashSet<ItemEntry>,
     macros: HashSet<ItemEntry>,
+    attributes: HashSet<ItemEntry>,
+    derives: HashSet<ItemEntry>,
     functions: HashSet<ItemEntry>,
     typedefs: HashSet<ItemEntry>,
     existentials: HashSet<ItemEntry>,
@@ -1561,7 +2062,10 @@ impl AllTypes {
             unions: HashSet::with_capacity(100),
             primitives: HashSet::with_capacity(26),
             traits: HashSet::with_capacity(100),
+            trait_aliases: HashSet::with_capacity(100),
             macros: HashSet::with_capacity(100),
+            attributes: HashSet::with_capacity(100),
+            derives: HashSet::with_capacity(100),
             functions: HashSet::with_capacity(100),
             typedefs: HashSet::with_capacity(100),
             existentials: HashSet::with_capacity(100),
@@ -1583,7 +2087,10 @@ impl AllTypes {
                 ItemType::Union => self.unions.insert(ItemEntry::new(new_url, name)),
                 ItemType::Primitive => self.primitives.insert(ItemEntry::new(new_url, name)),
                 ItemType::Trait => self.traits.insert(ItemEntry::new(new_url, name)),
+                ItemType::TraitAlias => self.trait_aliases.insert(ItemEntry::new(new_url, name)),
                 ItemType::Macro => self.macros.insert(ItemEntry::new(new_url, name)),
+                ItemType::ProcAttribute => self.attributes.insert(ItemEntry::new(new_url, name)),
+                ItemType::ProcDerive => self.derives.insert(ItemEntry::new(new_url, name)),
                 ItemType::Function => self.functions.insert(ItemEntry::new(new_url, name)),
                 ItemType::Typedef => self.typedefs.insert(ItemEntry::new(new_url, name)),
                 ItemType::Existential => self.existentials.insert(ItemEntry::new(new_url, name)),
@@ -1627,7 +2134,10 @@ impl fmt::Display for AllTypes {
         print_entries(f, &self.unions, "Unions", "unions")?;
         print_entries(f, &self.primitives, "Primitives", "primitives")?;
         print_entries(f, &self.traits, "Traits", "traits")?;
+        print_entries(f, &self.trait_aliases, "Trait Aliases", "trait-aliases")?;
         print_entries(f, &self.macros, "Macros", "macros")?;
+        print_entries(f, &self.attributes, "Attribute Macros", "attributes")?;
+        print_entries(f, &self.derives, "Derive Macros", "derives")?;
         print_entries(f, &self.functions, "Functions", "functions")?;
         print_entries(f, &self.typedefs, "Typedefs", "typedefs")?;
         print_entries(f, &self.existentials, "Existentials", "existentials")?;
@@ -1694,7 +2204,16 @@ impl Context {
     /// String representation of how to get back to the root path of the 'doc/'
     /// folder in terms of a relative URL.
     fn root_path(&self) -> String {
-        "../".repeat(self.current.len())
+        self.shared.root_path("../".repeat(self.depth()))
+    }
+
+    /// The number of directories between the current page and the root of the 'doc/' folder.
+    fn depth(&self) -> usize {
+        if self.shared.single_page {
+            // The single page is the index of the crate.
+            return 1;
+        }
+        self.current.len()
     }
 
     /// Recurse in the directory structure and change the "root path" to make
@@ -1738,6 +2257,10 @@ impl Context {
         let crate_name = krate.name.clone();
         item.name = Some(krate.name);
 
+        if self.shared.single_page {
+            return self.render_single_page(item);
+        }
+
         let mut all = AllTypes::new();
 
         {
@@ -1756,10 +2279,13 @@ impl Context {
         if !root_path.ends_with('/') {
             root_path.push('/');
         }
+        let crate_root_path = self.shared.root_path("../".to_string());
+        let settings_root_path = self.shared.root_path("./".to_string());
         let mut page = layout::Page {
             title: "List of all items in this crate",
             css_class: "mod",
-            root_path: "../",
+            root_path: &crate_root_path,
+            depth: 1,
             description: "List of all items in this crate",
             keywords: BASIC_KEYWORDS,
             resource_suffix: &self.shared.resource_suffix,
@@ -1780,11 +2306,28 @@ impl Context {
                                 &self.shared.themes),
                  &final_file);
 
+        if let Some(ref features) = self.shared.crate_features {
+            let features_file = self.dst.join(&crate_name).join("features.html");
+            let mut w = BufWriter::new(try_err!(File::create(&features_file), &features_file));
+            let content = FeaturesPage { krate: &crate_name, features, codes: self.codes };
+            page.title = "Features of this crate";
+            page.description = "The feature flags of this crate";
+            let sidebar = format!("<p class='location'>Crate {}</p>\
+                                   <a id='all-types' href='index.html'><p>Back to index</p></a>",
+                                  crate_name);
+            try_err!(layout::render(&mut w, &self.shared.layout,
+                                    &page, &sidebar, &content,
+                                    self.shared.css_file_extension.is_some(),
+                                    &self.shared.themes),
+                     &features_file);
+        }
+
         // Generating settings page.
-        let settings = Settings::new("./", &self.shared.resource_suffix);
+        let settings = Settings::new(&settings_root_path, &self.shared.resource_suffix);
         page.title = "Rustdoc settings";
         page.description = "Settings of Rustdoc";
-        page.root_path = "./";
+        page.root_path = &settings_root_path;
+        page.depth = 0;
 
         let mut w = BufWriter::new(try_err!(File::create(&settings_file), &settings_file));
         let mut themes = self.shared.themes.clone();
@@ -1803,6 +2346,87 @@ impl Context {
         Ok(())
     }
 
+    /// Renders the whole crate into its `index.html` page, each item being a section of it.
+    fn render_single_page(mut self, krate: clean::Item) -> Result<(), Error> {
+        let crate_name = krate.name.clone().unwrap();
+        let dst = self.dst.join(&crate_name);
+        try_err!(self.shared.ensure_dir(&dst), &dst);
+        let dst = dst.join("index.html");
+
+        // All the links are relative to the single page.
+        CURRENT_LOCATION_KEY.with(|slot| {
+            *slot.borrow_mut() = vec![crate_name.clone()];
+        });
+        let pages = single_page_sections(&cache());
+        let mut sections = Vec::new();
+        try_err!(self.render_single_page_item(&mut sections, krate, &pages), &dst);
+
+        let title = format!("{} - Rust", crate_name);
+        let desc = format!("API documentation for the Rust `{}` crate.", crate_name);
+        let root_path = self.shared.root_path("../".to_string());
+        let page = layout::Page {
+            css_class: "mod",
+            root_path: &root_path,
+            depth: 1,
+            title: &title,
+            description: &desc,
+            keywords: BASIC_KEYWORDS,
+            resource_suffix: &self.shared.resource_suffix,
+        };
+        let mut w = BufWriter::new(try_err!(File::create(&dst), &dst));
+        try_err!(layout::render(&mut w, &self.shared.layout,
+                                &page, &"", &String::from_utf8_lossy(&sections),
+                                self.shared.css_file_extension.is_some(),
+                                &self.shared.themes),
+                 &dst);
+        Ok(())
+    }
+
+    /// Writes the section of the single page of an item, and those of its children if it's a
+    /// module.
+    fn render_single_page_item(&mut self, out: &mut Vec<u8>, item: clean::Item,
+                               pages: &FxHashMap<String, String>) -> io::Result<()> {
+        // Stripped modules only get redirect pages, which the single page has no use for.
+        if item.is_stripped() {
+            return Ok(());
+        }
+
+        if item.is_mod() {
+            self.current.push(item.name.clone().unwrap());
+            let anchor = single_page_anchor(&self.current, ItemType::Module);
+            self.render_single_page_section(out, &item, &anchor, pages)?;
+            let m = match item.inner {
+                clean::ModuleItem(m) => m,
+                _ => unreachable!(),
+            };
+            for item in m.items {
+                self.render_single_page_item(out, item, pages)?;
+            }
+            self.current.pop();
+        } else if let Some(ref name) = item.name {
+            let mut fqp = self.current.clone();
+            fqp.push(name.clone());
+            let anchor = single_page_anchor(&fqp, item.type_());
+            self.render_single_page_section(out, &item, &anchor, pages)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the section `anchor` of the single page, holding the documentation of `item`.
+    ///
+    /// The ids of the section are derived as on the page of the item, and then scoped under the
+    /// anchor of the section, so they don't depend on the items rendered before it. The links
+    /// written in the documentation to the `pages` of the items of the crate are pointed to
+    /// their sections.
+    fn render_single_page_section(&self, out: &mut Vec<u8>, item: &clean::Item, anchor: &str,
+                                  pages: &FxHashMap<String, String>) -> io::Result<()> {
+        self.id_map.borrow_mut().reset();
+        self.id_map.borrow_mut().populate(initial_ids());
+        let html = format!("{}", Item { cx: self, item });
+        let html = link_single_page_sections(&scope_ids(&html, anchor), &self.current[1..], pages);
+        write!(out, "<section id='{}' class='single-page-item'>{}</section>", anchor, html)
+    }
+
     fn render_item(&self,
                    writer: &mut dyn io::Write,
                    it: &clean::Item,
@@ -1839,6 +2463,7 @@ impl Context {
         let page = layout::Page {
             css_class: tyname,
             root_path: &self.root_path(),
+            depth: self.depth(),
             title: &title,
             description: &desc,
             keywords: &keywords,
@@ -1943,6 +2568,9 @@ impl Context {
 
                 if !self.render_redirect_pages {
                     all.append(full_path(self, &item), &item_type);
+                    if self.shared.emit_item_json {
+                        self.render_item_json(&item, &joint_dst.with_extension("json"))?;
+                    }
                 }
                 // Redirect from a sane URL using the namespace to Rustdoc's
                 // URL for the page.
@@ -1969,6 +2597,36 @@ impl Context {
         Ok(())
     }
 
+    /// Writes to `dst` the `--emit-item-json` description of `item`: its path, its documentation,
+    /// the targets of its links, relative to its page, and the cleaned item itself, which holds
+    /// its signature. Modules have none, as their cleaned item holds all of their contents.
+    fn render_item_json(&self, item: &clean::Item, dst: &Path) -> Result<(), Error> {
+        let encoded = json::encode(item).map_err(|e| io::Error::new(io::ErrorKind::Other, e));
+        let encoded = try_err!(encoded, dst);
+
+        let links = item.links().into_iter().map(|(text, href, _)| {
+            let mut link = BTreeMap::new();
+            link.insert("text".to_string(), Json::String(text));
+            link.insert("href".to_string(), Json::String(href));
+            Json::Object(link)
+        }).collect();
+        let docs = self.shared.maybe_collapsed_doc_value(item).map(|s| s.into_owned());
+
+        // The encoded item is written as is, since going through a `Json::Object` would sort
+        // its fields.
+        let mut out = BufWriter::new(try_err!(File::create(dst), dst));
+        try_err!(write!(&mut out,
+                        "{{\"name\":{},\"kind\":{},\"path\":{},\"docs\":{},\
+                          \"links\":{},\"item\":{}}}",
+                        item.name.to_json(),
+                        item.type_().css_class().to_json(),
+                        full_path(self, item).to_json(),
+                        docs.to_json(),
+                        Json::Array(links),
+                        encoded), dst);
+        Ok(())
+    }
+
     fn build_sidebar_items(&self, m: &clean::Module) -> BTreeMap<String, Vec<NameDoc>> {
         // BTreeMap instead of HashMap to get a sorted output
         let mut map = BTreeMap::new();
@@ -2005,9 +2663,21 @@ impl<'a> Item<'a> {
     /// may happen, for example, with externally inlined items where the source
     /// of their crate documentation isn't known.
     fn src_href(&self) -> Option<String> {
+        if !self.cx.shared.include_sources {
+            return None;
+        }
+        // On an item rather than the crate, `#[doc(html_no_source)]` only hides its own source.
+        if self.item.attrs.lists("doc").has_word("html_no_source") {
+            return None;
+        }
         let mut root = self.cx.root_path();
 
         let cache = cache();
+        // The span of an item generated by a macro points into the macro, so its expansion is
+        // shown instead when there's one.
+        if cache.macro_expansions.contains_key(&self.item.def_id) {
+            return Some("#macro-expansion".to_string());
+        }
         let mut path = String::new();
 
         // We can safely ignore macros from other libraries
@@ -2077,11 +2747,18 @@ impl<'a> fmt::Display for Item<'a> {
                 },
             clean::FunctionItem(..) | clean::ForeignFunctionItem(..) => write!(fmt, "Function ")?,
             clean::TraitItem(..) => write!(fmt, "Trait ")?,
+            clean::TraitAliasItem(..) => write!(fmt, "Trait Alias ")?,
             clean::StructItem(..) => write!(fmt, "Struct ")?,
             clean::UnionItem(..) => write!(fmt, "Union ")?,
             clean::EnumItem(..) => write!(fmt, "Enum ")?,
             clean::TypedefItem(..) => write!(fmt, "Type Definition ")?,
             clean::MacroItem(..) => write!(fmt, "Macro ")?,
+            clean::ProcMacroItem(ref mac) => match mac.kind {
+                MacroKind::Bang => write!(fmt, "Macro ")?,
+                MacroKind::Attr => write!(fmt, "Attribute Macro ")?,
+                MacroKind::Derive => write!(fmt, "Derive Macro ")?,
+                MacroKind::ProcMacroStub => unreachable!(),
+            }
             clean::PrimitiveItem(..) => write!(fmt, "Primitive Type ")?,
             clean::StaticItem(..) | clean::ForeignStaticItem(..) => write!(fmt, "Static ")?,
             clean::ConstantItem(..) => write!(fmt, "Constant ")?,
@@ -2097,9 +2774,12 @@ impl<'a> fmt::Display for Item<'a> {
             let cur = &self.cx.current;
             let amt = if self.item.is_mod() { cur.len() - 1 } else { cur.len() };
             for (i, component) in cur.iter().enumerate().take(amt) {
-                write!(fmt, "<a href='{}index.html'>{}</a>::<wbr>",
-                       "../".repeat(cur.len() - i - 1),
-                       component)?;
+                let href = if self.cx.shared.single_page {
+                    format!("#{}", single_page_anchor(&cur[..i + 1], ItemType::Module))
+                } else {
+                    format!("{}index.html", "../".repeat(cur.len() - i - 1))
+                };
+                write!(fmt, "<a href='{}'>{}</a>::<wbr>", href, component)?;
             }
         }
         write!(fmt, "<a class=\"{}\" href=''>{}</a>",
@@ -2134,17 +2814,19 @@ impl<'a> fmt::Display for Item<'a> {
 
         write!(fmt, "</span></h1>")?; // out-of-band
 
-        match self.item.inner {
+        let result = match self.item.inner {
             clean::ModuleItem(ref m) =>
                 item_module(fmt, self.cx, self.item, &m.items),
             clean::FunctionItem(ref f) | clean::ForeignFunctionItem(ref f) =>
                 item_function(fmt, self.cx, self.item, f),
             clean::TraitItem(ref t) => item_trait(fmt, self.cx, self.item, t),
+            clean::TraitAliasItem(ref ta) => item_trait_alias(fmt, self.cx, self.item, ta),
             clean::StructItem(ref s) => item_struct(fmt, self.cx, self.item, s),
             clean::UnionItem(ref s) => item_union(fmt, self.cx, self.item, s),
             clean::EnumItem(ref e) => item_enum(fmt, self.cx, self.item, e),
             clean::TypedefItem(ref t, _) => item_typedef(fmt, self.cx, self.item, t),
             clean::MacroItem(ref m) => item_macro(fmt, self.cx, self.item, m),
+            clean::ProcMacroItem(ref m) => item_proc_macro(fmt, self.cx, self.item, m),
             clean::PrimitiveItem(ref p) => item_primitive(fmt, self.cx, self.item, p),
             clean::StaticItem(ref i) | clean::ForeignStaticItem(ref i) =>
                 item_static(fmt, self.cx, self.item, i),
@@ -2156,10 +2838,138 @@ impl<'a> fmt::Display for Item<'a> {
                 // We don't generate pages for any other type.
                 unreachable!();
             }
+        };
+        result?;
+
+        if let Some(expansion) = cache().macro_expansions.get(&self.item.def_id) {
+            write!(fmt, "<h2 id='macro-expansion' class='small-section-header'>\
+                         Macro Expansion<a href='#macro-expansion' class='anchor'></a>\
+                         </h2>")?;
+            write!(fmt, "{}", highlight::render_with_highlighting(expansion,
+                                                                  Some("macro-expansion"),
+                                                                  None, None))?;
         }
+        Ok(())
     }
 }
 
+/// Returns the id of the section of an item in the page rendered by `--single-page`.
+pub fn single_page_anchor(fqp: &[String], ty: ItemType) -> String {
+    format!("{}.{}", ty.css_class(), fqp.join("::"))
+}
+
+/// Returns the anchors of the sections of the single page, by the path of the page the item of
+/// each would otherwise have, relative to the directory of the crate.
+fn single_page_sections(cache: &Cache) -> FxHashMap<String, String> {
+    cache.paths.iter().filter(|&(did, _)| did.is_local()).map(|(_, &(ref fqp, ty))| {
+        let page = if fqp.len() == 1 {
+            "index.html".to_string()
+        } else {
+            let mut page = fqp[1..fqp.len() - 1].iter()
+                                                .map(|s| format!("{}/", s))
+                                                .collect::<String>();
+            page.push_str(&item_path(ty, fqp.last().unwrap()));
+            page
+        };
+        (page, single_page_anchor(fqp, ty))
+    }).collect()
+}
+
+/// Points the relative links of `html` which lead to one of the `pages` of the crate to its
+/// section of the single page. The links are relative to `dir`, the directory of the crate where
+/// the page of the item documented by `html` would otherwise be.
+fn link_single_page_sections(html: &str, dir: &[String],
+                             pages: &FxHashMap<String, String>) -> String {
+    rewrite_anchor_attrs(html, |attr, value| {
+        if attr != "href" || value.starts_with('#') || value.starts_with('/') ||
+           value.contains(':') {
+            return None;
+        }
+        let (path, fragment) = match value.find('#') {
+            Some(i) => (&value[..i], Some(&value[i + 1..])),
+            None => (value, None),
+        };
+        let mut components = dir.to_vec();
+        for component in path.split('/') {
+            match component {
+                "" | "." => {}
+                ".." => {
+                    components.pop()?;
+                }
+                _ => components.push(component.to_string()),
+            }
+        }
+        let section = pages.get(&components.join("/"))?;
+        Some(match fragment {
+            Some(fragment) => format!("#{}.{}", section, fragment),
+            None => format!("#{}", section),
+        })
+    })
+}
+
+/// Prefixes the ids and the anchor names of `html`, the section `section` of the single page,
+/// with `section`, along with the links pointing to those ids. The links to the other sections
+/// are left untouched.
+fn scope_ids(html: &str, section: &str) -> String {
+    let mut ids = FxHashSet();
+    rewrite_anchor_attrs(html, |attr, value| {
+        if attr == "id" {
+            ids.insert(value.to_string());
+        }
+        None
+    });
+    rewrite_anchor_attrs(html, |attr, value| match attr {
+        "id" | "name" => Some(format!("{}.{}", section, value)),
+        _ if value.starts_with('#') && ids.contains(&value[1..]) => {
+            Some(format!("#{}.{}", section, &value[1..]))
+        }
+        _ => None,
+    })
+}
+
+/// Calls `f` with the name and the value of each quoted `id`, `name` and `href` attribute of
+/// `html`, replacing the value with the one `f` returns, if any.
+fn rewrite_anchor_attrs<F>(html: &str, mut f: F) -> String
+    where F: FnMut(&str, &str) -> Option<String>
+{
+    let bytes = html.as_bytes();
+    let mut out = String::with_capacity(html.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let attr = ["id", "name", "href"].iter().cloned().find(|attr| {
+            bytes[i] == b' ' && bytes[i + 1..].starts_with(attr.as_bytes()) &&
+            bytes.get(i + 1 + attr.len()) == Some(&b'=')
+        });
+        let (attr, start) = match attr {
+            Some(attr) => (attr, i + attr.len() + 2),
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+        let quote = match bytes.get(start) {
+            Some(&q) if q == b'\'' || q == b'"' => q,
+            _ => {
+                i = start;
+                continue;
+            }
+        };
+        let end = match bytes[start + 1..].iter().position(|&b| b == quote) {
+            Some(len) => start + 1 + len,
+            None => break,
+        };
+        if let Some(value) = f(attr, &html[start + 1..end]) {
+            out.push_str(&html[copied..start + 1]);
+            out.push_str(&value);
+            copied = end;
+        }
+        i = end;
+    }
+    out.push_str(&html[copied..]);
+    out
+}
+
 fn item_path(ty: ItemType, name: &str) -> String {
     match ty {
         ItemType::Module => format!("{}/index.html", name),
@@ -2207,7 +3017,7 @@ fn document(w: &mut fmt::Formatter, cx: &Context, item: &clean::Item) -> fmt::Re
 fn render_markdown(w: &mut fmt::Formatter,
                    cx: &Context,
                    md_text: &str,
-                   links: Vec<(String, String)>,
+                   links: Vec<(String, String, Option<String>)>,
                    prefix: &str)
                    -> fmt::Result {
     let mut ids = cx.id_map.borrow_mut();
@@ -2330,15 +3140,17 @@ fn item_module(w: &mut fmt::Formatter, cx: &Context,
             ItemType::Primitive       => 2,
             ItemType::Module          => 3,
             ItemType::Macro           => 4,
-            ItemType::Struct          => 5,
-            ItemType::Enum            => 6,
-            ItemType::Constant        => 7,
-            ItemType::Static          => 8,
-            ItemType::Trait           => 9,
-            ItemType::Function        => 10,
-            ItemType::Typedef         => 12,
-            ItemType::Union           => 13,
-            _                         => 14 + ty as u8,
+            ItemType::ProcAttribute   => 5,
+            ItemType::ProcDerive      => 6,
+            ItemType::Struct          => 7,
+            ItemType::Enum            => 8,
+            ItemType::Constant        => 9,
+            ItemType::Static          => 10,
+            ItemType::Trait           => 11,
+            ItemType::Function        => 12,
+            ItemType::Typedef         => 14,
+            ItemType::Union           => 15,
+            _                         => 16 + ty as u8,
         }
     }
 
@@ -2463,10 +3275,28 @@ fn item_module(w: &mut fmt::Formatter, cx: &Context,
                     if func.header.unsafety == hir::Unsafety::Unsafe => {
                         "<a title='unsafe function' href='#'><sup>⚠</sup></a>"
                     }
+                    clean::StaticItem(ref s) | clean::ForeignStaticItem(ref s)
+                    if s.mutability == clean::Mutable => {
+                        "<a title='mutable static' href='#'><sup>⚠</sup></a>"
+                    }
                     _ => "",
                 };
 
                 let doc_value = myitem.doc_value().unwrap_or("");
+                // The markup is dropped from the shortened summaries.
+                let docs = match cx.shared.summary_length {
+                    Some(length) => {
+                        Escape(&markdown::truncate_summary(doc_value, length)).to_string()
+                    }
+                    None => MarkdownSummaryLine(doc_value, &myitem.links()).to_string(),
+                };
+                let href = if cx.shared.single_page {
+                    let mut fqp = cx.current.clone();
+                    fqp.push(myitem.name.clone().unwrap());
+                    format!("#{}", single_page_anchor(&fqp, myitem.type_()))
+                } else {
+                    item_path(myitem.type_(), myitem.name.as_ref().unwrap())
+                };
                 write!(w, "
                        <tr class='{stab} module-item'>
                            <td><a class=\"{class}\" href=\"{href}\"
@@ -2477,11 +3307,11 @@ fn item_module(w: &mut fmt::Formatter, cx: &Context,
                        </tr>",
                        name = *myitem.name.as_ref().unwrap(),
                        stab_docs = stab_docs,
-                       docs = MarkdownSummaryLine(doc_value, &myitem.links()),
+                       docs = docs,
                        class = myitem.type_(),
                        stab = myitem.stability_class().unwrap_or("".to_string()),
                        unsafety_flag = unsafety_flag,
-                       href = item_path(myitem.type_(), myitem.name.as_ref().unwrap()),
+                       href = href,
                        title_type = myitem.type_(),
                        title = full_path(cx, myitem))?;
             }
@@ -2494,6 +3324,38 @@ fn item_module(w: &mut fmt::Formatter, cx: &Context,
     Ok(())
 }
 
+/// Whether `item` is deprecated, through `#[deprecated]` or `#[rustc_deprecated]`.
+fn is_deprecated(item: &clean::Item) -> bool {
+    item.deprecation.is_some() ||
+        item.stability.as_ref().map_or(false, |stab| !stab.deprecated_since.is_empty())
+}
+
+fn deprecation_note(depr: &clean::Deprecation, cx: &Context, show_reason: bool) -> String {
+    let error_codes = ErrorCodes::from(UnstableFeatures::from_environment().is_nightly_build());
+    let note = if show_reason && !depr.note.is_empty() {
+        format!(": {}", depr.note)
+    } else {
+        String::new()
+    };
+    let since = if show_reason && !depr.since.is_empty() {
+        format!(" since {}", Escape(&depr.since))
+    } else {
+        String::new()
+    };
+
+    let mut ids = cx.id_map.borrow_mut();
+    let text = if stability::deprecation_in_effect(&depr.since) {
+        format!("Deprecated{}{}",
+                since,
+                MarkdownHtml(&note, RefCell::new(&mut ids), error_codes))
+    } else {
+        format!("Deprecating in {}{}",
+                Escape(&depr.since),
+                MarkdownHtml(&note, RefCell::new(&mut ids), error_codes))
+    };
+    format!("<div class='stab deprecated'>{}</div>", text)
+}
+
 fn short_stability(item: &clean::Item, cx: &Context, show_reason: bool) -> Vec<String> {
     let mut stability = vec![];
     let error_codes = ErrorCodes::from(UnstableFeatures::from_environment().is_nightly_build());
@@ -2559,31 +3421,11 @@ fn short_stability(item: &clean::Item, cx: &Context, show_reason: bool) -> Vec<S
             }
         };
     } else if let Some(depr) = item.deprecation.as_ref() {
-        let note = if show_reason && !depr.note.is_empty() {
-            format!(": {}", depr.note)
-        } else {
-            String::new()
-        };
-        let since = if show_reason && !depr.since.is_empty() {
-            format!(" since {}", Escape(&depr.since))
-        } else {
-            String::new()
-        };
-
-        let mut ids = cx.id_map.borrow_mut();
-        let text = if stability::deprecation_in_effect(&depr.since) {
-            format!("Deprecated{}{}",
-                    since,
-                    MarkdownHtml(&note, RefCell::new(&mut ids), error_codes))
-        } else {
-            format!("Deprecating in {}{}",
-                    Escape(&depr.since),
-                    MarkdownHtml(&note, RefCell::new(&mut ids), error_codes))
-        };
-        stability.push(format!("<div class='stab deprecated'>{}</div>", text))
+        stability.push(deprecation_note(depr, cx, show_reason))
     }
 
-    if let Some(ref cfg) = item.attrs.cfg {
+    let cfg = item.attrs.cfg.as_ref().and_then(|cfg| cfg.without_hidden(&cx.shared.hidden_cfgs));
+    if let Some(ref cfg) = cfg {
         stability.push(format!("<div class='stab portability'>{}</div>", if show_reason {
             cfg.render_long_html()
         } else {
@@ -2591,9 +3433,71 @@ fn short_stability(item: &clean::Item, cx: &Context, show_reason: bool) -> Vec<S
         }));
     }
 
+    if show_reason {
+        for name in badge_attributes(item, cx) {
+            stability.push(format!("<div class='stab attribute'><code>#[{}]</code></div>",
+                                   name));
+        }
+    }
+
     stability
 }
 
+/// The attributes of the given function which are shown as badges, in the order they are
+/// written.
+fn badge_attributes(item: &clean::Item, cx: &Context) -> Vec<String> {
+    match item.inner {
+        clean::FunctionItem(..) | clean::MethodItem(..) | clean::TyMethodItem(..) |
+        clean::ForeignFunctionItem(..) => {}
+        _ => return Vec::new(),
+    }
+    let mut names = Vec::new();
+    for attr in &item.attrs.other_attrs {
+        let name = attr.name().to_string();
+        if cx.shared.badge_attributes.contains(&name) && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Notes that the given method of a trait, or of an impl of a trait, can't be called on trait
+/// objects, when it has a `where Self: Sized` bound. The bound means nothing to the methods of the
+/// inherent impls.
+fn document_sized_self(w: &mut fmt::Formatter, item: &clean::Item) -> fmt::Result {
+    if requires_sized_self(item) {
+        write!(w, "<div class='stability'><div class='stab sized-self'>This method is not \
+                   available on trait objects because it requires <code>Self: Sized</code>.\
+                   </div></div>")?;
+    }
+    Ok(())
+}
+
+/// Whether the given method has a `where Self: Sized` bound, which makes it impossible to call
+/// on a trait object.
+fn requires_sized_self(item: &clean::Item) -> bool {
+    let generics = match item.inner {
+        clean::TyMethodItem(ref m) => &m.generics,
+        clean::MethodItem(ref m) => &m.generics,
+        _ => return false,
+    };
+    let sized_trait_did = match cache().sized_trait_did {
+        Some(did) => did,
+        None => return false,
+    };
+    generics.where_predicates.iter().any(|pred| match *pred {
+        clean::WherePredicate::BoundPredicate { ty: clean::Generic(ref name), ref bounds } => {
+            name == "Self" && bounds.iter().any(|bound| match *bound {
+                clean::GenericBound::TraitBound(ref poly_trait, hir::TraitBoundModifier::None) => {
+                    poly_trait.trait_.def_id() == Some(sized_trait_did)
+                }
+                _ => false,
+            })
+        }
+        _ => false,
+    })
+}
+
 struct Initializer<'a>(&'a str);
 
 impl<'a> fmt::Display for Initializer<'a> {
@@ -2629,6 +3533,14 @@ fn item_static(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
            name = it.name.as_ref().unwrap(),
            typ = s.type_,
            init = Initializer(&s.expr))?;
+    if it.attrs.other_attrs.iter().any(|a| a.check_name("thread_local")) {
+        write!(w, "<div class='stab thread-local'>This static is thread-local: each thread \
+                   has its own copy of it.</div>")?;
+    }
+    if s.mutability == clean::Mutable {
+        write!(w, "<div class='stab mutable'>This static is mutable: accessing it requires \
+                   <code>unsafe</code>.</div>")?;
+    }
     document(w, cx, it)
 }
 
@@ -2660,9 +3572,98 @@ fn item_function(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
               name_len,
               indent: 0,
            })?;
+    if cx.shared.show_async_desugaring && f.header.asyncness == hir::IsAsync::Async {
+        if let Some(future_trait) = cache().future_trait_did {
+            let decl = f.decl.async_desugared(future_trait);
+            write!(w,
+                   "<pre class='rust fn async-desugaring'>{vis}{constness}{unsafety}{abi}fn \
+                   {name}{generics}{decl}{where_clause}</pre>",
+                   vis = VisSpace(&it.visibility),
+                   constness = ConstnessSpace(f.header.constness),
+                   unsafety = UnsafetySpace(f.header.unsafety),
+                   abi = AbiSpace(f.header.abi),
+                   name = it.name.as_ref().unwrap(),
+                   generics = f.generics,
+                   where_clause = WhereClause { gens: &f.generics, indent: 0, end_newline: true },
+                   decl = Method {
+                      decl: &decl,
+                      name_len,
+                      indent: 0,
+                   })?;
+        }
+    }
     document(w, cx, it)
 }
 
+/// The smallest number of impls of a trait for tuples or arrays of different lengths which are
+/// collapsed into a single entry of the implementors.
+const MIN_IMPL_FAMILY: usize = 3;
+
+/// A family of impls of a trait for tuples or arrays of different lengths, as written by a macro
+/// for `(A,)`, `(A, B)`, `(A, B, C)`...
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ImplFamily {
+    Tuple,
+    Array,
+}
+
+impl ImplFamily {
+    /// Returns the family of `implementor` and the length of its tuple or array, if it is
+    /// implemented for a tuple of type parameters or for an array.
+    fn of(implementor: &Impl) -> Option<(ImplFamily, usize)> {
+        match implementor.inner_impl().for_ {
+            clean::Tuple(ref tys) => {
+                let all_params = tys.iter().all(|ty| match *ty {
+                    clean::Generic(_) => true,
+                    _ => false,
+                });
+                if !tys.is_empty() && all_params {
+                    Some((ImplFamily::Tuple, tys.len()))
+                } else {
+                    None
+                }
+            }
+            clean::Array(_, ref n) => n.parse().ok().map(|n| (ImplFamily::Array, n)),
+            _ => None,
+        }
+    }
+
+    /// Describes the lengths the family `members`, sorted by length, are implemented for.
+    fn summary(&self, members: &[&Impl]) -> String {
+        let len = |i: &Impl| ImplFamily::of(i).map_or(0, |(_, len)| len);
+        format!("Implemented for {} of {} up to {} elements",
+                match *self {
+                    ImplFamily::Tuple => "tuples",
+                    ImplFamily::Array => "arrays",
+                },
+                len(members[0]),
+                len(members[members.len() - 1]))
+    }
+}
+
+/// Splits the impls of `impls` which belong to a tuple or array family of at least
+/// `MIN_IMPL_FAMILY` members, each sorted by length, from the other ones.
+fn split_impl_families<'a>(impls: Vec<&'a Impl>)
+                           -> (Vec<(ImplFamily, Vec<&'a Impl>)>, Vec<&'a Impl>) {
+    let mut families: Vec<(ImplFamily, Vec<&Impl>)> = Vec::new();
+    for &implementor in &impls {
+        if let Some((family, _)) = ImplFamily::of(implementor) {
+            match families.iter().position(|&(f, _)| f == family) {
+                Some(pos) => families[pos].1.push(implementor),
+                None => families.push((family, vec![implementor])),
+            }
+        }
+    }
+    families.retain(|&(_, ref members)| members.len() >= MIN_IMPL_FAMILY);
+    for &mut (_, ref mut members) in &mut families {
+        members.sort_by_key(|i| ImplFamily::of(i).map(|(_, len)| len));
+    }
+    let rest = impls.into_iter().filter(|&i| {
+        !families.iter().any(|&(_, ref members)| members.iter().any(|&m| ::std::ptr::eq(m, i)))
+    }).collect();
+    (families, rest)
+}
+
 fn render_implementor(cx: &Context, implementor: &Impl, w: &mut fmt::Formatter,
                       implementor_dups: &FxHashMap<&str, (DefId, bool)>) -> fmt::Result {
     write!(w, "<li><table class='table-display'><tbody><tr><td><code>")?;
@@ -2680,7 +3681,8 @@ fn render_implementor(cx: &Context, implementor: &Impl, w: &mut fmt::Formatter,
     for it in &implementor.inner_impl().items {
         if let clean::TypedefItem(ref tydef, _) = it.inner {
             write!(w, "<span class=\"where fmt-newline\">  ")?;
-            assoc_type(w, it, &[], Some(&tydef.type_), AssocItemLink::Anchor(None))?;
+            assoc_type(w, it, &tydef.generics, &[], Some(&tydef.type_),
+                       AssocItemLink::Anchor(None), 0)?;
             write!(w, ";</span>")?;
         }
     }
@@ -2696,11 +3698,13 @@ fn render_implementor(cx: &Context, implementor: &Impl, w: &mut fmt::Formatter,
 }
 
 fn render_impls(cx: &Context, w: &mut fmt::Formatter,
-                traits: &[&&Impl],
+                impls: &[&Impl],
                 containing_item: &clean::Item) -> fmt::Result {
-    for i in traits {
-        let did = i.trait_did().unwrap();
-        let assoc_link = AssocItemLink::GotoSource(did, &i.inner_impl().provided_trait_methods);
+    for i in impls {
+        let assoc_link = match i.trait_did() {
+            Some(did) => AssocItemLink::GotoSource(did, &i.inner_impl().provided_trait_methods),
+            None => AssocItemLink::Anchor(None),
+        };
         render_impl(w, cx, i, assoc_link,
                     RenderMode::Normal, containing_item.stable_since(), true)?;
     }
@@ -2729,6 +3733,52 @@ fn bounds(t_bounds: &[clean::GenericBound]) -> String {
     bounds
 }
 
+/// Whether one of the supertraits of `t` can't be named outside of this crate, which keeps the
+/// other crates from implementing `t`.
+fn is_sealed(t: &clean::Trait) -> bool {
+    let c = cache();
+    let is_private = |bound: &clean::GenericBound| match *bound {
+        clean::GenericBound::TraitBound(ref poly, _) => match poly.trait_.def_id() {
+            Some(did) => did.is_local() && !c.access_levels.is_public(did),
+            None => false,
+        },
+        clean::GenericBound::Outlives(..) => false,
+    };
+    t.bounds.iter().any(&is_private) ||
+        t.generics.where_predicates.iter().any(|pred| match *pred {
+            clean::WherePredicate::BoundPredicate { ref ty, ref bounds } => {
+                ty.is_self_type() && bounds.iter().any(&is_private)
+            }
+            _ => false,
+        })
+}
+
+/// The message of the `#[rustc_on_unimplemented]` attribute of a trait, which the compiler
+/// gives when a type doesn't implement it: either the `message` or, failing that, the `label`.
+fn on_unimplemented_message(it: &clean::Item) -> Option<String> {
+    let attr = it.attrs.other_attrs.iter().find(|a| a.check_name("rustc_on_unimplemented"))?;
+    if let Some(message) = attr.value_str() {
+        return Some(message.to_string());
+    }
+    let items = attr.meta_item_list()?;
+    ["message", "label"].iter().filter_map(|&name| {
+        items.iter().find(|item| item.check_name(name)).and_then(|item| item.value_str())
+    }).next().map(|message| message.to_string())
+}
+
+/// The methods listed by the `#[rustc_must_implement_one_of]` attribute of a trait, at least one
+/// of which its implementations have to define, as each of them is provided in terms of the
+/// others.
+fn must_implement_one_of(it: &clean::Item) -> Option<Vec<String>> {
+    let attr = it.attrs.other_attrs.iter().find(|a| a.check_name("rustc_must_implement_one_of"))?;
+    let names = attr.meta_item_list()?
+                    .iter()
+                    .filter_map(|item| item.word())
+                    .map(|word| word.name().to_string())
+                    .collect::<Vec<_>>();
+    if names.is_empty() { None } else { Some(names) }
+}
+
 fn item_trait(
     w: &mut fmt::Formatter,
     cx: &Context,
@@ -2764,22 +3814,31 @@ fn item_trait(
         } else {
             // FIXME: we should be using a derived_id for the Anchors here
             write!(w, "{{\n")?;
+            // Each kind of item is a group of its own, labelled like the sections below.
+            let mut groups = 0;
+            let mut group = |w: &mut fmt::Formatter, items: &[&clean::Item], label: &str| {
+                if items.is_empty() {
+                    return Ok(());
+                }
+                if groups > 0 {
+                    w.write_str("\n")?;
+                }
+                groups += 1;
+                write!(w, "    <span class='comment'>// {}</span>\n", label)
+            };
+            group(w, &types, "Associated types")?;
             for t in &types {
                 write!(w, "    ")?;
                 render_assoc_item(w, t, AssocItemLink::Anchor(None), ItemType::Trait)?;
                 write!(w, ";\n")?;
             }
-            if !types.is_empty() && !consts.is_empty() {
-                w.write_str("\n")?;
-            }
+            group(w, &consts, "Associated constants")?;
             for t in &consts {
                 write!(w, "    ")?;
                 render_assoc_item(w, t, AssocItemLink::Anchor(None), ItemType::Trait)?;
                 write!(w, ";\n")?;
             }
-            if !consts.is_empty() && !required.is_empty() {
-                w.write_str("\n")?;
-            }
+            group(w, &required, "Required methods")?;
             for (pos, m) in required.iter().enumerate() {
                 write!(w, "    ")?;
                 render_assoc_item(w, m, AssocItemLink::Anchor(None), ItemType::Trait)?;
@@ -2789,9 +3848,7 @@ fn item_trait(
                    write!(w, "<div class='item-spacer'></div>")?;
                 }
             }
-            if !required.is_empty() && !provided.is_empty() {
-                w.write_str("\n")?;
-            }
+            group(w, &provided, "Provided methods")?;
             for (pos, m) in provided.iter().enumerate() {
                 write!(w, "    ")?;
                 render_assoc_item(w, m, AssocItemLink::Anchor(None), ItemType::Trait)?;
@@ -2812,9 +3869,38 @@ fn item_trait(
         write!(w, "</pre>")
     })?;
 
+    if cx.shared.detect_sealed_traits && is_sealed(t) {
+        write!(w, "<div class='stab sealed'>This trait is sealed and cannot be implemented \
+                   outside this crate.</div>")?;
+    }
+    if let Some(message) = on_unimplemented_message(it) {
+        write!(w, "<div class='stab on-unimplemented'>When a type doesn't implement this trait, \
+                   the compiler says: <q>{}</q></div>", Escape(&message))?;
+    }
+    if let Some(names) = must_implement_one_of(it) {
+        let methods = names.iter().map(|name| {
+            format!("<a href='#method.{0}'><code>{0}</code></a>", name)
+        }).collect::<Vec<_>>();
+        write!(w, "<div class='stab must-implement'>Implementations must define at least one of \
+                   these methods: {}.</div>", methods.join(", "))?;
+    }
+
     // Trait documentation
     document(w, cx, it)?;
 
+    if !t.object_safety_violations.is_empty() {
+        write!(w, "
+            <h2 id='object-safety' class='small-section-header'>
+              Object Safety<a href='#object-safety' class='anchor'></a>
+            </h2>
+        ")?;
+        let mut reasons = String::from("This trait can't be made into an object because:\n\n");
+        for violation in &t.object_safety_violations {
+            reasons.push_str(&format!("* {}\n", violation));
+        }
+        render_markdown(w, cx, &reasons, Vec::new(), "")?;
+    }
+
     fn trait_item(w: &mut fmt::Formatter, cx: &Context, m: &clean::Item, t: &clean::Item)
                   -> fmt::Result {
         let name = m.name.as_ref().unwrap();
@@ -2822,14 +3908,17 @@ fn item_trait(
         let id = cx.derive_id(format!("{}.{}", item_type, name));
         let ns_id = cx.derive_id(format!("{}.{}", name, item_type.name_space()));
         write!(w, "{extra}<h3 id='{id}' class='method'>\
-                   <span id='{ns_id}' class='invisible'><code>",
+                   <span id='{ns_id}' class='invisible'><code{deprecated}>",
                extra = render_spotlight_traits(m)?,
                id = id,
-               ns_id = ns_id)?;
+               ns_id = ns_id,
+               deprecated = if is_deprecated(m) { " class='deprecated'" } else { "" })?;
         render_assoc_item(w, m, AssocItemLink::Anchor(Some(&id)), ItemType::Impl)?;
         write!(w, "</code>")?;
         render_stability_since(w, m, t)?;
         write!(w, "</span></h3>")?;
+        render_async_desugaring(w, cx, m, AssocItemLink::Anchor(Some(&id)))?;
+        document_sized_self(w, m)?;
         document(w, cx, m)?;
         Ok(())
     }
@@ -2943,16 +4032,38 @@ fn item_trait(
                 </h2>
             ")?;
 
-            for implementor in foreign {
+            let render_foreign = |w: &mut fmt::Formatter, implementor: &Impl| {
                 let assoc_link = AssocItemLink::GotoSource(
                     implementor.impl_item.def_id, &implementor.inner_impl().provided_trait_methods
                 );
-                render_impl(w, cx, &implementor, assoc_link,
-                            RenderMode::Normal, implementor.impl_item.stable_since(), false)?;
+                render_impl(w, cx, implementor, assoc_link,
+                            RenderMode::Normal, implementor.impl_item.stable_since(), false)
+            };
+            let (families, foreign) = split_impl_families(foreign);
+            for (family, members) in families {
+                write!(w, "<details class='impl-family'><summary>{}</summary>",
+                       family.summary(&members))?;
+                for implementor in members {
+                    render_foreign(w, implementor)?;
+                }
+                write!(w, "</details>")?;
+            }
+            for implementor in foreign {
+                render_foreign(w, implementor)?;
             }
         }
 
         write!(w, "{}", impl_header)?;
+        let (families, concrete) = split_impl_families(concrete.into_iter().map(|i| *i).collect());
+        for (family, members) in families {
+            write!(w, "<li class='impl-family'><details><summary>{}</summary>\
+                       <ul class='item-list'>",
+                   family.summary(&members))?;
+            for implementor in members {
+                render_implementor(cx, implementor, w, &implementor_dups)?;
+            }
+            write!(w, "</ul></details></li>")?;
+        }
         for implementor in concrete {
             render_implementor(cx, implementor, w, &implementor_dups)?;
         }
@@ -2983,9 +4094,9 @@ fn item_trait(
            as_json(&synthetic_types))?;
 
     write!(w, r#"<script type="text/javascript" async
-                         src="{root_path}/implementors/{path}/{ty}.{name}.js">
+                         src="{root_path}implementors/{path}/{ty}.{name}.js">
                  </script>"#,
-           root_path = vec![".."; cx.current.len()].join("/"),
+           root_path = cx.root_path(),
            path = if it.def_id.is_local() {
                cx.current.join("/")
            } else {
@@ -3019,26 +4130,33 @@ fn naive_assoc_href(it: &clean::Item, link: AssocItemLink) -> String {
 fn assoc_const(w: &mut fmt::Formatter,
                it: &clean::Item,
                ty: &clean::Type,
-               _default: Option<&String>,
+               default: Option<&String>,
                link: AssocItemLink) -> fmt::Result {
     write!(w, "{}const <a href='{}' class=\"constant\"><b>{}</b></a>: {}",
            VisSpace(&it.visibility),
            naive_assoc_href(it, link),
            it.name.as_ref().unwrap(),
            ty)?;
+    if let Some(default) = default {
+        write!(w, " = {}", Escape(default))?;
+    }
     Ok(())
 }
 
 fn assoc_type<W: fmt::Write>(w: &mut W, it: &clean::Item,
+                             generics: &clean::Generics,
                              bounds: &[clean::GenericBound],
                              default: Option<&clean::Type>,
-                             link: AssocItemLink) -> fmt::Result {
-    write!(w, "type <a href='{}' class=\"type\">{}</a>",
+                             link: AssocItemLink,
+                             indent: usize) -> fmt::Result {
+    write!(w, "type <a href='{}' class=\"type\">{}</a>{}",
            naive_assoc_href(it, link),
-           it.name.as_ref().unwrap())?;
+           it.name.as_ref().unwrap(),
+           generics)?;
     if !bounds.is_empty() {
         write!(w, ": {}", GenericBounds(bounds))?
     }
+    write!(w, "{}", WhereClause { gens: generics, indent, end_newline: false })?;
     if let Some(default) = default {
         write!(w, " = {}", default)?;
     }
@@ -3063,6 +4181,31 @@ fn render_stability_since(w: &mut fmt::Formatter,
     render_stability_since_raw(w, item.stable_since(), containing_item.stable_since())
 }
 
+/// Writes the signature the async method `item` desugars to, as asked with
+/// `--show-async-desugaring`.
+fn render_async_desugaring(w: &mut fmt::Formatter, cx: &Context, item: &clean::Item,
+                           link: AssocItemLink) -> fmt::Result {
+    let future_trait = match cache().future_trait_did {
+        Some(did) if cx.shared.show_async_desugaring => did,
+        _ => return Ok(()),
+    };
+    let mut desugared = item.clone();
+    match desugared.inner {
+        clean::MethodItem(clean::Method { ref mut decl, ref mut header, .. }) |
+        clean::TyMethodItem(clean::TyMethod { ref mut decl, ref mut header, .. }) => {
+            if header.asyncness != hir::IsAsync::Async {
+                return Ok(());
+            }
+            *decl = decl.async_desugared(future_trait);
+            header.asyncness = hir::IsAsync::NotAsync;
+        }
+        _ => return Ok(()),
+    }
+    write!(w, "<div class='async-desugaring'><code>")?;
+    render_assoc_item(w, &desugared, link, ItemType::Impl)?;
+    write!(w, "</code></div>")
+}
+
 fn render_assoc_item(w: &mut fmt::Formatter,
                      item: &clean::Item,
                      link: AssocItemLink,
@@ -3137,15 +4280,95 @@ fn render_assoc_item(w: &mut fmt::Formatter,
             method(w, item, m.header, &m.generics, &m.decl, link, parent)
         }
         clean::AssociatedConstItem(ref ty, ref default) => {
-            assoc_const(w, item, ty, default.as_ref(), link)
+            // Outside of the trait declaration, the default value is already displayed at the
+            // top of the item's documentation.
+            let default = if parent == ItemType::Trait { default.as_ref() } else { None };
+            assoc_const(w, item, ty, default, link)
         }
-        clean::AssociatedTypeItem(ref bounds, ref default) => {
-            assoc_type(w, item, bounds, default.as_ref(), link)
+        clean::AssociatedTypeItem(ref generics, ref bounds, ref default) => {
+            let indent = if parent == ItemType::Trait { 4 } else { 0 };
+            assoc_type(w, item, generics, bounds, default.as_ref(), link, indent)
         }
         _ => panic!("render_assoc_item called on non-associated-item")
     }
 }
 
+/// Notes that the type `it` implements `Drop`, as the order in which its values are dropped may
+/// then matter.
+fn document_drop_impl(w: &mut fmt::Formatter, it: &clean::Item) -> fmt::Result {
+    let c = cache();
+    let drop_trait_did = match c.drop_trait_did {
+        Some(did) => did,
+        None => return Ok(()),
+    };
+    let has_drop_impl = c.impls.get(&it.def_id).map_or(false, |impls| {
+        impls.iter().any(|i| i.trait_did() == Some(drop_trait_did))
+    });
+    if has_drop_impl {
+        write!(w, "<div class='stab drop'>This type implements \
+                   <a href='#impl-Drop'><code>Drop</code></a>: some code runs when its values \
+                   go out of scope.</div>")?;
+    }
+    Ok(())
+}
+
+/// The type of the field a `#[repr(transparent)]` struct wraps, whether that field is public or
+/// not. The zero-sized fields which may come along with it are only recognized when they're
+/// `PhantomData` or `()`.
+fn transparent_inner_type<'a>(it: &clean::Item, s: &'a clean::Struct) -> Option<&'a clean::Type> {
+    if !it.attrs.lists("repr").has_word("transparent") {
+        return None;
+    }
+    let mut types = s.fields.iter().filter_map(|f| {
+        match f.inner {
+            clean::StructFieldItem(ref ty) |
+            clean::StrippedItem(box clean::StructFieldItem(ref ty)) => Some(ty),
+            _ => None,
+        }
+    }).filter(|ty| {
+        match **ty {
+            clean::Tuple(ref tys) => !tys.is_empty(),
+            _ => phantom_data_type(ty).is_none(),
+        }
+    });
+    match (types.next(), types.next()) {
+        (Some(ty), None) => Some(ty),
+        _ => None,
+    }
+}
+
+/// If `ty` is a `PhantomData`, returns the type it marks, if it's written out.
+fn phantom_data_type(ty: &clean::Type) -> Option<Option<&clean::Type>> {
+    let path = match *ty {
+        clean::ResolvedPath { ref path, .. } if path.last_name() == "PhantomData" => path,
+        _ => return None,
+    };
+    match path.segments.last().map(|segment| &segment.args) {
+        Some(&clean::GenericArgs::AngleBracketed { ref types, .. }) => Some(types.first()),
+        _ => Some(None),
+    }
+}
+
+/// Notes with `--annotate-phantom-data` that a field of type `ty` holds no data when it's a
+/// `PhantomData`.
+fn document_phantom_data(w: &mut fmt::Formatter, cx: &Context, ty: &clean::Type) -> fmt::Result {
+    if !cx.shared.annotate_phantom_data {
+        return Ok(());
+    }
+    match phantom_data_type(ty) {
+        Some(Some(marked)) => {
+            write!(w, "<div class='stab phantom-data'>This field holds no data at runtime: it \
+                       tells the compiler that the type acts as if it owned a \
+                       <code>{}</code>, for its variance and drop checking.</div>", marked)
+        }
+        Some(None) => {
+            write!(w, "<div class='stab phantom-data'>This field holds no data at runtime: it \
+                       only matters to the variance and drop checking of the type.</div>")
+        }
+        None => Ok(()),
+    }
+}
+
 fn item_struct(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                s: &clean::Struct) -> fmt::Result {
     wrap_into_docblock(w, |w| {
@@ -3157,10 +4380,17 @@ fn item_struct(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                       s.struct_type,
                       &s.fields,
                       "",
-                      true)?;
+                      true,
+                      cx.shared.show_private_field_names)?;
         write!(w, "</pre>")
     })?;
 
+    if let Some(inner) = transparent_inner_type(it, s) {
+        write!(w, "<div class='stab transparent'>This type is <code>#[repr(transparent)]</code>: \
+                   it has the same layout as <code>{}</code>.</div>", inner)?;
+    }
+    document_drop_impl(w, it)?;
+
     document(w, cx, it)?;
     let mut fields = s.fields.iter().filter_map(|f| {
         match f.inner {
@@ -3184,17 +4414,19 @@ fn item_struct(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                 write!(w, "<span id=\"{id}\" class=\"{item_type} small-section-header\">
                            <a href=\"#{id}\" class=\"anchor field\"></a>
                            <span id=\"{ns_id}\" class='invisible'>
-                           <code>{name}: {ty}</code>
+                           <code>{vis}{name}: {ty}</code>
                            </span></span>",
                        item_type = ItemType::StructField,
                        id = id,
                        ns_id = ns_id,
+                       vis = RestrictedVisSpace(&field.visibility),
                        name = field.name.as_ref().unwrap(),
                        ty = ty)?;
                 if let Some(stability_class) = field.stability_class() {
                     write!(w, "<span class='stab {stab}'></span>",
                         stab = stability_class)?;
                 }
+                document_phantom_data(w, cx, ty)?;
                 document(w, cx, field)?;
             }
         }
@@ -3212,10 +4444,12 @@ fn item_union(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                      Some(&s.generics),
                      &s.fields,
                      "",
-                     true)?;
+                     true,
+                     cx.shared.show_private_field_names)?;
         write!(w, "</pre>")
     })?;
 
+    document_drop_impl(w, it)?;
     document(w, cx, it)?;
     let mut fields = s.fields.iter().filter_map(|f| {
         match f.inner {
@@ -3231,9 +4465,10 @@ fn item_union(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
             let id = format!("{}.{}", ItemType::StructField, name);
             write!(w, "<span id=\"{id}\" class=\"{shortty} small-section-header\">\
                            <a href=\"#{id}\" class=\"anchor field\"></a>\
-                           <span class='invisible'><code>{name}: {ty}</code></span>\
+                           <span class='invisible'><code>{vis}{name}: {ty}</code></span>\
                        </span>",
                    id = id,
+                   vis = RestrictedVisSpace(&field.visibility),
                    name = name,
                    shortty = ItemType::StructField,
                    ty = ty)?;
@@ -3285,6 +4520,7 @@ fn item_enum(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                                               s.struct_type,
                                               &s.fields,
                                               "    ",
+                                              false,
                                               false)?;
                             }
                         }
@@ -3302,6 +4538,7 @@ fn item_enum(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
         write!(w, "</pre>")
     })?;
 
+    document_drop_impl(w, it)?;
     document(w, cx, it)?;
     if !e.variants.is_empty() {
         write!(w, "<h2 id='variants' class='variants small-section-header'>
@@ -3401,6 +4638,8 @@ fn render_attribute(attr: &ast::MetaItem) -> Option<String> {
     }
 }
 
+const DEFAULT_BADGE_ATTRIBUTES: &'static [&'static str] = &["track_caller"];
+
 const ATTRIBUTE_WHITELIST: &'static [&'static str] = &[
     "export_name",
     "lang",
@@ -3408,6 +4647,7 @@ const ATTRIBUTE_WHITELIST: &'static [&'static str] = &[
     "must_use",
     "no_mangle",
     "repr",
+    "thread_local",
     "unsafe_destructor_blind_to_params",
     "non_exhaustive"
 ];
@@ -3430,12 +4670,29 @@ fn render_attributes(w: &mut fmt::Formatter, it: &clean::Item) -> fmt::Result {
     Ok(())
 }
 
+/// Returns the names of the fields which were stripped because they're private, along with
+/// whether some other fields were stripped, e.g. because they're hidden.
+fn private_field_names(fields: &[clean::Item]) -> (Vec<&str>, bool) {
+    let mut names = Vec::new();
+    let mut others = false;
+    for field in fields {
+        if let clean::StrippedItem(box clean::StructFieldItem(..)) = field.inner {
+            match (&field.visibility, &field.name) {
+                (&Some(clean::Public), _) | (_, &None) => others = true,
+                (_, &Some(ref name)) => names.push(&name[..]),
+            }
+        }
+    }
+    (names, others)
+}
+
 fn render_struct(w: &mut fmt::Formatter, it: &clean::Item,
                  g: Option<&clean::Generics>,
                  ty: doctree::StructType,
                  fields: &[clean::Item],
                  tab: &str,
-                 structhead: bool) -> fmt::Result {
+                 structhead: bool,
+                 show_private_fields: bool) -> fmt::Result {
     write!(w, "{}{}{}",
            VisSpace(&it.visibility),
            if structhead {"struct "} else {""},
@@ -3461,11 +4718,21 @@ fn render_struct(w: &mut fmt::Formatter, it: &clean::Item,
                 }
             }
 
+            let (private, others) = if show_private_fields {
+                private_field_names(fields)
+            } else {
+                (Vec::new(), true)
+            };
             if has_visible_fields {
-                if it.has_stripped_fields().unwrap() {
+                if !private.is_empty() {
+                    write!(w, "\n{}    // private fields: {}", tab, private.join(", "))?;
+                }
+                if it.has_stripped_fields().unwrap() && others {
                     write!(w, "\n{}    // some fields omitted", tab)?;
                 }
                 write!(w, "\n{}", tab)?;
+            } else if !private.is_empty() && !others {
+                write!(w, " /* private fields: {} */ ", private.join(", "))?;
             } else if it.has_stripped_fields().unwrap() {
                 // If there are no visible fields we can just display
                 // `{ /* fields omitted */ }` to save space.
@@ -3510,7 +4777,8 @@ fn render_union(w: &mut fmt::Formatter, it: &clean::Item,
                 g: Option<&clean::Generics>,
                 fields: &[clean::Item],
                 tab: &str,
-                structhead: bool) -> fmt::Result {
+                structhead: bool,
+                show_private_fields: bool) -> fmt::Result {
     write!(w, "{}{}{}",
            VisSpace(&it.visibility),
            if structhead {"union "} else {""},
@@ -3531,7 +4799,15 @@ fn render_union(w: &mut fmt::Formatter, it: &clean::Item,
         }
     }
 
-    if it.has_stripped_fields().unwrap() {
+    let (private, others) = if show_private_fields {
+        private_field_names(fields)
+    } else {
+        (Vec::new(), true)
+    };
+    if !private.is_empty() {
+        write!(w, "    // private fields: {}\n{}", private.join(", "), tab)?;
+    }
+    if it.has_stripped_fields().unwrap() && others {
         write!(w, "    // some fields omitted\n{}", tab)?;
     }
     write!(w, "}}")?;
@@ -3570,14 +4846,62 @@ fn render_assoc_items(w: &mut fmt::Formatter,
                       it: DefId,
                       what: AssocItemRender) -> fmt::Result {
     let c = cache();
-    let v = match c.impls.get(&it) {
-        Some(v) => v,
-        None => return Ok(()),
+    // The page of a type alias also shows the impls inlined for it.
+    let alias_impls = match c.alias_targets.get(&containing_item.def_id) {
+        Some(&target) if target == it => c.alias_impls.get(&it),
+        _ => None,
     };
-    let (non_trait, traits): (Vec<_>, _) = v.iter().partition(|i| {
+    let v = c.impls.get(&it).into_iter().chain(alias_impls).flat_map(|v| v).collect::<Vec<_>>();
+    if v.is_empty() {
+        return Ok(());
+    }
+    let (non_trait, traits): (Vec<_>, Vec<_>) = v.iter().cloned().partition(|i| {
         i.inner_impl().trait_.is_none()
     });
-    if !non_trait.is_empty() {
+    let (synthetic, concrete): (Vec<&Impl>, Vec<&Impl>) = traits
+        .iter()
+        .cloned()
+        .partition(|t| t.inner_impl().synthetic);
+    let (blanket_impl, concrete): (Vec<&Impl>, Vec<&Impl>) = concrete
+        .into_iter()
+        .partition(|t| t.inner_impl().blanket_impl.is_some());
+    let order = match what {
+        AssocItemRender::All => cx.shared.impl_order,
+        AssocItemRender::DerefFor { .. } => ImplOrder::InherentFirst,
+    };
+
+    struct RendererStruct<'a, 'b, 'c>(&'a Context, &'b [&'b Impl], &'c clean::Item);
+
+    impl<'a, 'b, 'c> fmt::Display for RendererStruct<'a, 'b, 'c> {
+        fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+            render_impls(self.0, fmt, self.1, self.2)
+        }
+    }
+
+    fn impls_section(w: &mut fmt::Formatter, cx: &Context, impls: &[&Impl],
+                     containing_item: &clean::Item, title: &str) -> fmt::Result {
+        let impls = RendererStruct(cx, impls, containing_item).to_string();
+        if !impls.is_empty() {
+            write!(w, "\
+                <h2 id='implementations' class='small-section-header'>\
+                  {}<a href='#implementations' class='anchor'></a>\
+                </h2>\
+                <div id='implementations-list'>{}</div>", title, impls)?;
+        }
+        Ok(())
+    }
+
+    if order == ImplOrder::TraitFirst {
+        impls_section(w, cx, &concrete, containing_item, "Trait Implementations")?;
+    }
+    if order == ImplOrder::Interleaved {
+        // The inherent impls are listed with the trait impls, in the order they were found.
+        let impls = v.iter().cloned().filter(|i| {
+            let i = i.inner_impl();
+            !i.synthetic && i.blanket_impl.is_none()
+        }).collect::<Vec<_>>();
+        impls_section(w, cx, &impls, containing_item, "Implementations")?;
+    } else if !non_trait.is_empty() {
         let render_mode = match what {
             AssocItemRender::All => {
                 write!(w, "\
@@ -3605,64 +4929,85 @@ fn render_assoc_items(w: &mut fmt::Formatter,
     if let AssocItemRender::DerefFor { .. } = what {
         return Ok(());
     }
-    if !traits.is_empty() {
-        let deref_impl = traits.iter().find(|t| {
-            t.inner_impl().trait_.def_id() == c.deref_trait_did
-        });
-        if let Some(impl_) = deref_impl {
-            let has_deref_mut = traits.iter().find(|t| {
-                t.inner_impl().trait_.def_id() == c.deref_mut_trait_did
-            }).is_some();
-            render_deref_methods(w, cx, impl_, containing_item, has_deref_mut)?;
-        }
-
-        let (synthetic, concrete): (Vec<&&Impl>, Vec<&&Impl>) = traits
-            .iter()
-            .partition(|t| t.inner_impl().synthetic);
-        let (blanket_impl, concrete) = concrete
-            .into_iter()
-            .partition(|t| t.inner_impl().blanket_impl.is_some());
-
-        struct RendererStruct<'a, 'b, 'c>(&'a Context, Vec<&'b &'b Impl>, &'c clean::Item);
+    let deref_impl = traits.iter().find(|t| {
+        t.inner_impl().trait_.def_id() == c.deref_trait_did
+    });
+    if let Some(impl_) = deref_impl {
+        let has_deref_mut = traits.iter().find(|t| {
+            t.inner_impl().trait_.def_id() == c.deref_mut_trait_did
+        }).is_some();
+        render_deref_methods(w, cx, impl_, containing_item, has_deref_mut)?;
+    }
 
-        impl<'a, 'b, 'c> fmt::Display for RendererStruct<'a, 'b, 'c> {
-            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-                render_impls(self.0, fmt, &self.1, self.2)
-            }
-        }
+    if order == ImplOrder::InherentFirst {
+        impls_section(w, cx, &concrete, containing_item, "Trait Implementations")?;
+    }
 
-        let impls = RendererStruct(cx, concrete, containing_item).to_string();
-        if !impls.is_empty() {
-            write!(w, "\
-                <h2 id='implementations' class='small-section-header'>\
-                  Trait Implementations<a href='#implementations' class='anchor'></a>\
-                </h2>\
-                <div id='implementations-list'>{}</div>", impls)?;
+    if !synthetic.is_empty() {
+        write!(w, "\
+            <h2 id='synthetic-implementations' class='small-section-header'>\
+              Auto Trait Implementations\
+              <a href='#synthetic-implementations' class='anchor'></a>\
+            </h2>\
+            <div id='synthetic-implementations-list'>\
+        ")?;
+        // With `--trim-auto-traits`, the impls are folded under the list of the traits when the
+        // type implements them all without conditions.
+        let trimmed = cx.shared.trim_auto_traits && synthetic.iter().all(|i| {
+            let i = i.inner_impl();
+            i.polarity != Some(clean::ImplPolarity::Negative) &&
+            i.generics.where_predicates.is_empty()
+        });
+        if trimmed {
+            let traits = synthetic.iter()
+                                  .filter_map(|i| i.inner_impl().trait_.as_ref())
+                                  .map(|t| t.to_string())
+                                  .collect::<Vec<_>>();
+            write!(w, "<details class='auto-traits'><summary>Implements <code>{}</code>\
+                       </summary>",
+                   traits.join(" + "))?;
+        }
+        render_impls(cx, w, &synthetic, containing_item)?;
+        if trimmed {
+            write!(w, "</details>")?;
         }
+        write!(w, "</div>")?;
+    }
 
-        if !synthetic.is_empty() {
-            write!(w, "\
-                <h2 id='synthetic-implementations' class='small-section-header'>\
-                  Auto Trait Implementations\
-                  <a href='#synthetic-implementations' class='anchor'></a>\
-                </h2>\
-                <div id='synthetic-implementations-list'>\
-            ")?;
-            render_impls(cx, w, &synthetic, containing_item)?;
-            write!(w, "</div>")?;
+    if !blanket_impl.is_empty() {
+        write!(w, "\
+            <h2 id='blanket-implementations' class='small-section-header'>\
+              Blanket Implementations\
+              <a href='#blanket-implementations' class='anchor'></a>\
+            </h2>\
+            <div id='blanket-implementations-list'>\
+        ")?;
+        // The blanket impls are grouped by the crate of their trait, which provides them.
+        let mut by_crate: BTreeMap<String, Vec<&Impl>> = BTreeMap::new();
+        for i in blanket_impl {
+            let did = i.trait_did().unwrap();
+            let name = if did.krate == LOCAL_CRATE {
+                cx.shared.layout.krate.clone()
+            } else {
+                // The path of the trait starts with the name of its crate, should the crate be
+                // missing from the extern locations.
+                c.extern_locations.get(&did.krate).map(|l| l.0.clone())
+                 .or_else(|| {
+                     c.external_paths.get(&did).or_else(|| c.paths.get(&did))
+                      .and_then(|&(ref fqp, _)| fqp.first().cloned())
+                 })
+                 .unwrap_or_default()
+            };
+            by_crate.entry(name).or_insert_with(Vec::new).push(i);
         }
-
-        if !blanket_impl.is_empty() {
-            write!(w, "\
-                <h2 id='blanket-implementations' class='small-section-header'>\
-                  Blanket Implementations\
-                  <a href='#blanket-implementations' class='anchor'></a>\
-                </h2>\
-                <div id='blanket-implementations-list'>\
-            ")?;
-            render_impls(cx, w, &blanket_impl, containing_item)?;
-            write!(w, "</div>")?;
+        for (name, impls) in &by_crate {
+            let id = cx.derive_id(format!("blanket-implementations-{}", name));
+            write!(w, "<h3 id='{id}' class='blanket-crate'>From <code>{name}</code>\
+                       <a href='#{id}' class='anchor'></a></h3>",
+                   id = id, name = name)?;
+            render_impls(cx, w, impls, containing_item)?;
         }
+        write!(w, "</div>")?;
     }
     Ok(())
 }
@@ -3756,9 +5101,9 @@ fn spotlight_decl(decl: &clean::FnDecl) -> Result<String, fmt::Error> {
                     for it in &impl_.items {
                         if let clean::TypedefItem(ref tydef, _) = it.inner {
                             out.push_str("<span class=\"where fmt-newline\">    ");
-                            assoc_type(&mut out, it, &[],
+                            assoc_type(&mut out, it, &tydef.generics, &[],
                                        Some(&tydef.type_),
-                                       AssocItemLink::GotoSource(t_did, &FxHashSet()))?;
+                                       AssocItemLink::GotoSource(t_did, &FxHashSet()), 0)?;
                             out.push_str(";</span>");
                         }
                     }
@@ -3781,6 +5126,7 @@ fn spotlight_decl(decl: &clean::FnDecl) -> Result<String, fmt::Error> {
 fn render_impl(w: &mut fmt::Formatter, cx: &Context, i: &Impl, link: AssocItemLink,
                render_mode: RenderMode, outer_version: Option<&str>,
                show_def_docs: bool) -> fmt::Result {
+    let mut impl_id = None;
     if render_mode == RenderMode::Normal {
         let id = cx.derive_id(match i.inner_impl().trait_ {
             Some(ref t) => format!("impl-{}", small_url_encode(&format!("{:#}", t))),
@@ -3801,17 +5147,47 @@ fn render_impl(w: &mut fmt::Formatter, cx: &Context, i: &Impl, link: AssocItemLi
             render_stability_since_raw(w, since, outer_version)?;
         }
         write!(w, "</span></td></tr></tbody></table></h3>")?;
+        // The blanket impls only have a stability with `--document-unstable-impls`, when their
+        // trait is unstable.
+        if i.inner_impl().blanket_impl.is_some() {
+            document_stability(w, cx, &i.impl_item)?;
+            render_blanket_bounds(w, i.inner_impl())?;
+        }
         if let Some(ref dox) = cx.shared.maybe_collapsed_doc_value(&i.impl_item) {
             let mut ids = cx.id_map.borrow_mut();
             write!(w, "<div class='docblock'>{}</div>",
                    Markdown(&*dox, &i.impl_item.links(), RefCell::new(&mut ids), cx.codes))?;
         }
+        // The derived id of the impl depends on the impls rendered before it, so the aliases of
+        // its items are qualified by its header instead.
+        let impl_ = i.inner_impl();
+        impl_id = Some(match impl_.trait_ {
+            Some(ref t) => format!("impl-{}-for-{}",
+                                   small_url_encode(&format!("{:#}", t)),
+                                   small_url_encode(&format!("{:#}", impl_.for_))),
+            None => format!("impl-{}", small_url_encode(&format!("{:#}", impl_.for_))),
+        });
+    }
+
+    // The anchor of an associated item only depends on its name, unless another item of the
+    // page already took it. In that case, the item also gets an alias qualified by its impl, so
+    // that links to it don't depend on the order in which the impls are rendered.
+    fn item_anchor(w: &mut fmt::Formatter, cx: &Context, impl_id: Option<&str>,
+                   anchor: String) -> Result<String, fmt::Error> {
+        let id = cx.derive_id(anchor.clone());
+        if let Some(impl_id) = impl_id {
+            if id != anchor {
+                write!(w, "<a name='{}'></a>", cx.derive_id(format!("{}.{}", impl_id, anchor)))?;
+            }
+        }
+        Ok(id)
     }
 
     fn doc_impl_item(w: &mut fmt::Formatter, cx: &Context, item: &clean::Item,
                      link: AssocItemLink, render_mode: RenderMode,
                      is_default_item: bool, outer_version: Option<&str>,
-                     trait_: Option<&clean::Trait>, show_def_docs: bool) -> fmt::Result {
+                     trait_: Option<&clean::Trait>, show_def_docs: bool,
+                     impl_id: Option<&str>, impl_: &clean::Impl) -> fmt::Result {
         let item_type = item.type_();
         let name = item.name.as_ref().unwrap();
 
@@ -3820,17 +5196,22 @@ fn render_impl(w: &mut fmt::Formatter, cx: &Context, i: &Impl, link: AssocItemLi
             RenderMode::ForDeref { mut_: deref_mut_ } => should_render_item(&item, deref_mut_),
         };
 
+        // The item of the trait this item implements, which may have been stripped.
+        let trait_item = trait_.and_then(|t| t.items.iter().find(|i| i.name == item.name));
+
         match item.inner {
             clean::MethodItem(clean::Method { ref decl, .. }) |
             clean::TyMethodItem(clean::TyMethod{ ref decl, .. }) => {
                 // Only render when the method is not static or we allow static methods
                 if render_method_item {
-                    let id = cx.derive_id(format!("{}.{}", item_type, name));
+                    let deprecated = is_deprecated(item) || trait_item.map_or(false, is_deprecated);
+                    let id = item_anchor(w, cx, impl_id, format!("{}.{}", item_type, name))?;
                     let ns_id = cx.derive_id(format!("{}.{}", name, item_type.name_space()));
                     write!(w, "<h4 id='{}' class=\"{}\">", id, item_type)?;
                     write!(w, "{}", spotlight_decl(decl)?)?;
                     write!(w, "<span id='{}' class='invisible'>", ns_id)?;
-                    write!(w, "<table class='table-display'><tbody><tr><td><code>")?;
+                    write!(w, "<table class='table-display'><tbody><tr><td><code{}>",
+                           if deprecated { " class='deprecated'" } else { "" })?;
                     render_assoc_item(w, item, link.anchor(&id), ItemType::Impl)?;
                     write!(w, "</code>")?;
                     if let Some(l) = (Item { cx, item }).src_href() {
@@ -3844,30 +5225,36 @@ fn render_impl(w: &mut fmt::Formatter, cx: &Context, i: &Impl, link: AssocItemLi
                         render_stability_since_raw(w, item.stable_since(), outer_version)?;
                     }
                     write!(w, "</td></tr></tbody></table></span></h4>")?;
+                    render_async_desugaring(w, cx, item, link.anchor(&id))?;
+                    if decl.self_type().is_none() {
+                        render_call_form(w, impl_, name)?;
+                    }
                 }
             }
             clean::TypedefItem(ref tydef, _) => {
-                let id = cx.derive_id(format!("{}.{}", ItemType::AssociatedType, name));
+                let id = item_anchor(w, cx, impl_id,
+                                     format!("{}.{}", ItemType::AssociatedType, name))?;
                 let ns_id = cx.derive_id(format!("{}.{}", name, item_type.name_space()));
                 write!(w, "<h4 id='{}' class=\"{}\">", id, item_type)?;
                 write!(w, "<span id='{}' class='invisible'><code>", ns_id)?;
-                assoc_type(w, item, &Vec::new(), Some(&tydef.type_), link.anchor(&id))?;
+                assoc_type(w, item, &tydef.generics, &Vec::new(), Some(&tydef.type_),
+                           link.anchor(&id), 0)?;
                 write!(w, "</code></span></h4>\n")?;
             }
-            clean::AssociatedConstItem(ref ty, ref default) => {
-                let id = cx.derive_id(format!("{}.{}", item_type, name));
+            clean::AssociatedConstItem(ref ty, _) => {
+                let id = item_anchor(w, cx, impl_id, format!("{}.{}", item_type, name))?;
                 let ns_id = cx.derive_id(format!("{}.{}", name, item_type.name_space()));
                 write!(w, "<h4 id='{}' class=\"{}\">", id, item_type)?;
                 write!(w, "<span id='{}' class='invisible'><code>", ns_id)?;
-                assoc_const(w, item, ty, default.as_ref(), link.anchor(&id))?;
+                assoc_const(w, item, ty, None, link.anchor(&id))?;
                 write!(w, "</code></span></h4>\n")?;
             }
-            clean::AssociatedTypeItem(ref bounds, ref default) => {
-                let id = cx.derive_id(format!("{}.{}", item_type, name));
+            clean::AssociatedTypeItem(ref generics, ref bounds, ref default) => {
+                let id = item_anchor(w, cx, impl_id, format!("{}.{}", item_type, name))?;
                 let ns_id = cx.derive_id(format!("{}.{}", name, item_type.name_space()));
                 write!(w, "<h4 id='{}' class=\"{}\">", id, item_type)?;
                 write!(w, "<span id='{}' class='invisible'><code>", ns_id)?;
-                assoc_type(w, item, bounds, default.as_ref(), link.anchor(&id))?;
+                assoc_type(w, item, generics, bounds, default.as_ref(), link.anchor(&id), 0)?;
                 write!(w, "</code></span></h4>\n")?;
             }
             clean::StrippedItem(..) => return Ok(()),
@@ -3878,13 +5265,22 @@ fn render_impl(w: &mut fmt::Formatter, cx: &Context, i: &Impl, link: AssocItemLi
             let prefix = render_assoc_const_value(item);
 
             if !is_default_item {
-                if let Some(t) = trait_ {
+                if trait_.is_some() {
                     // The trait item may have been stripped so we might not
                     // find any documentation or stability for it.
-                    if let Some(it) = t.items.iter().find(|i| i.name == item.name) {
+                    if let Some(it) = trait_item {
                         // We need the stability of the item from the trait
                         // because impls can't have a stability.
+                        document_sized_self(w, it)?;
                         document_stability(w, cx, it)?;
+                        // The implementation can still be deprecated on its own.
+                        match item.deprecation {
+                            Some(ref depr) if !is_deprecated(it) => {
+                                write!(w, "<div class='stability'>{}</div>",
+                                       deprecation_note(depr, cx, true))?;
+                            }
+                            _ => {}
+                        }
                         if item.doc_value().is_some() {
                             document_full(w, item, cx, &prefix)?;
                         } else if show_def_docs {
@@ -3900,6 +5296,7 @@ fn render_impl(w: &mut fmt::Formatter, cx: &Context, i: &Impl, link: AssocItemLi
                     }
                 }
             } else {
+                document_sized_self(w, item)?;
                 document_stability(w, cx, item)?;
                 if show_def_docs {
                     document_short(w, cx, item, link, &prefix)?;
@@ -3916,38 +5313,71 @@ fn render_impl(w: &mut fmt::Formatter, cx: &Context, i: &Impl, link: AssocItemLi
         write!(w, "<span class='docblock autohide'>")?;
     }
 
-    write!(w, "<div class='impl-items'>")?;
-    for trait_item in &i.inner_impl().items {
-        doc_impl_item(w, cx, trait_item, link, render_mode,
-                      false, outer_version, trait_, show_def_docs)?;
-    }
-
-    fn render_default_items(w: &mut fmt::Formatter,
-                            cx: &Context,
-                            t: &clean::Trait,
-                            i: &clean::Impl,
-                            render_mode: RenderMode,
-                            outer_version: Option<&str>,
-                            show_def_docs: bool) -> fmt::Result {
+    // If we've implemented a trait, then also emit documentation for all
+    // default items which weren't overridden in the implementation block.
+    fn default_items(t: &clean::Trait, i: &clean::Impl) -> Vec<clean::Item> {
+        let mut items = Vec::new();
         for trait_item in &t.items {
             let n = trait_item.name.clone();
             if i.items.iter().find(|m| m.name == n).is_some() {
                 continue;
             }
-            let did = i.trait_.as_ref().unwrap().def_id().unwrap();
-            let assoc_link = AssocItemLink::GotoSource(did, &i.provided_trait_methods);
+            // The signatures of the provided methods are written in terms of `Self`, which is
+            // the implementing type here.
+            let mut trait_item = trait_item.clone();
+            match trait_item.inner {
+                clean::MethodItem(clean::Method { ref mut decl, .. }) |
+                clean::TyMethodItem(clean::TyMethod { ref mut decl, .. }) => {
+                    *decl = decl.replace_self(&i.for_);
+                }
+                _ => {}
+            }
+            items.push(trait_item);
+        }
+        items
+    }
 
-            doc_impl_item(w, cx, trait_item, assoc_link, render_mode, true,
-                          outer_version, None, show_def_docs)?;
+    let defaults = trait_.map_or(Vec::new(), |t| default_items(t, i.inner_impl()));
+    // The methods the impl wrote itself are set apart from the ones it got from the trait.
+    let split_methods = render_mode == RenderMode::Normal &&
+                        defaults.iter().any(|item| item.is_method() || item.is_ty_method());
+
+    // The groups only speak of methods when there isn't any associated type or const in them.
+    fn group_kind<'a, I: IntoIterator<Item = &'a clean::Item>>(items: I) -> &'static str {
+        if items.into_iter().all(|item| item.is_method() || item.is_ty_method()) {
+            "Methods"
+        } else {
+            "Items"
         }
-        Ok(())
     }
 
-    // If we've implemented a trait, then also emit documentation for all
-    // default items which weren't overridden in the implementation block.
-    if let Some(t) = trait_ {
-        render_default_items(w, cx, t, &i.inner_impl(),
-                             render_mode, outer_version, show_def_docs)?;
+    write!(w, "<div class='impl-items'>")?;
+    if split_methods && !i.inner_impl().items.is_empty() {
+        write!(w, "<h5 class='impl-items-group'>Implemented {}</h5>",
+               group_kind(&i.inner_impl().items))?;
+    }
+    let groups = cfg_groups(&i.impl_item, &i.inner_impl().items);
+    for &(cfg, ref items) in &groups {
+        if render_mode == RenderMode::Normal && groups.len() > 1 {
+            if let Some(cfg) = cfg.and_then(|cfg| cfg.without_hidden(&cx.shared.hidden_cfgs)) {
+                write!(w, "<h5 class='impl-items-group cfg-group'>{}</h5>",
+                       cfg.render_short_html())?;
+            }
+        }
+        for trait_item in items {
+            doc_impl_item(w, cx, trait_item, link, render_mode, false, outer_version, trait_,
+                          show_def_docs, impl_id.as_ref().map(|s| &**s), i.inner_impl())?;
+        }
+    }
+
+    if split_methods {
+        write!(w, "<h5 class='impl-items-group'>Provided {}</h5>", group_kind(&defaults))?;
+    }
+    for trait_item in &defaults {
+        let did = i.trait_did().unwrap();
+        let assoc_link = AssocItemLink::GotoSource(did, &i.inner_impl().provided_trait_methods);
+        doc_impl_item(w, cx, trait_item, assoc_link, render_mode, true, outer_version, None,
+                      show_def_docs, impl_id.as_ref().map(|s| &**s), i.inner_impl())?;
     }
     write!(w, "</div>")?;
 
@@ -3958,6 +5388,84 @@ fn render_impl(w: &mut fmt::Formatter, cx: &Context, i: &Impl, link: AssocItemLi
     Ok(())
 }
 
+/// Groups the `items` of `impl_item` by their cfg, keeping the order in which each cfg first
+/// appears. The items which only have the cfg of the impl itself come first.
+fn cfg_groups<'a>(impl_item: &clean::Item,
+                  items: &'a [clean::Item]) -> Vec<(Option<&'a Arc<Cfg>>, Vec<&'a clean::Item>)> {
+    let mut groups: Vec<(Option<&Arc<Cfg>>, Vec<&clean::Item>)> = Vec::new();
+    for item in items {
+        let cfg = item.attrs.cfg.as_ref();
+        match groups.iter().position(|&(group_cfg, _)| group_cfg == cfg) {
+            Some(pos) => groups[pos].1.push(item),
+            None => groups.push((cfg, vec![item])),
+        }
+    }
+    groups.sort_by_key(|&(cfg, _)| cfg != impl_item.attrs.cfg.as_ref());
+    groups
+}
+
+/// Writes the bounds which the type satisfies to get the blanket impl `impl_`, like
+/// `MyStruct: Clone` for `impl<T: Clone> Foo for T`.
+fn render_blanket_bounds(w: &mut fmt::Formatter, impl_: &clean::Impl) -> fmt::Result {
+    // Each trait predicate carries a single bound, so they're merged per type to read
+    // `MyStruct: Copy + Default`.
+    let mut bounds: Vec<(&clean::Type, Vec<clean::GenericBound>)> = Vec::new();
+    for pred in &impl_.blanket_bounds {
+        if let clean::WherePredicate::BoundPredicate { ref ty, bounds: ref b } = *pred {
+            match bounds.iter().position(|&(t, _)| t == ty) {
+                Some(pos) => bounds[pos].1.extend(b.iter().cloned()),
+                None => bounds.push((ty, b.clone())),
+            }
+        }
+    }
+    if bounds.is_empty() {
+        return Ok(());
+    }
+    write!(w, "<div class='blanket-bounds'>Applies since ")?;
+    for (i, (ty, bounds)) in bounds.into_iter().enumerate() {
+        if i > 0 {
+            write!(w, ", ")?;
+        }
+        write!(w, "<code>{}: {}</code>", ty, GenericBounds(&bounds))?;
+    }
+    write!(w, "</div>")
+}
+
+/// Writes the path through which the associated function `name` of `impl_`, which takes no
+/// `self`, is called: `Type::name` for an inherent impl and `<Type as Trait>::name` for the impl
+/// of a trait. The arguments are left out, as the signature above already lists them.
+fn render_call_form(w: &mut fmt::Formatter, impl_: &clean::Impl, name: &str) -> fmt::Result {
+    write!(w, "<div class='call-form'>Called as <code>")?;
+    match (&impl_.trait_, &impl_.for_) {
+        (&Some(ref trait_), for_) => write!(w, "&lt;{} as {}&gt;", for_, trait_)?,
+        (&None, &clean::ResolvedPath { ref path, .. }) => {
+            write!(w, "{}", Escape(path.last_name()))?
+        }
+        (&None, for_) => write!(w, "&lt;{}&gt;", for_)?,
+    }
+    write!(w, "::{}</code></div>", name)
+}
+
+fn item_trait_alias(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
+                    t: &clean::TraitAlias) -> fmt::Result {
+    write!(w, "<pre class='rust trait-alias'>")?;
+    render_attributes(w, it)?;
+    write!(w, "trait {}{} = ", it.name.as_ref().unwrap(), t.generics)?;
+    for (i, b) in t.bounds.iter().enumerate() {
+        if i > 0 {
+            write!(w, " + ")?;
+        }
+        write!(w, "{}", *b)?;
+    }
+    write!(w, "{};</pre>", WhereClause { gens: &t.generics, indent: 0, end_newline: false })?;
+
+    document(w, cx, it)?;
+
+    // Render any items associated directly to this alias, as otherwise they
+    // won't be visible anywhere in the docs.
+    render_assoc_items(w, cx, it, it.def_id, AssocItemRender::All)
+}
+
 fn item_existential(
     w: &mut fmt::Formatter,
     cx: &Context,
@@ -3994,10 +5502,16 @@ fn item_typedef(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
     document(w, cx, it)?;
 
     // Render any items associated directly to this alias, as otherwise they
-    // won't be visible anywhere in the docs. It would be nice to also show
-    // associated items from the aliased type (see discussion in #32077), but
-    // we need #14072 to make sense of the generics.
-    render_assoc_items(w, cx, it, it.def_id, AssocItemRender::All)
+    // won't be visible anywhere in the docs. The associated items from the
+    // aliased type (see discussion in #32077) are only shown on request, as
+    // they're written with the generics of that type rather than the alias'.
+    let c = cache();
+    match c.alias_targets.get(&it.def_id) {
+        Some(&target) if !c.impls.contains_key(&it.def_id) => {
+            render_assoc_items(w, cx, it, target, AssocItemRender::All)
+        }
+        _ => render_assoc_items(w, cx, it, it.def_id, AssocItemRender::All),
+    }
 }
 
 fn item_foreign_type(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item) -> fmt::Result {
@@ -4052,18 +5566,27 @@ impl<'a> fmt::Display for Sidebar<'a> {
                        version,
                        it.name.as_ref().unwrap())?;
             }
+            if cx.shared.crate_features.is_some() {
+                write!(fmt,
+                       "<a id='crate-features' href='features.html'><p>See {}'s features</p></a>",
+                       it.name.as_ref().unwrap())?;
+            }
         }
 
-        write!(fmt, "<div class=\"sidebar-elems\">")?;
+        if cx.shared.compact_sidebar {
+            write!(fmt, "<div class=\"sidebar-elems compact\">")?;
+        } else {
+            write!(fmt, "<div class=\"sidebar-elems\">")?;
+        }
         match it.inner {
-            clean::StructItem(ref s) => sidebar_struct(fmt, it, s)?,
-            clean::TraitItem(ref t) => sidebar_trait(fmt, it, t)?,
-            clean::PrimitiveItem(ref p) => sidebar_primitive(fmt, it, p)?,
-            clean::UnionItem(ref u) => sidebar_union(fmt, it, u)?,
-            clean::EnumItem(ref e) => sidebar_enum(fmt, it, e)?,
-            clean::TypedefItem(ref t, _) => sidebar_typedef(fmt, it, t)?,
+            clean::StructItem(ref s) => sidebar_struct(fmt, cx, it, s)?,
+            clean::TraitItem(ref t) => sidebar_trait(fmt, cx, it, t)?,
+            clean::PrimitiveItem(ref p) => sidebar_primitive(fmt, cx, it, p)?,
+            clean::UnionItem(ref u) => sidebar_union(fmt, cx, it, u)?,
+            clean::EnumItem(ref e) => sidebar_enum(fmt, cx, it, e)?,
+            clean::TypedefItem(ref t, _) => sidebar_typedef(fmt, cx, it, t)?,
             clean::ModuleItem(ref m) => sidebar_module(fmt, it, &m.items)?,
-            clean::ForeignTypeItem => sidebar_foreign_type(fmt, it)?,
+            clean::ForeignTypeItem => sidebar_foreign_type(fmt, cx, it)?,
             _ => (),
         }
 
@@ -4081,7 +5604,7 @@ impl<'a> fmt::Display for Sidebar<'a> {
                 write!(fmt, "::<wbr>")?;
             }
             write!(fmt, "<a href='{}index.html'>{}</a>",
-                   &cx.root_path()[..(cx.current.len() - i - 1) * 3],
+                   "../".repeat(cx.current.len() - i - 1),
                    *name)?;
         }
         write!(fmt, "</p>")?;
@@ -4143,7 +5666,7 @@ fn small_url_encode(s: &str) -> String {
      .replace("\"", "%22")
 }
 
-fn sidebar_assoc_items(it: &clean::Item) -> String {
+fn sidebar_assoc_items(cx: &Context, it: &clean::Item) -> String {
     let mut out = String::new();
     let c = cache();
     if let Some(v) = c.impls.get(&it.def_id) {
@@ -4152,8 +5675,14 @@ fn sidebar_assoc_items(it: &clean::Item) -> String {
                    .flat_map(|i| get_methods(i.inner_impl(), false))
                    .collect::<String>();
         if !ret.is_empty() {
-            out.push_str(&format!("<a class=\"sidebar-title\" href=\"#methods\">Methods\
-                                   </a><div class=\"sidebar-links\">{}</div>", ret));
+            // The inherent impls are in the "Implementations" section when they're interleaved.
+            let anchor = if cx.shared.impl_order == ImplOrder::Interleaved {
+                "implementations"
+            } else {
+                "methods"
+            };
+            out.push_str(&format!("<a class=\"sidebar-title\" href=\"#{}\">Methods\
+                                   </a><div class=\"sidebar-links\">{}</div>", anchor, ret));
         }
 
         if v.iter().any(|i| i.inner_impl().trait_.is_some()) {
@@ -4243,7 +5772,7 @@ fn sidebar_assoc_items(it: &clean::Item) -> String {
     out
 }
 
-fn sidebar_struct(fmt: &mut fmt::Formatter, it: &clean::Item,
+fn sidebar_struct(fmt: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                   s: &clean::Struct) -> fmt::Result {
     let mut sidebar = String::new();
     let fields = get_struct_fields_name(&s.fields);
@@ -4255,7 +5784,7 @@ fn sidebar_struct(fmt: &mut fmt::Formatter, it: &clean::Item,
         }
     }
 
-    sidebar.push_str(&sidebar_assoc_items(it));
+    sidebar.push_str(&sidebar_assoc_items(cx, it));
 
     if !sidebar.is_empty() {
         write!(fmt, "<div class=\"block items\">{}</div>", sidebar)?;
@@ -4280,7 +5809,7 @@ fn is_negative_impl(i: &clean::Impl) -> bool {
     i.polarity == Some(clean::ImplPolarity::Negative)
 }
 
-fn sidebar_trait(fmt: &mut fmt::Formatter, it: &clean::Item,
+fn sidebar_trait(fmt: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                  t: &clean::Trait) -> fmt::Result {
     let mut sidebar = String::new();
 
@@ -4384,14 +5913,14 @@ fn sidebar_trait(fmt: &mut fmt::Formatter, it: &clean::Item,
                           href=\"#synthetic-implementors\">Auto Implementors</a>");
     }
 
-    sidebar.push_str(&sidebar_assoc_items(it));
+    sidebar.push_str(&sidebar_assoc_items(cx, it));
 
     write!(fmt, "<div class=\"block items\">{}</div>", sidebar)
 }
 
-fn sidebar_primitive(fmt: &mut fmt::Formatter, it: &clean::Item,
+fn sidebar_primitive(fmt: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                      _p: &clean::PrimitiveType) -> fmt::Result {
-    let sidebar = sidebar_assoc_items(it);
+    let sidebar = sidebar_assoc_items(cx, it);
 
     if !sidebar.is_empty() {
         write!(fmt, "<div class=\"block items\">{}</div>", sidebar)?;
@@ -4399,9 +5928,9 @@ fn sidebar_primitive(fmt: &mut fmt::Formatter, it: &clean::Item,
     Ok(())
 }
 
-fn sidebar_typedef(fmt: &mut fmt::Formatter, it: &clean::Item,
+fn sidebar_typedef(fmt: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                    _t: &clean::Typedef) -> fmt::Result {
-    let sidebar = sidebar_assoc_items(it);
+    let sidebar = sidebar_assoc_items(cx, it);
 
     if !sidebar.is_empty() {
         write!(fmt, "<div class=\"block items\">{}</div>", sidebar)?;
@@ -4424,7 +5953,7 @@ fn get_struct_fields_name(fields: &[clean::Item]) -> String {
           .collect()
 }
 
-fn sidebar_union(fmt: &mut fmt::Formatter, it: &clean::Item,
+fn sidebar_union(fmt: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                  u: &clean::Union) -> fmt::Result {
     let mut sidebar = String::new();
     let fields = get_struct_fields_name(&u.fields);
@@ -4434,7 +5963,7 @@ fn sidebar_union(fmt: &mut fmt::Formatter, it: &clean::Item,
                                    <div class=\"sidebar-links\">{}</div>", fields));
     }
 
-    sidebar.push_str(&sidebar_assoc_items(it));
+    sidebar.push_str(&sidebar_assoc_items(cx, it));
 
     if !sidebar.is_empty() {
         write!(fmt, "<div class=\"block items\">{}</div>", sidebar)?;
@@ -4442,7 +5971,7 @@ fn sidebar_union(fmt: &mut fmt::Formatter, it: &clean::Item,
     Ok(())
 }
 
-fn sidebar_enum(fmt: &mut fmt::Formatter, it: &clean::Item,
+fn sidebar_enum(fmt: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
                 e: &clean::Enum) -> fmt::Result {
     let mut sidebar = String::new();
 
@@ -4458,7 +5987,7 @@ fn sidebar_enum(fmt: &mut fmt::Formatter, it: &clean::Item,
                                    <div class=\"sidebar-links\">{}</div>", variants));
     }
 
-    sidebar.push_str(&sidebar_assoc_items(it));
+    sidebar.push_str(&sidebar_assoc_items(cx, it));
 
     if !sidebar.is_empty() {
         write!(fmt, "<div class=\"block items\">{}</div>", sidebar)?;
@@ -4491,6 +6020,9 @@ fn item_ty_to_strs(ty: &ItemType) -> (&'static str, &'static str) {
         ItemType::ForeignType     => ("foreign-types", "Foreign Types"),
         ItemType::Keyword         => ("keywords", "Keywords"),
         ItemType::Existential     => ("existentials", "Existentials"),
+        ItemType::ProcAttribute   => ("attributes", "Attribute Macros"),
+        ItemType::ProcDerive      => ("derives", "Derive Macros"),
+        ItemType::TraitAlias      => ("trait-aliases", "Trait Aliases"),
     }
 }
 
@@ -4507,11 +6039,13 @@ fn sidebar_module(fmt: &mut fmt::Formatter, _it: &clean::Item,
 
     // ordering taken from item_module, reorder, where it prioritized elements in a certain order
     // to print its headings
-    for &myty in &[ItemType::Primitive, ItemType::Module, ItemType::Macro, ItemType::Struct,
+    for &myty in &[ItemType::Primitive, ItemType::Module, ItemType::Macro,
+                   ItemType::ProcAttribute, ItemType::ProcDerive, ItemType::Struct,
                    ItemType::Enum, ItemType::Constant, ItemType::Static, ItemType::Trait,
                    ItemType::Function, ItemType::Typedef, ItemType::Union, ItemType::Impl,
                    ItemType::TyMethod, ItemType::Method, ItemType::StructField, ItemType::Variant,
-                   ItemType::AssociatedType, ItemType::AssociatedConst, ItemType::ForeignType] {
+                   ItemType::AssociatedType, ItemType::AssociatedConst, ItemType::ForeignType,
+                   ItemType::TraitAlias] {
         if items.iter().any(|it| !it.is_stripped() && it.type_() == myty) {
             let (short, name) = item_ty_to_strs(&myty);
             sidebar.push_str(&format!("<li><a href=\"#{id}\">{name}</a></li>",
@@ -4526,8 +6060,9 @@ fn sidebar_module(fmt: &mut fmt::Formatter, _it: &clean::Item,
     Ok(())
 }
 
-fn sidebar_foreign_type(fmt: &mut fmt::Formatter, it: &clean::Item) -> fmt::Result {
-    let sidebar = sidebar_assoc_items(it);
+fn sidebar_foreign_type(fmt: &mut fmt::Formatter, cx: &Context,
+                        it: &clean::Item) -> fmt::Result {
+    let sidebar = sidebar_assoc_items(cx, it);
     if !sidebar.is_empty() {
         write!(fmt, "<div class=\"block items\">{}</div>", sidebar)?;
     }
@@ -4536,7 +6071,7 @@ fn sidebar_foreign_type(fmt: &mut fmt::Formatter, it: &clean::Item) -> fmt::Resu
 
 impl<'a> fmt::Display for Source<'a> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let Source(s) = *self;
+        let Source(s, links) = *self;
         let lines = s.lines().count();
         let mut cols = 0;
         let mut tmp = lines;
@@ -4549,8 +6084,7 @@ impl<'a> fmt::Display for Source<'a> {
             write!(fmt, "<span id=\"{0}\">{0:1$}</span>\n", i, cols)?;
         }
         write!(fmt, "</pre>")?;
-        write!(fmt, "{}",
-               highlight::render_with_highlighting(s, None, None, None))?;
+        write!(fmt, "{}", highlight::render_source_with_links(s, links))?;
         Ok(())
     }
 }
@@ -4566,6 +6100,33 @@ fn item_macro(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
     document(w, cx, it)
 }
 
+fn item_proc_macro(w: &mut fmt::Formatter, cx: &Context, it: &clean::Item,
+                   m: &clean::ProcMacro) -> fmt::Result {
+    let name = it.name.as_ref().expect("proc-macros always have names");
+    match m.kind {
+        MacroKind::Bang => {
+            write!(w, "<pre class='rust macro'>{}!() {{ /* proc-macro */ }}</pre>", name)?;
+        }
+        MacroKind::Attr => {
+            write!(w, "<pre class='rust attr'>#[{}]</pre>", name)?;
+        }
+        MacroKind::Derive => {
+            write!(w, "<pre class='rust derive'>#[derive({})]", name)?;
+            if !m.helpers.is_empty() {
+                writeln!(w, "\n{{")?;
+                writeln!(w, "    // Attributes available to this derive:")?;
+                for attr in &m.helpers {
+                    writeln!(w, "    #[{}]", attr)?;
+                }
+                write!(w, "}}")?;
+            }
+            write!(w, "</pre>")?;
+        }
+        MacroKind::ProcMacroStub => unreachable!(),
+    }
+    document(w, cx, it)
+}
+
 fn item_primitive(w: &mut fmt::Formatter, cx: &Context,
                   it: &clean::Item,
                   _p: &clean::PrimitiveType) -> fmt::Result {
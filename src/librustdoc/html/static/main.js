@@ -11,7 +11,7 @@
  */
 
 /*jslint browser: true, es5: true */
-/*globals $: true, rootPath: true */
+/*globals $: true, rootPath: true, rootDepth: true */
 
 (function() {
     "use strict";
@@ -39,9 +39,13 @@
                      "associatedconstant",
                      "union",
                      "foreigntype",
-                     "keyword"];
+                     "keyword",
+                     "existential",
+                     "attr",
+                     "derive",
+                     "traitalias"];
 
-    var search_input = document.getElementsByClassName('search-input')[0];
+    var search_input = document.getElementsByClassName(prefixClasses('search-input'))[0];
 
     // On the search screen, so you remain on the last tab you opened.
     //
@@ -74,6 +78,7 @@
     }
 
     function hasClass(elem, className) {
+        className = prefixClasses(className);
         if (elem && className && elem.className) {
             var elemClass = elem.className;
             var start = elemClass.indexOf(className);
@@ -99,6 +104,7 @@
 
     function addClass(elem, className) {
         if (elem && className && !hasClass(elem, className)) {
+            className = prefixClasses(className);
             if (elem.className && elem.className.length > 0) {
                 elem.className += ' ' + className;
             } else {
@@ -108,6 +114,7 @@
     }
 
     function removeClass(elem, className) {
+        className = prefixClasses(className);
         if (elem && className && elem.className) {
             elem.className = (" " + elem.className + " ").replace(" " + className + " ", " ")
                                                          .trim();
@@ -119,11 +126,11 @@
     }
 
     function showSidebar() {
-        var elems = document.getElementsByClassName("sidebar-elems")[0];
+        var elems = document.getElementsByClassName(prefixClasses("sidebar-elems"))[0];
         if (elems) {
             addClass(elems, "show-it");
         }
-        var sidebar = document.getElementsByClassName('sidebar')[0];
+        var sidebar = document.getElementsByClassName(prefixClasses('sidebar'))[0];
         if (sidebar) {
             addClass(sidebar, 'mobile');
             var filler = document.getElementById("sidebar-filler");
@@ -133,25 +140,25 @@
                 sidebar.appendChild(div);
             }
         }
-        var themePicker = document.getElementsByClassName("theme-picker");
+        var themePicker = document.getElementsByClassName(prefixClasses("theme-picker"));
         if (themePicker && themePicker.length > 0) {
             themePicker[0].style.display = "none";
         }
     }
 
     function hideSidebar() {
-        var elems = document.getElementsByClassName("sidebar-elems")[0];
+        var elems = document.getElementsByClassName(prefixClasses("sidebar-elems"))[0];
         if (elems) {
             removeClass(elems, "show-it");
         }
-        var sidebar = document.getElementsByClassName('sidebar')[0];
+        var sidebar = document.getElementsByClassName(prefixClasses('sidebar'))[0];
         removeClass(sidebar, 'mobile');
         var filler = document.getElementById("sidebar-filler");
         if (filler) {
             filler.remove();
         }
         document.getElementsByTagName("body")[0].style.marginTop = '';
-        var themePicker = document.getElementsByClassName("theme-picker");
+        var themePicker = document.getElementsByClassName(prefixClasses("theme-picker"));
         if (themePicker && themePicker.length > 0) {
             themePicker[0].style.display = null;
         }
@@ -161,7 +168,7 @@
     var TY_PRIMITIVE = itemTypes.indexOf("primitive");
     var TY_KEYWORD = itemTypes.indexOf("keyword");
 
-    onEach(document.getElementsByClassName('js-only'), function(e) {
+    onEach(document.getElementsByClassName(prefixClasses('js-only')), function(e) {
         removeClass(e, 'js-only');
     });
 
@@ -200,7 +207,7 @@
                     x.scrollIntoView();
                 }
             }
-            onEach(document.getElementsByClassName('line-numbers'), function(e) {
+            onEach(document.getElementsByClassName(prefixClasses('line-numbers')), function(e) {
                 onEach(e.getElementsByTagName('span'), function(i_e) {
                     removeClass(i_e, 'line-highlighted');
                 });
@@ -354,13 +361,13 @@
         }
     };
 
-    var x = document.getElementsByClassName('version-selector');
+    var x = document.getElementsByClassName(prefixClasses('version-selector'));
     if (x.length > 0) {
         x[0].onchange = function() {
             var i, match,
                 url = document.location.href,
                 stripped = '',
-                len = rootPath.match(/\.\.\//g).length + 1;
+                len = rootDepth + 1;
 
             for (i = 0; i < len; ++i) {
                 match = url.match(/\/[^\/]*$/);
@@ -370,7 +377,9 @@
                 url = url.substring(0, url.length - match[0].length);
             }
 
-            url += '/' + document.getElementsByClassName('version-selector')[0].value + stripped;
+            var versionSelector =
+                document.getElementsByClassName(prefixClasses('version-selector'))[0];
+            url += '/' + versionSelector.value + stripped;
 
             document.location.href = url;
         };
@@ -1095,7 +1104,9 @@
             var matches, type, query;
             query = raw;
 
-            matches = query.match(/^(fn|mod|struct|enum|trait|type|const|macro)\s*:\s*/i);
+            matches = query.match(new RegExp("^(fn|mod|struct|enum|trait|type|const|static|" +
+                                             "union|macro|primitive|keyword|attr|derive|" +
+                                             "traitalias)\\s*:\\s*", "i"));
             if (matches) {
                 type = matches[1].replace(/^const$/, 'constant');
                 query = query.substring(matches[0].length);
@@ -1137,16 +1148,18 @@
                 }
                 clearTimeout(hoverTimeout);
                 hoverTimeout = setTimeout(function() {
-                    onEach(document.getElementsByClassName('search-results'), function(e) {
-                        onEach(e.getElementsByClassName('result'), function(i_e) {
+                    var searchResults =
+                        document.getElementsByClassName(prefixClasses('search-results'));
+                    onEach(searchResults, function(e) {
+                        onEach(e.getElementsByClassName(prefixClasses('result')), function(i_e) {
                             removeClass(i_e, 'highlighted');
                         });
                     });
                     addClass(el, 'highlighted');
                 }, 20);
             };
-            onEach(document.getElementsByClassName('search-results'), function(e) {
-                onEach(e.getElementsByClassName('result'), function(i_e) {
+            onEach(document.getElementsByClassName(prefixClasses('search-results')), function(e) {
+                onEach(e.getElementsByClassName(prefixClasses('result')), function(i_e) {
                     i_e.onclick = click_func;
                     i_e.onmouseover = mouseover_func;
                 });
@@ -1158,8 +1171,10 @@
                 var actives = [[], [], []];
                 // "current" is used to know which tab we're looking into.
                 var current = 0;
-                onEach(document.getElementsByClassName('search-results'), function(e) {
-                    onEach(e.getElementsByClassName('highlighted'), function(e) {
+                var searchResults =
+                    document.getElementsByClassName(prefixClasses('search-results'));
+                onEach(searchResults, function(e) {
+                    onEach(e.getElementsByClassName(prefixClasses('highlighted')), function(e) {
                         actives[current].push(e);
                     });
                     current += 1;
@@ -1175,9 +1190,11 @@
                     removeClass(actives[currentTab][0], 'highlighted');
                 } else if (e.which === 40) { // down
                     if (!actives[currentTab].length) {
-                        var results = document.getElementsByClassName('search-results');
+                        var results =
+                            document.getElementsByClassName(prefixClasses('search-results'));
                         if (results.length > 0) {
-                            var res = results[currentTab].getElementsByClassName('result');
+                            var res =
+                                results[currentTab].getElementsByClassName(prefixClasses('result'));
                             if (res.length > 0) {
                                 addClass(res[0], 'highlighted');
                             }
@@ -1272,7 +1289,8 @@
             var duplicates = {};
             var length = 0;
             if (array.length > 0) {
-                output = '<table class="search-results"' + extraStyle + '>';
+                output = '<table class="' + prefixClasses('search-results') + '"' + extraStyle +
+                         '>';
 
                 array.forEach(function(item) {
                     var name, type;
@@ -1288,20 +1306,24 @@
                     }
                     length += 1;
 
-                    output += '<tr class="' + type + ' result"><td>' +
+                    output += '<tr class="' + prefixClasses(type + ' result') + '"><td>' +
                               '<a href="' + item.href + '">' +
                               (item.is_alias === true ?
-                               ('<span class="alias"><b>' + item.alias + ' </b></span><span ' +
-                                  'class="grey"><i>&nbsp;- see&nbsp;</i></span>') : '') +
-                              item.displayPath + '<span class="' + type + '">' +
+                               ('<span class="' + prefixClasses('alias') + '"><b>' +
+                                  item.alias + ' </b></span><span class="' +
+                                  prefixClasses('grey') + '"><i>&nbsp;- see&nbsp;</i></span>') :
+                               '') +
+                              item.displayPath + '<span class="' + prefixClasses(type) + '">' +
                               name + '</span></a></td><td>' +
                               '<a href="' + item.href + '">' +
-                              '<span class="desc">' + escape(item.desc) +
+                              '<span class="' + prefixClasses('desc') + '">' +
+                              escape(item.desc) +
                               '&nbsp;</span></a></td></tr>';
                 });
                 output += '</table>';
             } else {
-                output = '<div class="search-failed"' + extraStyle + '>No results :(<br/>' +
+                output = '<div class="' + prefixClasses('search-failed') + '"' + extraStyle +
+                    '>No results :(<br/>' +
                     'Try on <a href="https://duckduckgo.com/?q=' +
                     encodeURIComponent('rust ' + query.query) +
                     '">DuckDuckGo</a>?</div>';
@@ -1311,10 +1333,12 @@
 
         function makeTabHeader(tabNb, text, nbElems) {
             if (currentTab === tabNb) {
-                return '<div class="selected">' + text +
-                       ' <div class="count">(' + nbElems + ')</div></div>';
+                return '<div class="' + prefixClasses('selected') + '">' + text +
+                       ' <div class="' + prefixClasses('count') + '">(' + nbElems +
+                       ')</div></div>';
             }
-            return '<div>' + text + ' <div class="count">(' + nbElems + ')</div></div>';
+            return '<div>' + text + ' <div class="' + prefixClasses('count') + '">(' + nbElems +
+                   ')</div></div>';
         }
 
         function showResults(results) {
@@ -1354,7 +1378,7 @@
                 td_width = tds[0].offsetWidth;
             }
             var width = search.offsetWidth - 40 - td_width;
-            onEach(search.getElementsByClassName('desc'), function(e) {
+            onEach(search.getElementsByClassName(prefixClasses('desc')), function(e) {
                 e.style.width = width + 'px';
             });
             initSearchNav();
@@ -1551,7 +1575,8 @@
             };
             search_input.onkeyup = callback;
             search_input.oninput = callback;
-            document.getElementsByClassName("search-form")[0].onsubmit = function(e) {
+            var searchForm = document.getElementsByClassName(prefixClasses("search-form"))[0];
+            searchForm.onsubmit = function(e) {
                 e.preventDefault();
                 clearTimeout(searchTimeout);
                 search();
@@ -1617,11 +1642,11 @@
         startSearch();
 
         // Draw a convenient sidebar of known crates if we have a listing
-        if (rootPath === '../' || rootPath === "./") {
-            var sidebar = document.getElementsByClassName('sidebar-elems')[0];
+        if (rootDepth <= 1) {
+            var sidebar = document.getElementsByClassName(prefixClasses('sidebar-elems'))[0];
             if (sidebar) {
                 var div = document.createElement('div');
-                div.className = 'block crate';
+                div.className = prefixClasses('block crate');
                 div.innerHTML = '<h3>Crates</h3>';
                 var ul = document.createElement('ul');
                 div.appendChild(ul);
@@ -1636,13 +1661,13 @@
                 crates.sort();
                 for (var i = 0; i < crates.length; ++i) {
                     var klass = 'crate';
-                    if (rootPath !== "./" && crates[i] === window.currentCrate) {
+                    if (rootDepth !== 0 && crates[i] === window.currentCrate) {
                         klass += ' current';
                     }
                     var link = document.createElement('a');
                     link.href = rootPath + crates[i] + '/index.html';
                     link.title = rawSearchIndex[crates[i]].doc;
-                    link.className = klass;
+                    link.className = prefixClasses(klass);
                     link.textContent = crates[i];
 
                     var li = document.createElement('li');
@@ -1658,7 +1683,7 @@
 
     // delayed sidebar rendering.
     function initSidebarItems(items) {
-        var sidebar = document.getElementsByClassName('sidebar-elems')[0];
+        var sidebar = document.getElementsByClassName(prefixClasses('sidebar-elems'))[0];
         var current = window.sidebarCurrent;
 
         function block(shortty, longty) {
@@ -1666,7 +1691,7 @@
             if (!filtered) { return; }
 
             var div = document.createElement('div');
-            div.className = 'block ' + shortty;
+            div.className = prefixClasses('block ' + shortty);
             var h3 = document.createElement('h3');
             h3.textContent = longty;
             div.appendChild(h3);
@@ -1690,7 +1715,7 @@
                 var link = document.createElement('a');
                 link.href = current.relpath + path;
                 link.title = desc;
-                link.className = klass;
+                link.className = prefixClasses(klass);
                 link.textContent = name;
                 var li = document.createElement('li');
                 li.appendChild(link);
@@ -1790,6 +1815,7 @@
         if (!toggle) {
             return;
         }
+        var toggles = document.getElementsByClassName(prefixClasses("collapse-toggle"));
         if (hasClass(toggle, "will-expand")) {
             updateLocalStorage("rustdoc-collapse", "false");
             removeClass(toggle, "will-expand");
@@ -1798,7 +1824,7 @@
             });
             toggle.title = "collapse all docs";
             if (fromAutoCollapse !== true) {
-                onEach(document.getElementsByClassName("collapse-toggle"), function(e) {
+                onEach(toggles, function(e) {
                     collapseDocs(e, "show");
                 });
             }
@@ -1810,7 +1836,7 @@
             });
             toggle.title = "expand all docs";
             if (fromAutoCollapse !== true) {
-                onEach(document.getElementsByClassName("collapse-toggle"), function(e) {
+                onEach(toggles, function(e) {
                     collapseDocs(e, "hide", pageId);
                 });
             }
@@ -1939,7 +1965,7 @@
             toggleAllDocs(pageId, true);
         }
         if (getCurrentValue('rustdoc-trait-implementations') !== "false") {
-            onEach(document.getElementsByClassName("collapse-toggle"), function(e) {
+            onEach(document.getElementsByClassName(prefixClasses("collapse-toggle")), function(e) {
                 // inherent impl ids are like 'impl' or impl-<number>'.
                 // they will never be hidden by default.
                 var n = e.parentNode;
@@ -1976,8 +2002,9 @@
 
     var toggle = document.createElement('a');
     toggle.href = 'javascript:void(0)';
-    toggle.className = 'collapse-toggle';
-    toggle.innerHTML = "[<span class='inner'>" + labelForToggleButton(false) + "</span>]";
+    toggle.className = prefixClasses('collapse-toggle');
+    toggle.innerHTML = "[<span class='" + prefixClasses('inner') + "'>" +
+                       labelForToggleButton(false) + "</span>]";
 
     var func = function(e) {
         var next = e.nextElementSibling;
@@ -1995,15 +2022,15 @@
             insertAfter(toggle.cloneNode(true), e.childNodes[e.childNodes.length - 1]);
         }
     };
-    onEach(document.getElementsByClassName('method'), func);
-    onEach(document.getElementsByClassName('impl'), func);
-    onEach(document.getElementsByClassName('impl-items'), function(e) {
-        onEach(e.getElementsByClassName('associatedconstant'), func);
+    onEach(document.getElementsByClassName(prefixClasses('method')), func);
+    onEach(document.getElementsByClassName(prefixClasses('impl')), func);
+    onEach(document.getElementsByClassName(prefixClasses('impl-items')), function(e) {
+        onEach(e.getElementsByClassName(prefixClasses('associatedconstant')), func);
     });
 
     function createToggle(otherMessage, fontSize, extraClass) {
         var span = document.createElement('span');
-        span.className = 'toggle-label';
+        span.className = prefixClasses('toggle-label');
         span.style.display = 'none';
         if (!otherMessage) {
             span.innerHTML = '&nbsp;Expand&nbsp;description';
@@ -2019,15 +2046,15 @@
         mainToggle.appendChild(span);
 
         var wrapper = document.createElement('div');
-        wrapper.className = 'toggle-wrapper';
+        wrapper.className = prefixClasses('toggle-wrapper');
         if (extraClass) {
-            wrapper.className += ' ' + extraClass;
+            wrapper.className += ' ' + prefixClasses(extraClass);
         }
         wrapper.appendChild(mainToggle);
         return wrapper;
     }
 
-    onEach(document.getElementsByClassName('docblock'), function(e) {
+    onEach(document.getElementsByClassName(prefixClasses('docblock')), function(e) {
         if (hasClass(e, 'autohide')) {
             var wrap = e.previousElementSibling;
             if (wrap && hasClass(wrap, 'toggle-wrapper')) {
@@ -2038,10 +2065,10 @@
                 }
                 e.style.display = 'none';
                 addClass(wrap, 'collapsed');
-                onEach(toggle.getElementsByClassName('inner'), function(e) {
+                onEach(toggle.getElementsByClassName(prefixClasses('inner')), function(e) {
                     e.innerHTML = labelForToggleButton(true);
                 });
-                onEach(toggle.getElementsByClassName('toggle-label'), function(e) {
+                onEach(toggle.getElementsByClassName(prefixClasses('toggle-label')), function(e) {
                     e.style.display = 'inline-block';
                     if (extra === true) {
                         i_e.innerHTML = " Show " + e.childNodes[0].innerHTML;
@@ -2080,13 +2107,13 @@
 
     function createToggleWrapper(tog) {
         var span = document.createElement('span');
-        span.className = 'toggle-label';
+        span.className = prefixClasses('toggle-label');
         span.style.display = 'none';
         span.innerHTML = '&nbsp;Expand&nbsp;attributes';
         tog.appendChild(span);
 
         var wrapper = document.createElement('div');
-        wrapper.className = 'toggle-wrapper toggle-attributes';
+        wrapper.className = prefixClasses('toggle-wrapper toggle-attributes');
         wrapper.appendChild(tog);
         return wrapper;
     }
@@ -2115,14 +2142,16 @@
         });
     }
 
-    onEach(document.getElementById('main').getElementsByClassName('attributes'), function(i_e) {
+    var attributes =
+        document.getElementById('main').getElementsByClassName(prefixClasses('attributes'));
+    onEach(attributes, function(i_e) {
         i_e.parentNode.insertBefore(createToggleWrapper(toggle.cloneNode(true)), i_e);
         if (getCurrentValue("rustdoc-item-attributes") !== "false") {
             collapseDocs(i_e.previousSibling.childNodes[0], "toggle");
         }
     });
 
-    onEach(document.getElementsByClassName('rust-example-rendered'), function(e) {
+    onEach(document.getElementsByClassName(prefixClasses('rust-example-rendered')), function(e) {
         if (hasClass(e, 'compile_fail')) {
             e.addEventListener("mouseover", function(event) {
                 e.previousElementSibling.childNodes[0].style.color = '#f00';
@@ -2144,9 +2173,10 @@
         var modal = document.createElement('div');
         modal.id = "important";
         addClass(modal, 'modal');
-        modal.innerHTML = '<div class="modal-content"><div class="close" id="modal-close">✕</div>' +
-                          '<div class="whiter"></div><span class="docblock">' + content +
-                          '</span></div>';
+        modal.innerHTML = '<div class="' + prefixClasses('modal-content') + '"><div class="' +
+                          prefixClasses('close') + '" id="modal-close">✕</div><div class="' +
+                          prefixClasses('whiter') + '"></div><span class="' +
+                          prefixClasses('docblock') + '">' + content + '</span></div>';
         document.getElementsByTagName('body')[0].appendChild(modal);
         document.getElementById('modal-close').onclick = hideModal;
         modal.onclick = hideModal;
@@ -2159,7 +2189,7 @@
         }
     }
 
-    onEach(document.getElementsByClassName('important-traits'), function(e) {
+    onEach(document.getElementsByClassName(prefixClasses('important-traits')), function(e) {
         e.onclick = function() {
             showModal(e.lastElementChild.innerHTML);
         };
@@ -2191,10 +2221,10 @@
         search.innerHTML = '<h3 style="text-align: center;">Loading search results...</h3>';
     }
 
-    var sidebar_menu = document.getElementsByClassName("sidebar-menu")[0];
+    var sidebar_menu = document.getElementsByClassName(prefixClasses("sidebar-menu"))[0];
     if (sidebar_menu) {
         sidebar_menu.onclick = function() {
-            var sidebar = document.getElementsByClassName('sidebar')[0];
+            var sidebar = document.getElementsByClassName(prefixClasses('sidebar'))[0];
             if (hasClass(sidebar, "mobile") === true) {
                 hideSidebar();
             } else {
@@ -2210,12 +2240,18 @@
     autoCollapse(getPageId(), getCurrentValue("rustdoc-collapse") === "true");
 }());
 
-// Sets the focus on the search bar at the top of the page
+// Sets the focus on the search bar at the top of the page, if there is one
 function focusSearchBar() {
-    document.getElementsByClassName('search-input')[0].focus();
+    var search_input = document.getElementsByClassName(prefixClasses('search-input'))[0];
+    if (search_input) {
+        search_input.focus();
+    }
 }
 
 // Removes the focus from the search bar
 function defocusSearchBar() {
-    document.getElementsByClassName('search-input')[0].blur();
+    var search_input = document.getElementsByClassName(prefixClasses('search-input'))[0];
+    if (search_input) {
+        search_input.blur();
+    }
 }
@@ -20,7 +20,7 @@
     }
 
     function setEvents() {
-        var elems = document.getElementsByClassName("slider");
+        var elems = document.getElementsByClassName(prefixClasses("slider"));
         if (!elems || elems.length === 0) {
             return;
         }
@@ -15,6 +15,19 @@ var mainTheme = document.getElementById("mainThemeStyle");
 
 var savedHref = [];
 
+// The prefix of the classes of the pages, given with `--css-class-prefix`.
+var classPrefix = window.classPrefix || "";
+
+// Prefixes the space-separated class names of `classes` like the classes of the pages.
+function prefixClasses(classes) {
+    if (!classPrefix || !classes) {
+        return classes;
+    }
+    return classes.split(" ").map(function(name) {
+        return name ? classPrefix + name : name;
+    }).join(" ");
+}
+
 function onEach(arr, func) {
     if (arr && arr.length > 0 && func) {
         for (var i = 0; i < arr.length; i++) {
@@ -0,0 +1,125 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Self-check of the rendered documentation, making sure that the links to anchors emitted in
+//! the pages of a crate point to elements which exist.
+
+use std::collections::hash_map::Entry;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rustc::util::nodemap::{FxHashMap, FxHashSet};
+
+/// A link to an anchor which doesn't exist in the page it points to.
+pub struct DeadAnchor {
+    /// The page containing the link, relative to the output directory.
+    pub page: PathBuf,
+    pub href: String,
+}
+
+/// Checks the links to anchors in the pages of `krate`, including its source pages, which were
+/// rendered into `dst`.
+pub fn check(dst: &Path, krate: &str) -> io::Result<Vec<DeadAnchor>> {
+    let mut pages = Vec::new();
+    collect_pages(&dst.join(krate), &mut pages)?;
+    collect_pages(&dst.join("src").join(krate), &mut pages)?;
+
+    let mut anchors = FxHashMap();
+    let mut dead_anchors = Vec::new();
+    for page in &pages {
+        let contents = fs::read_to_string(page)?;
+        for href in attribute_values(&contents, "href") {
+            let (file, fragment) = match href.find('#') {
+                Some(pos) => (&href[..pos], &href[pos + 1..]),
+                None => continue,
+            };
+            // Skip empty fragments, links to other sites and the line ranges of the source
+            // pages, which are handled by the scripts.
+            if fragment.is_empty() || file.contains(':') || is_line_range(fragment) {
+                continue;
+            }
+            let target = if file.is_empty() {
+                page.clone()
+            } else {
+                page.parent().unwrap_or(dst).join(file)
+            };
+            let target_anchors = match anchors.entry(target) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let target_anchors = read_anchors(entry.key());
+                    entry.insert(target_anchors)
+                }
+            };
+            // Pages which weren't rendered locally can't be checked
+            if let Some(ref target_anchors) = *target_anchors {
+                if !target_anchors.contains(fragment) {
+                    dead_anchors.push(DeadAnchor {
+                        page: page.strip_prefix(dst).unwrap_or(page).to_path_buf(),
+                        href: href.to_owned(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(dead_anchors)
+}
+
+fn collect_pages(dir: &Path, pages: &mut Vec<PathBuf>) -> io::Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_pages(&path, pages)?;
+        } else if path.extension().map_or(false, |ext| ext == "html") {
+            pages.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the `id`s and `name`s defined in the given page, or `None` if it can't be read.
+fn read_anchors(page: &Path) -> Option<FxHashSet<String>> {
+    let contents = fs::read_to_string(page).ok()?;
+    let mut anchors = FxHashSet();
+    anchors.extend(attribute_values(&contents, "id").into_iter().map(|id| id.to_owned()));
+    anchors.extend(attribute_values(&contents, "name").into_iter().map(|name| name.to_owned()));
+    Some(anchors)
+}
+
+/// Returns the values of the given attribute in an HTML document, as emitted by the renderer.
+///
+/// Quotes in the text of the pages are always escaped, so they can't be mistaken for
+/// attributes.
+fn attribute_values<'a>(html: &'a str, attr: &str) -> Vec<&'a str> {
+    let pattern = format!(" {}=", attr);
+    let mut values = Vec::new();
+    let mut rest = html;
+    while let Some(pos) = rest.find(&pattern[..]) {
+        rest = &rest[pos + pattern.len()..];
+        let quote = match rest.chars().next() {
+            Some(quote @ '"') | Some(quote @ '\'') => quote,
+            _ => continue,
+        };
+        rest = &rest[1..];
+        if let Some(end) = rest.find(quote) {
+            values.push(&rest[..end]);
+            rest = &rest[end + 1..];
+        }
+    }
+    values
+}
+
+fn is_line_range(fragment: &str) -> bool {
+    let mut lines = fragment.splitn(2, '-');
+    lines.all(|line| line.parse::<u32>().is_ok()) && fragment.contains('-')
+}
@@ -55,9 +55,10 @@ extern crate serialize as rustc_serialize; // used by deriving
 
 use errors::ColorConfig;
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::default::Default;
 use std::env;
+use std::fs;
 use std::panic;
 use std::path::{Path, PathBuf};
 use std::process;
@@ -71,26 +72,32 @@ use rustc::session::config::{ErrorOutputType, RustcOptGroup, Externs, CodegenOpt
 use rustc::session::config::{nightly_options, build_codegen_options};
 use rustc_target::spec::TargetTriple;
 use rustc::session::config::get_cmd_lint_options;
+use rustc::util::nodemap::FxHashSet;
 
 #[macro_use]
 mod externalfiles;
 
 mod clean;
 mod core;
+mod coverage;
 mod doctree;
 mod fold;
 pub mod html {
+    crate mod class_prefix;
     crate mod highlight;
     crate mod escape;
+    crate mod features;
     crate mod item_type;
     crate mod format;
     crate mod layout;
     pub mod markdown;
     crate mod render;
     crate mod toc;
+    crate mod verify_anchors;
 }
 mod markdown;
 mod passes;
+mod span_map;
 mod visit_ast;
 mod visit_lib;
 mod test;
@@ -102,6 +109,8 @@ struct Output {
     krate: clean::Crate,
     renderinfo: html::render::RenderInfo,
     passes: Vec<String>,
+    /// Whether any error was reported while documenting the crate.
+    has_errors: bool,
 }
 
 pub fn main() {
@@ -249,6 +258,18 @@ fn opts() -> Vec<RustcOptGroup> {
         stable("sysroot", |o| {
             o.optopt("", "sysroot", "Override the system root", "PATH")
         }),
+        unstable("html-favicon-url", |o| {
+            o.optopt("", "html-favicon-url",
+                     "URL of the favicon of the documentation, overriding \
+                      `#![doc(html_favicon_url=...)]`",
+                     "URL")
+        }),
+        unstable("html-logo-url", |o| {
+            o.optopt("", "html-logo-url",
+                     "URL of the logo shown in the sidebar, overriding \
+                      `#![doc(html_logo_url=...)]`",
+                     "URL")
+        }),
         unstable("playground-url", |o| {
             o.optopt("", "playground-url",
                      "URL to send code snippets to, may be reset by --markdown-playground-url \
@@ -264,6 +285,257 @@ fn opts() -> Vec<RustcOptGroup> {
         unstable("linker", |o| {
             o.optopt("", "linker", "linker used for building executable test code", "PATH")
         }),
+        unstable("show-coverage", |o| {
+            o.optflag("",
+                      "show-coverage",
+                      "calculate the percentage of public items with documentation and print it \
+                       instead of generating the documentation; use `--output-format json` to \
+                       get the numbers as JSON")
+        }),
+        unstable("html-no-source", |o| {
+            o.optflag("",
+                      "html-no-source",
+                      "do not generate the source code pages nor link to them from the \
+                       documentation")
+        }),
+        unstable("generate-link-to-definition", |o| {
+            o.optflag("",
+                      "generate-link-to-definition",
+                      "make the paths in the source code pages link to the documentation of \
+                       the local items, and to the source code of the items of other crates")
+        }),
+        unstable("verify-anchors", |o| {
+            o.optflag("",
+                      "verify-anchors",
+                      "check that the links to anchors in the generated documentation point to \
+                       elements which exist")
+        }),
+        unstable("inline-search-index", |o| {
+            o.optflag("",
+                      "inline-search-index",
+                      "embed the search index into every page so that search works without \
+                       loading it, e.g. when browsing the documentation from `file://` URLs")
+        }),
+        unstable("single-page", |o| {
+            o.optflag("",
+                      "single-page",
+                      "render the whole crate into a single HTML page")
+        }),
+        unstable("extern-locations", |o| {
+            o.optopt("",
+                     "extern-locations",
+                     "file of `crate = url` lines giving the documentation root of external \
+                      crates to link to",
+                     "FILE")
+        }),
+        unstable("since", |o| {
+            o.optopt("",
+                     "since",
+                     "only document the items defined in the files changed since the given git \
+                      revision",
+                     "GITREF")
+        }),
+        unstable("changed-files", |o| {
+            o.optopt("",
+                     "changed-files",
+                     "file listing the changed source files, one per line and relative to the \
+                      directory of the list: only the items they define are documented",
+                     "FILE")
+        }),
+        unstable("destructured-args", |o| {
+            o.optopt("",
+                     "destructured-args",
+                     "how to show the function arguments which are destructured: as their \
+                      `pattern` (the default), as a made up `name`, or as their `type` alone",
+                     "pattern|name|type")
+        }),
+        unstable("bounds-style", |o| {
+            o.optopt("",
+                     "bounds-style",
+                     "where to show the bounds of the type parameters: next to them, or all in \
+                      the where clauses",
+                     "inline|where")
+        }),
+        unstable("expand-type-aliases", |o| {
+            o.optflag("",
+                      "expand-type-aliases",
+                      "show the methods and trait implementations of the aliased type on the \
+                       pages of the public type aliases")
+        }),
+        unstable("summary-length", |o| {
+            o.optopt("",
+                     "summary-length",
+                     "maximum number of characters of the item summaries shown in the module \
+                      listings",
+                     "N")
+        }),
+        unstable("show-attributes", |o| {
+            o.optmulti("",
+                       "show-attributes",
+                       "comma-separated list of attributes to show as badges on the functions \
+                        which have them, in addition to `track_caller`",
+                       "ATTRS")
+        }),
+        unstable("compact-sidebar", |o| {
+            o.optflag("",
+                      "compact-sidebar",
+                      "lay out the method lists of the sidebar as tightly packed names")
+        }),
+        unstable("emit-metadata-only", |o| {
+            o.optflag("",
+                      "emit-metadata-only",
+                      "only write the search index and the implementor lists of the crate, which \
+                       the documentation of its dependents links to, without rendering its pages")
+        }),
+        unstable("check", |o| {
+            o.optflag("",
+                      "check",
+                      "document the crate and report its warnings without writing any output")
+        }),
+        unstable("no-search", |o| {
+            o.optflag("",
+                      "no-search",
+                      "leave the search index and the search box out of the documentation")
+        }),
+        unstable("crate-features", |o| {
+            o.optopt("",
+                     "crate-features",
+                     "JSON file describing the feature flags of the crate, to document on its \
+                      features page",
+                     "FILE")
+        }),
+        unstable("link-summaries", |o| {
+            o.optflag("",
+                      "link-summaries",
+                      "show the summary of the documented items when hovering the links to them")
+        }),
+        unstable("detect-sealed-traits", |o| {
+            o.optflag("",
+                      "detect-sealed-traits",
+                      "note the traits which other crates can't implement because of a private \
+                       supertrait")
+        }),
+        unstable("document-unstable-impls", |o| {
+            o.optflag("",
+                      "document-unstable-impls",
+                      "mark the blanket implementations of unstable traits as unstable")
+        }),
+        unstable("mask-crate", |o| {
+            o.optmulti("",
+                       "mask-crate",
+                       "leave out the traits and the impls of the given dependency, as \
+                        `#[doc(masked)]` does on its `extern crate`",
+                       "NAME")
+        }),
+        unstable("render-macro-expansion", |o| {
+            o.optflag("",
+                      "render-macro-expansion",
+                      "show the expanded source of the items generated by macros on their pages, \
+                       in place of their source link")
+        }),
+        unstable("impl-order", |o| {
+            o.optopt("",
+                     "impl-order",
+                     "where the inherent impls of the types are shown relative to their trait \
+                      implementations",
+                     "inherent-first|trait-first|interleaved")
+        }),
+        unstable("css-class-prefix", |o| {
+            o.optopt("",
+                     "css-class-prefix",
+                     "prefix the classes of the pages and of their stylesheets, to embed the \
+                      documentation into another site",
+                     "PREFIX")
+        }),
+        unstable("trim-auto-traits", |o| {
+            o.optflag("",
+                      "trim-auto-traits",
+                      "sum up the auto trait implementations of a type on one line when it \
+                       implements all of them")
+        }),
+        unstable("annotate-phantom-data", |o| {
+            o.optflag("",
+                      "annotate-phantom-data",
+                      "explain the `PhantomData` fields of the structs")
+        }),
+        unstable("show-private-field-names", |o| {
+            o.optflag("",
+                      "show-private-field-names",
+                      "list the names of the private fields of the structs and unions instead \
+                       of only saying that some fields are omitted")
+        }),
+        unstable("emit-item-json", |o| {
+            o.optflag("",
+                      "emit-item-json",
+                      "write next to the page of each item a JSON file describing the item")
+        }),
+        unstable("html-root-path", |o| {
+            o.optopt("",
+                     "html-root-path",
+                     "refer to the root of the documentation with this path instead of a \
+                      relative one, to host it in a directory of a site",
+                     "PATH")
+        }),
+        unstable("emit-trait-impls-graph", |o| {
+            o.optopt("",
+                     "emit-trait-impls-graph",
+                     "write to this file a JSON graph of the traits implemented by the types, \
+                      telling apart the synthetic and blanket impls",
+                     "PATH")
+        }),
+        unstable("emit-redirect-map", |o| {
+            o.optopt("",
+                     "emit-redirect-map",
+                     "write to this file a JSON map from the paths of the items to the URLs of \
+                      their documentation, relative to its root",
+                     "PATH")
+        }),
+        unstable("warn-undocumented-features", |o| {
+            o.optflag("",
+                      "warn-undocumented-features",
+                      "warn about the features given with --crate-features which have no \
+                       description")
+        }),
+        unstable("exclude-module", |o| {
+            o.optmulti("",
+                       "exclude-module",
+                       "remove a module and all its items from the documentation",
+                       "PATH")
+        }),
+        unstable("show-async-desugaring", |o| {
+            o.optflag("",
+                      "show-async-desugaring",
+                      "also show the `impl Future` signature that `async fn`s desugar to")
+        }),
+        unstable("doctest-threads", |o| {
+            o.optopt("",
+                     "doctest-threads",
+                     "maximum number of code examples to run as tests at the same time \
+                      (defaults to the number of CPUs, overridden by a `--test-threads` \
+                      in `--test-args`)",
+                     "N")
+        }),
+        unstable("persist-doctests", |o| {
+            o.optopt("",
+                     "persist-doctests",
+                     "directory to persist doctest sources and executables into instead of \
+                      deleting them after running",
+                     "PATH")
+        }),
+        unstable("doctest-runner", |o| {
+            o.optopt("",
+                     "doctest-runner",
+                     "run the doctests with this command, given the path of their executable, \
+                      after compiling them for the `--target`",
+                     "PATH")
+        }),
+        unstable("test-builder", |o| {
+            o.optopt("",
+                     "test-builder",
+                     "compiler wrapper used to build the doctests, given the arguments rustdoc \
+                      would compile them with",
+                     "PATH")
+        }),
         unstable("sort-modules-by-appearance", |o| {
             o.optflag("", "sort-modules-by-appearance", "sort modules by where they appear in the \
                                                          program, rather than alphabetically")
@@ -273,6 +545,12 @@ fn opts() -> Vec<RustcOptGroup> {
                        "additional themes which will be added to the generated docs",
                        "FILES")
         }),
+        unstable("theme-directory", |o| {
+            o.optmulti("", "theme-directory",
+                       "directory whose CSS files will all be added as themes to the generated \
+                        docs",
+                       "DIR")
+        }),
         unstable("theme-checker", |o| {
             o.optmulti("", "theme-checker",
                        "check if given theme is valid",
@@ -456,12 +734,44 @@ fn main_args(args: &[String]) -> isize {
             return 1;
         }
     };
+    let extern_locations = match parse_extern_locations(&matches) {
+        Ok(locations) => locations,
+        Err(err) => {
+            diag.struct_err(&err).emit();
+            return 1;
+        }
+    };
+
+    let changed_files = match parse_changed_files(&matches, Path::new(input)) {
+        Ok(files) => files,
+        Err(err) => {
+            diag.struct_err(&err).emit();
+            return 1;
+        }
+    };
 
     let test_args = matches.opt_strs("test-args");
-    let test_args: Vec<String> = test_args.iter()
-                                          .flat_map(|s| s.split_whitespace())
-                                          .map(|s| s.to_string())
-                                          .collect();
+    let mut test_args: Vec<String> = test_args.iter()
+                                              .flat_map(|s| s.split_whitespace())
+                                              .map(|s| s.to_string())
+                                              .collect();
+    // The test runner runs the tests on its own pool of threads, whose size given in the test
+    // arguments wins over `--doctest-threads`.
+    let has_test_threads = test_args.iter().any(|arg| {
+        arg == "--test-threads" || arg.starts_with("--test-threads=")
+    });
+    match matches.opt_str("doctest-threads").map(|n| n.parse::<usize>()) {
+        Some(Ok(threads)) if threads > 0 => {
+            if !has_test_threads {
+                test_args.push(format!("--test-threads={}", threads));
+            }
+        }
+        Some(_) => {
+            diag.struct_err("--doctest-threads must be a positive number").emit();
+            return 1;
+        }
+        None => {}
+    }
 
     let should_test = matches.opt_present("test");
     let markdown_input = Path::new(input).extension()
@@ -478,24 +788,63 @@ fn main_args(args: &[String]) -> isize {
         }
     }
 
+    // The themes are kept along with the option which gave them, for the errors.
+    let mut theme_files = matches.opt_strs("themes")
+                                 .iter()
+                                 .map(|s| (PathBuf::from(&s), s.to_owned(), "--themes"))
+                                 .collect::<Vec<_>>();
+    for dir in matches.opt_strs("theme-directory") {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                diag.struct_err(&format!("couldn't read theme directory \"{}\": {}", dir, e))
+                    .emit();
+                return 1;
+            }
+        };
+        let mut dir_themes = entries.filter_map(|entry| entry.ok())
+                                    .map(|entry| entry.path())
+                                    .filter(|path| {
+                                        path.is_file() &&
+                                        path.extension().map_or(false, |ext| ext == "css")
+                                    })
+                                    .collect::<Vec<_>>();
+        dir_themes.sort();
+        theme_files.extend(dir_themes.into_iter().map(|path| {
+            let theme_s = path.display().to_string();
+            (path, theme_s, "--theme-directory")
+        }));
+    }
+
     let mut themes = Vec::new();
-    if matches.opt_present("themes") {
+    if !theme_files.is_empty() {
         let paths = theme::load_css_paths(include_bytes!("html/static/themes/light.css"));
+        let mut theme_names = HashSet::new();
+        theme_names.insert("light".to_owned());
+        theme_names.insert("dark".to_owned());
 
-        for (theme_file, theme_s) in matches.opt_strs("themes")
-                                            .iter()
-                                            .map(|s| (PathBuf::from(&s), s.to_owned())) {
+        for (theme_file, theme_s, option) in theme_files {
             if !theme_file.is_file() {
-                diag.struct_err("option --themes arguments must all be files").emit();
+                diag.struct_err(&format!("option {} arguments must all be files", option)).emit();
                 return 1;
             }
             let (success, ret) = theme::test_theme_against(&theme_file, &paths, &diag);
             if !success || !ret.is_empty() {
                 diag.struct_err(&format!("invalid theme: \"{}\"", theme_s))
+                    .note(&format!("given with {}", option))
                     .help("check what's wrong with the --theme-checker option")
                     .emit();
                 return 1;
             }
+            let theme_name = theme_file.file_stem()
+                                       .map(|name| name.to_string_lossy().into_owned())
+                                       .unwrap_or_default();
+            if !theme_names.insert(theme_name.clone()) {
+                diag.struct_err(&format!("theme \"{}\" is defined more than once", theme_name))
+                    .note(&format!("conflicting theme file: \"{}\"", theme_s))
+                    .emit();
+                return 1;
+            }
             themes.push(theme_file);
         }
     }
@@ -513,12 +862,142 @@ fn main_args(args: &[String]) -> isize {
     };
     let crate_name = matches.opt_str("crate-name");
     let playground_url = matches.opt_str("playground-url");
+    let favicon_url = matches.opt_str("html-favicon-url");
+    let logo_url = matches.opt_str("html-logo-url");
     let maybe_sysroot = matches.opt_str("sysroot").map(PathBuf::from);
     let display_warnings = matches.opt_present("display-warnings");
     let linker = matches.opt_str("linker").map(PathBuf::from);
+    let persist_doctests = matches.opt_str("persist-doctests").map(PathBuf::from);
+    let test_builder = matches.opt_str("test-builder").map(|path| test::TestBuilder {
+        path: PathBuf::from(path),
+        codegen_args: matches.opt_strs("C"),
+    });
+    let doctest_runner = matches.opt_str("doctest-runner").map(|command| test::DoctestRunner {
+        command: PathBuf::from(command),
+        target: matches.opt_str("target").map(parse_target),
+    });
     let sort_modules_alphabetically = !matches.opt_present("sort-modules-by-appearance");
     let resource_suffix = matches.opt_str("resource-suffix");
     let enable_minification = !matches.opt_present("disable-minification");
+    let include_sources = !matches.opt_present("html-no-source");
+    let show_async_desugaring = matches.opt_present("show-async-desugaring");
+    let single_page = matches.opt_present("single-page");
+    let inline_search_index = matches.opt_present("inline-search-index");
+    let compact_sidebar = matches.opt_present("compact-sidebar");
+    let verify_anchors = matches.opt_present("verify-anchors");
+    let emit_metadata_only = matches.opt_present("emit-metadata-only");
+    let check = matches.opt_present("check");
+    let no_search = matches.opt_present("no-search");
+    let link_summaries = matches.opt_present("link-summaries");
+    let detect_sealed_traits = matches.opt_present("detect-sealed-traits");
+
+    if emit_metadata_only && verify_anchors {
+        diag.struct_err("--emit-metadata-only and --verify-anchors can't be used together").emit();
+        return 1;
+    }
+
+    if check && markdown_input {
+        diag.struct_err("--check can only be used on a crate").emit();
+        return 1;
+    }
+
+    if no_search && inline_search_index {
+        diag.struct_err("--no-search and --inline-search-index can't be used together").emit();
+        return 1;
+    }
+
+    let destructured_args = match matches.opt_str("destructured-args").map(|s| s.parse()) {
+        Some(Ok(destructured_args)) => destructured_args,
+        Some(Err(_)) => {
+            diag.struct_err("--destructured-args must be one of `pattern`, `name` or `type`")
+                .emit();
+            return 1;
+        }
+        None => clean::DestructuredArgs::Pattern,
+    };
+
+    let css_class_prefix = matches.opt_str("css-class-prefix");
+    if let Some(ref prefix) = css_class_prefix {
+        if !html::class_prefix::is_valid(prefix) {
+            diag.struct_err("--css-class-prefix must be made of ASCII letters, digits, `-` and \
+                             `_`, and can't start with a digit")
+                .emit();
+            return 1;
+        }
+    }
+
+    let emit_item_json = matches.opt_present("emit-item-json");
+    let trim_auto_traits = matches.opt_present("trim-auto-traits");
+    let annotate_phantom_data = matches.opt_present("annotate-phantom-data");
+    let html_root_path = matches.opt_str("html-root-path");
+    let trait_impls_graph = matches.opt_str("emit-trait-impls-graph").map(PathBuf::from);
+    let show_private_field_names = matches.opt_present("show-private-field-names");
+    let redirect_map = matches.opt_str("emit-redirect-map").map(PathBuf::from);
+
+    let impl_order = match matches.opt_str("impl-order").map(|s| s.parse()) {
+        Some(Ok(order)) => order,
+        Some(Err(())) => {
+            diag.struct_err("--impl-order must be one of `inherent-first`, `trait-first` or \
+                             `interleaved`")
+                .emit();
+            return 1;
+        }
+        None => html::render::ImplOrder::InherentFirst,
+    };
+
+    let bounds_style = match matches.opt_str("bounds-style").map(|s| s.parse()) {
+        Some(Ok(bounds_style)) => bounds_style,
+        Some(Err(_)) => {
+            diag.struct_err("--bounds-style must be one of `inline` or `where`").emit();
+            return 1;
+        }
+        None => clean::BoundsStyle::Source,
+    };
+
+    let summary_length = match matches.opt_str("summary-length").map(|n| n.parse()) {
+        Some(Ok(length)) => Some(length),
+        Some(Err(_)) => {
+            diag.struct_err("could not parse summary length").emit();
+            return 1;
+        }
+        None => None,
+    };
+
+    let crate_features = match matches.opt_str("crate-features") {
+        Some(path) => match html::features::load(Path::new(&path)) {
+            Ok(features) => Some(features),
+            Err(e) => {
+                diag.struct_err(&format!("could not read the crate features in {}: {}", path, e))
+                    .emit();
+                return 1;
+            }
+        },
+        None => None,
+    };
+
+    if matches.opt_present("warn-undocumented-features") {
+        let features = match crate_features {
+            Some(ref features) => features,
+            None => {
+                diag.struct_err("--warn-undocumented-features requires --crate-features").emit();
+                return 1;
+            }
+        };
+        // `default` is described well enough by the features it enables.
+        for feature in features.iter().filter(|f| f.description.is_empty() && f.name != "default") {
+            diag.struct_warn(&format!("the feature `{}` is not documented", feature.name))
+                .help("add a `description` to its entry in the --crate-features file")
+                .emit();
+        }
+    }
+
+    let shown_attributes = matches.opt_strs("show-attributes")
+                                  .iter()
+                                  .flat_map(|s| s.split(','))
+                                  .map(|s| s.trim())
+                                  .filter(|s| !s.is_empty())
+                                  .map(|s| s.to_string())
+                                  .collect::<Vec<_>>();
 
     let edition = matches.opt_str("edition").unwrap_or("2015".to_string());
     let edition = match edition.parse() {
@@ -534,11 +1013,13 @@ fn main_args(args: &[String]) -> isize {
     match (should_test, markdown_input) {
         (true, true) => {
             return markdown::test(input, cfgs, libs, externs, test_args, maybe_sysroot,
-                                  display_warnings, linker, edition, cg, &diag)
+                                  display_warnings, linker, edition, cg, persist_doctests,
+                                  test_builder, doctest_runner, &diag)
         }
         (true, false) => {
             return test::run(Path::new(input), cfgs, libs, externs, test_args, crate_name,
-                             maybe_sysroot, display_warnings, linker, edition, cg)
+                             maybe_sysroot, display_warnings, linker, edition, cg,
+                             persist_doctests, test_builder, doctest_runner)
         }
         (false, true) => return markdown::render(Path::new(input),
                                                  output.unwrap_or(PathBuf::from("doc")),
@@ -548,24 +1029,97 @@ fn main_args(args: &[String]) -> isize {
     }
 
     let output_format = matches.opt_str("w");
+    let show_coverage = matches.opt_present("show-coverage");
+
+    let doc_options = core::DocOptions {
+        destructured_args,
+        generate_link_to_definition: matches.opt_present("generate-link-to-definition"),
+        bounds_style,
+        expand_type_aliases: matches.opt_present("expand-type-aliases"),
+        document_unstable_impls: matches.opt_present("document-unstable-impls"),
+        masked_crate_names: matches.opt_strs("mask-crate"),
+        render_macro_expansion: matches.opt_present("render-macro-expansion"),
+    };
+    let render_options = html::render::RenderOptions {
+        favicon_url,
+        logo_url,
+        include_sources,
+        show_async_desugaring,
+        single_page,
+        extern_locations,
+        summary_length,
+        inline_search_index,
+        shown_attributes,
+        compact_sidebar,
+        emit_metadata_only,
+        no_search,
+        crate_features,
+        link_summaries,
+        detect_sealed_traits,
+        impl_order,
+        css_class_prefix,
+        emit_item_json,
+        trim_auto_traits,
+        annotate_phantom_data,
+        html_root_path,
+        trait_impls_graph,
+        show_private_field_names,
+        redirect_map,
+    };
 
     let res = acquire_input(PathBuf::from(input), externs, edition, cg, &matches, error_format,
-                            move |out| {
-        let Output { krate, passes, renderinfo } = out;
+                            doc_options, move |out| {
+        let Output { mut krate, passes, renderinfo, has_errors } = out;
         let diag = core::new_handler(error_format, None);
+        if let Some(ref changed_files) = changed_files {
+            krate = passes::strip_unchanged(krate, changed_files, &diag);
+        }
+        // Everything up to the rendering, including the passes which resolve the intra-doc
+        // links, has run and reported its warnings by now.
+        if check {
+            return if has_errors || diag.has_errors() { 1 } else { 0 };
+        }
+        if show_coverage {
+            return match output_format.as_ref().map(|s| &**s) {
+                Some("json") => { coverage::print(krate, true); 0 }
+                None => { coverage::print(krate, false); 0 }
+                Some(s) => {
+                    diag.struct_err(&format!("unknown output format for --show-coverage: {}", s))
+                        .emit();
+                    1
+                }
+            };
+        }
         info!("going to format");
         match output_format.as_ref().map(|s| &**s) {
             Some("html") | None => {
+                let output = output.unwrap_or(PathBuf::from("doc"));
+                let crate_name = krate.name.clone();
                 html::render::run(krate, &external_html, playground_url,
-                                  output.unwrap_or(PathBuf::from("doc")),
+                                  output.clone(),
                                   resource_suffix.unwrap_or(String::new()),
                                   passes.into_iter().collect(),
                                   css_file_extension,
                                   renderinfo,
                                   sort_modules_alphabetically,
                                   themes,
-                                  enable_minification, id_map)
+                                  enable_minification,
+                                  render_options,
+                                  id_map)
                     .expect("failed to generate documentation");
+                if verify_anchors {
+                    let dead_anchors = html::verify_anchors::check(&output, &crate_name)
+                        .expect("failed to read the generated documentation");
+                    for dead_anchor in &dead_anchors {
+                        diag.struct_err(&format!("dead link to `{}` in `{}`",
+                                                 dead_anchor.href,
+                                                 dead_anchor.page.display()))
+                            .emit();
+                    }
+                    if !dead_anchors.is_empty() {
+                        return 1;
+                    }
+                }
                 0
             }
             Some(s) => {
@@ -588,13 +1142,16 @@ fn acquire_input<R, F>(input: PathBuf,
                        cg: CodegenOptions,
                        matches: &getopts::Matches,
                        error_format: ErrorOutputType,
+                       doc_options: core::DocOptions,
                        f: F)
                        -> Result<R, String>
 where R: 'static + Send, F: 'static + Send + FnOnce(Output) -> R {
     match matches.opt_str("r").as_ref().map(|s| &**s) {
-        Some("rust") => Ok(rust_input(input, externs, edition, cg, matches, error_format, f)),
+        Some("rust") => {
+            Ok(rust_input(input, externs, edition, cg, matches, error_format, doc_options, f))
+        }
         Some(s) => Err(format!("unknown input format: {}", s)),
-        None => Ok(rust_input(input, externs, edition, cg, matches, error_format, f))
+        None => Ok(rust_input(input, externs, edition, cg, matches, error_format, doc_options, f))
     }
 }
 
@@ -615,6 +1172,92 @@ fn parse_externs(matches: &getopts::Matches) -> Result<Externs, String> {
     Ok(Externs::new(externs))
 }
 
+/// Reads the `--extern-locations FILE` argument, made of `crate = url` lines, and
+/// returns a map mapping crate names to the root of their documentation or else an
+/// error message.
+fn parse_extern_locations(matches: &getopts::Matches) -> Result<BTreeMap<String, String>, String> {
+    let mut locations = BTreeMap::new();
+    let path = match matches.opt_str("extern-locations") {
+        Some(path) => path,
+        None => return Ok(locations),
+    };
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("couldn't read extern locations file \"{}\": {}", path, e))?;
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.splitn(2, '=').map(str::trim);
+        match (parts.next(), parts.next()) {
+            (Some(name), Some(url)) if !name.is_empty() && !url.is_empty() => {
+                locations.insert(name.to_string(), url.to_string());
+            }
+            _ => return Err(format!("{}:{}: extern location must be of the format `crate = url`",
+                                    path, i + 1)),
+        }
+    }
+    Ok(locations)
+}
+
+/// Collects the source files changed since the `--since GITREF` revision, as told by `git diff`,
+/// or listed by the `--changed-files FILE` argument, and returns them as canonical paths or else
+/// an error message. `None` is returned when all the items are to be documented.
+fn parse_changed_files(matches: &getopts::Matches,
+                       input: &Path) -> Result<Option<FxHashSet<PathBuf>>, String> {
+    let (base, listed) = match (matches.opt_str("since"), matches.opt_str("changed-files")) {
+        (Some(_), Some(_)) => {
+            return Err("--since and --changed-files can't be used together".to_string());
+        }
+        (Some(gitref), None) => {
+            // `git diff` gives the paths relative to the root of the repository.
+            let dir = match input.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => Path::new("."),
+            };
+            let git = |args: &[&str]| -> Result<String, String> {
+                let output = process::Command::new("git")
+                    .arg("-C").arg(dir)
+                    .args(args)
+                    .output()
+                    .map_err(|e| format!("couldn't run git: {}", e))?;
+                if !output.status.success() {
+                    return Err(format!("couldn't find the files changed since `{}`: {}",
+                                       gitref,
+                                       String::from_utf8_lossy(&output.stderr).trim()));
+                }
+                Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+            };
+            let root = git(&["rev-parse", "--show-toplevel"])?;
+            (PathBuf::from(root.trim()), git(&["diff", "--name-only", &gitref[..]])?)
+        }
+        (None, Some(path)) => {
+            // The listed paths are relative to the directory of the list.
+            let contents = fs::read_to_string(&path)
+                .map_err(|e| format!("couldn't read changed files list \"{}\": {}", path, e))?;
+            let base = Path::new(&path).parent().map(Path::to_path_buf).unwrap_or_default();
+            (base, contents)
+        }
+        (None, None) => return Ok(None),
+    };
+
+    // The files which don't exist anymore can't define anything.
+    Ok(Some(listed.lines()
+                  .map(str::trim)
+                  .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                  .filter_map(|line| fs::canonicalize(base.join(line)).ok())
+                  .collect()))
+}
+
+/// Reads the `--target` flag, which names either a built-in target or a JSON specification.
+fn parse_target(target: String) -> TargetTriple {
+    if target.ends_with(".json") {
+        TargetTriple::TargetPath(PathBuf::from(target))
+    } else {
+        TargetTriple::TargetTriple(target)
+    }
+}
+
 /// Interprets the input file as a rust source file, passing it through the
 /// compiler all the way through the analysis passes. The rustdoc output is then
 /// generated from the cleaned AST of the crate.
@@ -626,6 +1269,7 @@ fn rust_input<R, F>(cratefile: PathBuf,
                     cg: CodegenOptions,
                     matches: &getopts::Matches,
                     error_format: ErrorOutputType,
+                    doc_options: core::DocOptions,
                     f: F) -> R
 where R: 'static + Send,
       F: 'static + Send + FnOnce(Output) -> R
@@ -647,17 +1291,12 @@ where R: 'static + Send,
         paths.add_path(s, ErrorOutputType::default());
     }
     let cfgs = matches.opt_strs("cfg");
-    let triple = matches.opt_str("target").map(|target| {
-        if target.ends_with(".json") {
-            TargetTriple::TargetPath(PathBuf::from(target))
-        } else {
-            TargetTriple::TargetTriple(target)
-        }
-    });
+    let triple = matches.opt_str("target").map(parse_target);
     let maybe_sysroot = matches.opt_str("sysroot").map(PathBuf::from);
     let crate_name = matches.opt_str("crate-name");
     let crate_version = matches.opt_str("crate-version");
     let plugin_path = matches.opt_str("plugin-path");
+    let excluded_modules = matches.opt_strs("exclude-module");
 
     info!("starting to run rustc");
     let display_warnings = matches.opt_present("display-warnings");
@@ -665,6 +1304,9 @@ where R: 'static + Send,
     let force_unstable_if_unmarked = matches.opt_strs("Z").iter().any(|x| {
         *x == "force-unstable-if-unmarked"
     });
+    let doc_redundant_summary = matches.opt_strs("Z").iter().any(|x| {
+        *x == "doc-redundant-summary"
+    });
 
     let (lint_opts, describe_lints, lint_cap) = get_cmd_lint_options(matches, error_format);
 
@@ -673,11 +1315,11 @@ where R: 'static + Send,
     let result = rustc_driver::monitor(move || syntax::with_globals(move || {
         use rustc::session::config::Input;
 
-        let (mut krate, renderinfo) =
+        let (mut krate, renderinfo, has_errors) =
             core::run_core(paths, cfgs, externs, Input::File(cratefile), triple, maybe_sysroot,
                            display_warnings, crate_name.clone(),
                            force_unstable_if_unmarked, edition, cg, error_format,
-                           lint_opts, lint_cap, describe_lints);
+                           lint_opts, lint_cap, describe_lints, doc_options);
 
         info!("finished with rustc");
 
@@ -764,7 +1406,18 @@ where R: 'static + Send,
             krate = pass(krate);
         }
 
-        tx.send(f(Output { krate: krate, renderinfo: renderinfo, passes: passes })).unwrap();
+        krate = passes::strip_modules(krate, &excluded_modules, &diag);
+
+        if doc_redundant_summary {
+            krate = passes::check_redundant_summary(krate, &diag);
+        }
+
+        tx.send(f(Output {
+            krate: krate,
+            renderinfo: renderinfo,
+            passes: passes,
+            has_errors: has_errors || diag.has_errors(),
+        })).unwrap();
     }));
 
     match result {
@@ -0,0 +1,55 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use errors;
+
+use clean::{self, Item};
+use fold::DocFolder;
+
+/// Warns about the items whose summary, the first line of their documentation, only restates
+/// their name, like `/// Foo.` on `struct Foo`, as asked with `-Z doc-redundant-summary`.
+pub fn check_redundant_summary(krate: clean::Crate, diag: &errors::Handler) -> clean::Crate {
+    let mut checker = RedundantSummaryChecker { diag };
+    checker.fold_crate(krate)
+}
+
+/// Whether `summary` is nothing more than `name`, give or take the case, backticks and a final
+/// period.
+fn is_redundant(summary: &str, name: &str) -> bool {
+    let summary = summary.trim().trim_right_matches('.').replace('`', "");
+    summary.trim().eq_ignore_ascii_case(name)
+}
+
+struct RedundantSummaryChecker<'a> {
+    diag: &'a errors::Handler,
+}
+
+impl<'a> DocFolder for RedundantSummaryChecker<'a> {
+    fn fold_item(&mut self, i: Item) -> Option<Item> {
+        match i.inner {
+            // Nothing is rendered for the stripped items, and the impls have no name.
+            clean::StrippedItem(..) | clean::ImplItem(..) => return self.fold_item_recur(i),
+            _ => {}
+        }
+        if i.def_id.is_local() {
+            if let (Some(name), Some(doc)) = (i.name.as_ref(), i.doc_value()) {
+                if is_redundant(doc.lines().next().unwrap_or(""), name) {
+                    self.diag.struct_warn(&format!("the documentation of `{}` only restates \
+                                                    its name", name))
+                             .note(&format!("documented at {}:{}",
+                                            i.source.filename, i.source.loline))
+                             .help("describe what the item is for in its first line")
+                             .emit();
+                }
+            }
+        }
+        self.fold_item_recur(i)
+    }
+}
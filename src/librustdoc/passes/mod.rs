@@ -17,6 +17,9 @@ use clean::{self, GetDefId, Item};
 use fold;
 use fold::StripItem;
 
+mod check_redundant_summary;
+pub use self::check_redundant_summary::check_redundant_summary;
+
 mod collapse_docs;
 pub use self::collapse_docs::collapse_docs;
 
@@ -29,6 +32,12 @@ pub use self::strip_private::strip_private;
 mod strip_priv_imports;
 pub use self::strip_priv_imports::strip_priv_imports;
 
+mod strip_modules;
+pub use self::strip_modules::strip_modules;
+
+mod strip_unchanged;
+pub use self::strip_unchanged::strip_unchanged;
+
 mod unindent_comments;
 pub use self::unindent_comments::unindent_comments;
 
@@ -130,6 +139,7 @@ impl<'a> fold::DocFolder for Stripper<'a> {
             | clean::StructItem(..)
             | clean::EnumItem(..)
             | clean::TraitItem(..)
+            | clean::TraitAliasItem(..)
             | clean::FunctionItem(..)
             | clean::VariantItem(..)
             | clean::MethodItem(..)
@@ -177,6 +187,9 @@ impl<'a> fold::DocFolder for Stripper<'a> {
 
             // Keywords are never stripped
             clean::KeywordItem(..) => {}
+
+            // Proc-macros are always public
+            clean::ProcMacroItem(..) => {}
         }
 
         let fastreturn = match i.inner {
@@ -0,0 +1,144 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rustc::hir::def_id::DefId;
+use rustc::util::nodemap::{DefIdSet, FxHashMap, FxHashSet};
+use std::mem;
+
+use errors;
+
+use clean::{self, Item};
+use fold::{DocFolder, StripItem};
+use passes::ImplStripper;
+
+/// Strips the modules given with `--exclude-module`, along with everything they contain.
+///
+/// The paths are relative to the crate root, like `path::to::module`. A warning is emitted for the
+/// paths which don't match any module, and for the re-exports reaching into an excluded module.
+pub fn strip_modules(krate: clean::Crate,
+                     excluded: &[String],
+                     diag: &errors::Handler) -> clean::Crate {
+    if excluded.is_empty() {
+        return krate;
+    }
+
+    let mut retained = DefIdSet();
+    let mut stripped = FxHashMap();
+    let mut found = FxHashSet();
+
+    // Strip the excluded modules, sorting the items into those kept and those stripped with them
+    let krate = {
+        let mut stripper = Stripper {
+            excluded,
+            current: Vec::new(),
+            in_root: true,
+            excluded_module: None,
+            retained: &mut retained,
+            stripped: &mut stripped,
+            found: &mut found,
+        };
+        stripper.fold_crate(krate)
+    };
+
+    for path in excluded.iter().filter(|path| !found.contains(*path)) {
+        diag.warn(&format!("module `{}` given to `--exclude-module` was not found", path));
+    }
+
+    let krate = {
+        let mut checker = ReexportChecker { stripped: &stripped, diag };
+        checker.fold_crate(krate)
+    };
+
+    // strip all impls referencing stripped items
+    let mut stripper = ImplStripper { retained: &retained };
+    stripper.fold_crate(krate)
+}
+
+struct Stripper<'a> {
+    excluded: &'a [String],
+    /// The path of the module being folded, relative to the crate root.
+    current: Vec<String>,
+    in_root: bool,
+    /// The path of the excluded module being folded, if any.
+    excluded_module: Option<String>,
+    retained: &'a mut DefIdSet,
+    /// The items of the excluded modules, mapped to the path of their module.
+    stripped: &'a mut FxHashMap<DefId, String>,
+    found: &'a mut FxHashSet<String>,
+}
+
+impl<'a> DocFolder for Stripper<'a> {
+    fn fold_item(&mut self, i: Item) -> Option<Item> {
+        // The crate root isn't part of the paths.
+        let is_module = i.is_mod() && !mem::replace(&mut self.in_root, false);
+        if is_module {
+            self.current.push(i.name.clone().unwrap_or_default());
+            let path = self.current.join("::");
+            if self.excluded_module.is_none() && self.excluded.contains(&path) {
+                debug!("stripping excluded module {}", path);
+                self.found.insert(path.clone());
+                self.stripped.insert(i.def_id, path.clone());
+                // We need to recurse into stripped modules to strip things like impl methods,
+                // and to know which items they contain.
+                self.excluded_module = Some(path);
+                let ret = StripItem(self.fold_item_recur(i).unwrap()).strip();
+                self.excluded_module = None;
+                self.current.pop();
+                return ret;
+            }
+        }
+
+        match self.excluded_module {
+            Some(ref module) => {
+                self.stripped.insert(i.def_id, module.clone());
+            }
+            None => {
+                self.retained.insert(i.def_id);
+            }
+        }
+        let ret = self.fold_item_recur(i);
+        if is_module {
+            self.current.pop();
+        }
+        ret
+    }
+}
+
+/// Warns about the re-exports of the items of the excluded modules.
+struct ReexportChecker<'a> {
+    stripped: &'a FxHashMap<DefId, String>,
+    diag: &'a errors::Handler,
+}
+
+impl<'a> DocFolder for ReexportChecker<'a> {
+    fn fold_item(&mut self, i: Item) -> Option<Item> {
+        let (name, module) = match i.inner {
+            // The content of the excluded modules is not rendered.
+            clean::StrippedItem(box clean::ModuleItem(..)) => return Some(i),
+            clean::ImportItem(clean::Import::Simple(_, ref source)) |
+            clean::ImportItem(clean::Import::Glob(ref source)) => {
+                let path = source.path.segments.iter()
+                                               .map(|segment| &*segment.name)
+                                               .collect::<Vec<_>>()
+                                               .join("::");
+                (path, source.did.and_then(|did| self.stripped.get(&did)))
+            }
+            // Items inlined from an excluded module.
+            _ => (i.name.clone().unwrap_or_default(), self.stripped.get(&i.def_id)),
+        };
+        if let Some(module) = module {
+            self.diag.struct_warn(&format!("`{}` is re-exported from the excluded module `{}`",
+                                           name, module))
+                     .note(&format!("re-exported at {}:{}", i.source.filename, i.source.loline))
+                     .emit();
+        }
+        self.fold_item_recur(i)
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rustc::util::nodemap::{DefIdSet, FxHashMap, FxHashSet};
+use std::fs;
+use std::mem;
+use std::path::PathBuf;
+use syntax_pos::FileName;
+
+use errors;
+
+use clean::{self, Item};
+use fold::{DocFolder, StripItem};
+use passes::ImplStripper;
+
+/// Strips the items which aren't defined in one of the given files, as given by `--since` or
+/// `--changed-files`.
+///
+/// The modules are kept as long as they contain an item which is kept. The impls are kept along
+/// with the types and traits they're for, wherever they're defined.
+pub fn strip_unchanged(krate: clean::Crate,
+                       changed: &FxHashSet<PathBuf>,
+                       diag: &errors::Handler) -> clean::Crate {
+    let mut retained = DefIdSet();
+
+    // strip the items of the unchanged files
+    let krate = {
+        let mut stripper = Stripper {
+            changed,
+            canonical: FxHashMap(),
+            in_root: true,
+            retained: &mut retained,
+        };
+        stripper.fold_crate(krate)
+    };
+
+    if retained.is_empty() {
+        diag.warn("none of the changed files define any documented item");
+    }
+
+    // strip all impls referencing stripped items
+    let mut stripper = ImplStripper { retained: &retained };
+    stripper.fold_crate(krate)
+}
+
+struct Stripper<'a> {
+    changed: &'a FxHashSet<PathBuf>,
+    /// Whether each source file of the crate is one of the changed files.
+    canonical: FxHashMap<PathBuf, bool>,
+    in_root: bool,
+    retained: &'a mut DefIdSet,
+}
+
+impl<'a> Stripper<'a> {
+    fn is_changed(&mut self, filename: &FileName) -> bool {
+        let path = match *filename {
+            FileName::Real(ref path) => path,
+            _ => return false,
+        };
+        let changed = self.changed;
+        *self.canonical.entry(path.clone()).or_insert_with(|| {
+            fs::canonicalize(path).map_or(false, |path| changed.contains(&path))
+        })
+    }
+}
+
+impl<'a> DocFolder for Stripper<'a> {
+    fn fold_item(&mut self, i: Item) -> Option<Item> {
+        match i.inner {
+            // The impls are stripped along with the items they're for.
+            clean::ImplItem(..) | clean::StrippedItem(..) => return Some(i),
+            clean::ModuleItem(..) => {}
+            _ => {
+                if !self.is_changed(&i.source.filename) {
+                    debug!("stripping unchanged item {:?}", i.name);
+                    return None;
+                }
+                // The content of an item is defined along with it.
+                self.retained.insert(i.def_id);
+                return Some(i);
+            }
+        }
+
+        // The crate root is always kept.
+        let in_root = mem::replace(&mut self.in_root, false);
+        let changed = self.is_changed(&i.source.filename);
+        let i = self.fold_item_recur(i).unwrap();
+        let kept = match i.inner {
+            clean::ModuleItem(ref m) => m.items.iter().any(|item| match item.inner {
+                clean::ImplItem(..) | clean::StrippedItem(..) => false,
+                _ => true,
+            }),
+            _ => false,
+        };
+        if in_root || changed || kept {
+            if changed || kept {
+                self.retained.insert(i.def_id);
+            }
+            Some(i)
+        } else {
+            debug!("stripping unchanged module {:?}", i.name);
+            StripItem(i).strip()
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Collects the paths written in the source code of the crate along with the items they resolve
+//! to, so that with `--generate-link-to-definition` the source pages can link them to the
+//! documentation of those items, or to the source of the items from other crates.
+
+use rustc::hir::{self, intravisit};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{NestedVisitorMap, Visitor};
+use rustc::ty::TyCtxt;
+use rustc::util::nodemap::FxHashMap;
+use syntax::ast::NodeId;
+use syntax_pos::{FileName, Span};
+use syntax_pos::hygiene::SyntaxContext;
+
+use std::path::PathBuf;
+
+#[derive(Default)]
+pub struct SpanMap {
+    /// For each source file, maps the byte range of a path segment to the item it refers to.
+    pub paths: FxHashMap<PathBuf, FxHashMap<(u32, u32), DefId>>,
+    /// The file and lines where each item of another crate referred to by `paths` is defined.
+    pub extern_defs: FxHashMap<DefId, (PathBuf, usize, usize)>,
+}
+
+/// Walks the whole HIR of the local crate to build its `SpanMap`.
+pub fn collect<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>) -> SpanMap {
+    let mut collector = SpanMapCollector {
+        tcx,
+        map: SpanMap::default(),
+    };
+    intravisit::walk_crate(&mut collector, tcx.hir.krate());
+    collector.map
+}
+
+struct SpanMapCollector<'a, 'tcx: 'a> {
+    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    map: SpanMap,
+}
+
+impl<'a, 'tcx> SpanMapCollector<'a, 'tcx> {
+    fn record(&mut self, span: Span, did: DefId) {
+        // Paths coming from macro expansions don't appear as such in the source.
+        if span.ctxt() != SyntaxContext::empty() {
+            return;
+        }
+        let lo = self.tcx.sess.codemap().lookup_byte_offset(span.lo());
+        let hi = self.tcx.sess.codemap().lookup_byte_offset(span.hi());
+        let path = match lo.fm.name {
+            FileName::Real(ref path) => path.clone(),
+            _ => return,
+        };
+        self.map.paths.entry(path)
+                      .or_insert_with(FxHashMap)
+                      .insert((lo.pos.0, hi.pos.0), did);
+
+        // The spans of the items of other crates point into the files imported along with their
+        // metadata, which keep the original path of the file.
+        if !did.is_local() && !self.map.extern_defs.contains_key(&did) {
+            let def_span = self.tcx.def_span(did);
+            let lo = self.tcx.sess.codemap().lookup_char_pos(def_span.lo());
+            let hi = self.tcx.sess.codemap().lookup_char_pos(def_span.hi());
+            if let FileName::Real(ref path) = lo.file.name {
+                self.map.extern_defs.insert(did, (path.clone(), lo.line, hi.line));
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for SpanMapCollector<'a, 'tcx> {
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::All(&self.tcx.hir)
+    }
+
+    fn visit_path(&mut self, path: &'tcx hir::Path, _id: NodeId) {
+        match path.def {
+            Def::Mod(did) | Def::Struct(did) | Def::Union(did) | Def::Enum(did) |
+            Def::TyAlias(did) | Def::Trait(did) | Def::TyForeign(did) | Def::Fn(did) |
+            Def::Const(did) | Def::Static(did, _) | Def::Macro(did, _) => {
+                if let Some(segment) = path.segments.last() {
+                    self.record(segment.ident.span, did);
+                }
+            }
+            _ => {}
+        }
+        intravisit::walk_path(self, path);
+    }
+}
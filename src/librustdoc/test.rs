@@ -10,11 +10,12 @@
 
 use std::env;
 use std::ffi::OsString;
+use std::fs;
 use std::io::prelude::*;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::panic::{self, AssertUnwindSafe};
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::str;
 use rustc_data_structures::sync::Lrc;
 use std::sync::{Arc, Mutex};
@@ -25,10 +26,12 @@ use rustc::hir;
 use rustc::hir::intravisit;
 use rustc::session::{self, CompileIncomplete, config};
 use rustc::session::config::{OutputType, OutputTypes, Externs, CodegenOptions};
+use rustc::session::filesearch;
 use rustc::session::search_paths::{SearchPaths, PathKind};
 use rustc_metadata::dynamic_lib::DynamicLibrary;
-use tempfile::Builder as TempFileBuilder;
-use rustc_driver::{self, driver, target_features, Compilation};
+use rustc_target::spec::TargetTriple;
+use tempfile::{Builder as TempFileBuilder, TempDir};
+use rustc_driver::{self, driver, Compilation};
 use rustc_driver::driver::phase_2_configure_and_expand;
 use rustc_metadata::cstore::CStore;
 use rustc_resolve::MakeGlobMap;
@@ -65,7 +68,10 @@ pub fn run(input_path: &Path,
            display_warnings: bool,
            linker: Option<PathBuf>,
            edition: Edition,
-           cg: CodegenOptions)
+           cg: CodegenOptions,
+           persist_doctests: Option<PathBuf>,
+           test_builder: Option<TestBuilder>,
+           doctest_runner: Option<DoctestRunner>)
            -> isize {
     let input = config::Input::File(input_path.to_owned());
 
@@ -99,9 +105,7 @@ pub fn run(input_path: &Path,
         let cstore = CStore::new(codegen_backend.metadata_loader());
         rustc_lint::register_builtins(&mut sess.lint_store.borrow_mut(), Some(&sess));
 
-        let mut cfg = config::build_configuration(&sess, config::parse_cfgspecs(cfgs.clone()));
-        target_features::add_configuration(&mut cfg, &sess, &*codegen_backend);
-        sess.parse_sess.config = cfg;
+        sess.parse_sess.config = ::core::crate_config(&sess, cfgs.clone(), &*codegen_backend);
 
         let krate = panictry!(driver::phase_1_parse_input(&driver::CompileController::basic(),
                                                         &sess,
@@ -136,7 +140,10 @@ pub fn run(input_path: &Path,
             Some(codemap),
              None,
             linker,
-            edition
+            edition,
+            persist_doctests,
+            test_builder,
+            doctest_runner,
         );
 
         {
@@ -195,15 +202,70 @@ fn scrape_test_config(krate: &::rustc::hir::Crate) -> TestOptions {
     opts
 }
 
+/// A compiler wrapper given through `--test-builder`, which builds the doctests instead of
+/// rustdoc compiling them itself.
+#[derive(Clone)]
+pub struct TestBuilder {
+    pub path: PathBuf,
+    /// The `-C` options given to rustdoc, which are passed on to the wrapper.
+    pub codegen_args: Vec<String>,
+}
+
+/// A command given through `--doctest-runner`, which runs the doctests instead of rustdoc
+/// running their executables itself.
+#[derive(Clone)]
+pub struct DoctestRunner {
+    pub command: PathBuf,
+    /// The `--target` given to rustdoc, which the doctests are then compiled for.
+    pub target: Option<TargetTriple>,
+}
+
+/// Where the artifacts of a doctest are written to.
+enum DirState {
+    /// A temporary directory, removed once the doctest is done.
+    Temp(TempDir),
+    /// A directory given through `--persist-doctests`, kept around afterwards.
+    Perm(PathBuf),
+}
+
+impl DirState {
+    fn path(&self) -> &Path {
+        match *self {
+            DirState::Temp(ref t) => t.path(),
+            DirState::Perm(ref p) => p.as_path(),
+        }
+    }
+}
+
 fn run_test(test: &str, cratename: &str, filename: &FileName, line: usize,
             cfgs: Vec<String>, libs: SearchPaths,
             cg: CodegenOptions, externs: Externs,
             should_panic: bool, no_run: bool, as_test_harness: bool,
             compile_fail: bool, mut error_codes: Vec<String>, opts: &TestOptions,
-            maybe_sysroot: Option<PathBuf>, linker: Option<PathBuf>, edition: Edition) {
+            maybe_sysroot: Option<PathBuf>, linker: Option<PathBuf>, edition: Edition,
+            persist_doctests: Option<PathBuf>, test_builder: Option<TestBuilder>,
+            doctest_runner: Option<DoctestRunner>) {
     // the test harness wants its own `main` & top level functions, so
     // never wrap the test in `fn main() { ... }`
     let (test, line_offset) = make_test(test, Some(cratename), as_test_harness, opts);
+    let outdir = if let Some(mut path) = persist_doctests {
+        // The whole path of the file goes in the name, as files of different directories often
+        // share theirs.
+        let name = filename.to_string().replace(|c: char| c == '/' || c == '\\' || c == '.', "_");
+        path.push(format!("{}_{}", name, line));
+        if let Err(e) = fs::create_dir_all(&path) {
+            panic!("couldn't create the directory for the doctest `{}`: {}", path.display(), e);
+        }
+        // Keep the assembled source next to the executable so it can be inspected or rebuilt.
+        if let Err(e) = fs::write(path.join("doctest.rs"), &test) {
+            panic!("couldn't write the source of the doctest into `{}`: {}", path.display(), e);
+        }
+        DirState::Perm(path)
+    } else {
+        DirState::Temp(
+            TempFileBuilder::new().prefix("rustdoctest").tempdir().expect("rustdoc needs a tempdir")
+        )
+    };
     // FIXME(#44940): if doctests ever support path remapping, then this filename
     // needs to be the result of CodeMap::span_to_unmapped_path
     let input = config::Input::Str {
@@ -230,6 +292,11 @@ fn run_test(test: &str, cratename: &str, filename: &FileName, line: usize,
             ..config::basic_debugging_options()
         },
         edition,
+        target_triple: doctest_runner.as_ref()
+                                     .and_then(|runner| runner.target.clone())
+                                     .unwrap_or_else(|| {
+                                         TargetTriple::from_triple(config::host_triple())
+                                     }),
         ..config::Options::default()
     };
 
@@ -261,62 +328,61 @@ fn run_test(test: &str, cratename: &str, filename: &FileName, line: usize,
     let old = io::set_panic(Some(box Sink(data.clone())));
     let _bomb = Bomb(data.clone(), old.unwrap_or(box io::stdout()));
 
-    let (libdir, outdir, compile_result) = driver::spawn_thread_pool(sessopts, |sessopts| {
-        let codemap = Lrc::new(CodeMap::new_doctest(
-            sessopts.file_path_mapping(), filename.clone(), line as isize - line_offset as isize
-        ));
-        let emitter = errors::emitter::EmitterWriter::new(box Sink(data.clone()),
-                                                        Some(codemap.clone()),
-                                                        false,
-                                                        false);
-
-        // Compile the code
-        let diagnostic_handler = errors::Handler::with_emitter(true, false, box emitter);
-
-        let mut sess = session::build_session_(
-            sessopts, None, diagnostic_handler, codemap,
-        );
-        let codegen_backend = rustc_driver::get_codegen_backend(&sess);
-        let cstore = CStore::new(codegen_backend.metadata_loader());
-        rustc_lint::register_builtins(&mut sess.lint_store.borrow_mut(), Some(&sess));
-
-        let outdir = Mutex::new(
-            TempFileBuilder::new().prefix("rustdoctest").tempdir().expect("rustdoc needs a tempdir")
-        );
-        let libdir = sess.target_filesearch(PathKind::All).get_lib_path();
-        let mut control = driver::CompileController::basic();
-
-        let mut cfg = config::build_configuration(&sess, config::parse_cfgspecs(cfgs.clone()));
-        target_features::add_configuration(&mut cfg, &sess, &*codegen_backend);
-        sess.parse_sess.config = cfg;
-
-        let out = Some(outdir.lock().unwrap().path().to_path_buf());
-
-        if no_run {
-            control.after_analysis.stop = Compilation::Stop;
-        }
-
-        let res = panic::catch_unwind(AssertUnwindSafe(|| {
-            driver::compile_input(
-                codegen_backend,
-                &sess,
-                &cstore,
-                &None,
-                &input,
-                &out,
-                &None,
-                None,
-                &control
-            )
-        }));
+    let (libdir, compile_result) = if let Some(ref builder) = test_builder {
+        build_with_wrapper(builder, &test, &sessopts, &cfgs, outdir.path(), no_run, &data)
+    } else {
+        driver::spawn_thread_pool(sessopts, |sessopts| {
+            let codemap = Lrc::new(CodeMap::new_doctest(
+                sessopts.file_path_mapping(), filename.clone(), line as isize - line_offset as isize
+            ));
+            let emitter = errors::emitter::EmitterWriter::new(box Sink(data.clone()),
+                                                              Some(codemap.clone()),
+                                                              false,
+                                                              false);
+
+            // Compile the code
+            let diagnostic_handler = errors::Handler::with_emitter(true, false, box emitter);
+
+            let mut sess = session::build_session_(
+                sessopts, None, diagnostic_handler, codemap,
+            );
+            let codegen_backend = rustc_driver::get_codegen_backend(&sess);
+            let cstore = CStore::new(codegen_backend.metadata_loader());
+            rustc_lint::register_builtins(&mut sess.lint_store.borrow_mut(), Some(&sess));
+
+            let libdir = sess.target_filesearch(PathKind::All).get_lib_path();
+            let mut control = driver::CompileController::basic();
+
+            sess.parse_sess.config = ::core::crate_config(&sess, cfgs.clone(), &*codegen_backend);
+
+            let out = Some(outdir.path().to_path_buf());
+
+            if no_run {
+                control.after_analysis.stop = Compilation::Stop;
+            }
 
-        let compile_result = match res {
-            Ok(Ok(())) | Ok(Err(CompileIncomplete::Stopped)) => Ok(()),
-            Err(_) | Ok(Err(CompileIncomplete::Errored(_))) => Err(())
-        };
+            let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                driver::compile_input(
+                    codegen_backend,
+                    &sess,
+                    &cstore,
+                    &None,
+                    &input,
+                    &out,
+                    &None,
+                    None,
+                    &control
+                )
+            }));
+
+            let compile_result = match res {
+                Ok(Ok(())) | Ok(Err(CompileIncomplete::Stopped)) => Ok(()),
+                Err(_) | Ok(Err(CompileIncomplete::Errored(_))) => Err(())
+            };
 
-        (libdir, outdir, compile_result)
-    });
+            (libdir, compile_result)
+        })
+    };
 
     match (compile_result, compile_fail) {
         (Ok(()), true) => {
@@ -346,7 +412,14 @@ fn run_test(test: &str, cratename: &str, filename: &FileName, line: usize,
     // environment to ensure that the target loads the right libraries at
     // runtime. It would be a sad day if the *host* libraries were loaded as a
     // mistake.
-    let mut cmd = Command::new(&outdir.lock().unwrap().path().join("rust_out"));
+    let mut cmd = match doctest_runner {
+        Some(ref runner) => {
+            let mut cmd = Command::new(&runner.command);
+            cmd.arg(outdir.path().join("rust_out"));
+            cmd
+        }
+        None => Command::new(&outdir.path().join("rust_out")),
+    };
     let var = DynamicLibrary::envvar();
     let newpath = {
         let path = env::var_os(var).unwrap_or(OsString::new());
@@ -373,6 +446,71 @@ fn run_test(test: &str, cratename: &str, filename: &FileName, line: usize,
     }
 }
 
+/// Builds the doctest `test` with the `--test-builder` wrapper, passing it the arguments rustdoc
+/// would compile the doctest with. The source is given on the standard input, and the wrapper's
+/// error output is appended to `stderr`.
+fn build_with_wrapper(builder: &TestBuilder, test: &str, sessopts: &config::Options,
+                      cfgs: &[String], outdir: &Path, no_run: bool,
+                      stderr: &Mutex<Vec<u8>>) -> (PathBuf, Result<(), ()>) {
+    let sysroot = sessopts.maybe_sysroot.clone().expect("doctests always have a sysroot");
+    let mut cmd = Command::new(&builder.path);
+    if sessopts.test {
+        cmd.arg("--test");
+    } else {
+        cmd.args(&["--crate-type", "bin"]);
+    }
+    cmd.arg("--edition").arg(sessopts.edition.to_string());
+    match sessopts.target_triple {
+        TargetTriple::TargetTriple(ref triple) => cmd.arg("--target").arg(triple),
+        TargetTriple::TargetPath(ref path) => cmd.arg("--target").arg(path),
+    };
+    cmd.arg("--sysroot").arg(&sysroot);
+    for cfg in cfgs {
+        cmd.arg("--cfg").arg(cfg);
+    }
+    for (path, kind) in sessopts.search_paths.iter(PathKind::All) {
+        let prefix = match kind {
+            PathKind::Native => "native=",
+            PathKind::Crate => "crate=",
+            PathKind::Dependency => "dependency=",
+            PathKind::Framework => "framework=",
+            PathKind::ExternFlag | PathKind::All => "",
+        };
+        cmd.arg("-L").arg(format!("{}{}", prefix, path.display()));
+    }
+    for (name, locations) in sessopts.externs.iter() {
+        for location in locations {
+            cmd.arg("--extern").arg(format!("{}={}", name, location));
+        }
+    }
+    cmd.args(&["-C", "prefer-dynamic"]);
+    if let Some(ref linker) = sessopts.cg.linker {
+        cmd.arg("-C").arg(format!("linker={}", linker.display()));
+    }
+    for arg in &builder.codegen_args {
+        cmd.arg("-C").arg(arg);
+    }
+    if no_run {
+        cmd.arg("--emit=metadata");
+    }
+    cmd.arg("-o").arg(outdir.join("rust_out"));
+    cmd.arg("-");
+    cmd.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().unwrap_or_else(|e| {
+        panic!("couldn't run the test builder `{}`: {}", builder.path.display(), e)
+    });
+    child.stdin.take().unwrap().write_all(test.as_bytes())
+         .expect("couldn't pass the doctest to the test builder");
+    let out = child.wait_with_output().expect("couldn't wait for the test builder");
+    stderr.lock().unwrap().extend_from_slice(&out.stderr);
+
+    let libdir = sysroot.join(filesearch::relative_target_lib_path(
+        &sysroot, sessopts.target_triple.triple()
+    ));
+    (libdir, if out.status.success() { Ok(()) } else { Err(()) })
+}
+
 /// Makes the test file. Also returns the number of lines before the code begins
 pub fn make_test(s: &str,
                  cratename: Option<&str>,
@@ -504,13 +642,19 @@ pub struct Collector {
     filename: Option<PathBuf>,
     linker: Option<PathBuf>,
     edition: Edition,
+    persist_doctests: Option<PathBuf>,
+    test_builder: Option<TestBuilder>,
+    doctest_runner: Option<DoctestRunner>,
 }
 
 impl Collector {
     pub fn new(cratename: String, cfgs: Vec<String>, libs: SearchPaths, cg: CodegenOptions,
                externs: Externs, use_headers: bool, opts: TestOptions,
                maybe_sysroot: Option<PathBuf>, codemap: Option<Lrc<CodeMap>>,
-               filename: Option<PathBuf>, linker: Option<PathBuf>, edition: Edition) -> Collector {
+               filename: Option<PathBuf>, linker: Option<PathBuf>, edition: Edition,
+               persist_doctests: Option<PathBuf>,
+               test_builder: Option<TestBuilder>,
+               doctest_runner: Option<DoctestRunner>) -> Collector {
         Collector {
             tests: Vec::new(),
             names: Vec::new(),
@@ -527,6 +671,9 @@ impl Collector {
             filename,
             linker,
             edition,
+            persist_doctests,
+            test_builder,
+            doctest_runner,
         }
     }
 
@@ -546,6 +693,9 @@ impl Collector {
         let maybe_sysroot = self.maybe_sysroot.clone();
         let linker = self.linker.clone();
         let edition = self.edition;
+        let persist_doctests = self.persist_doctests.clone();
+        let test_builder = self.test_builder.clone();
+        let doctest_runner = self.doctest_runner.clone();
         debug!("Creating test {}: {}", name, test);
         self.tests.push(testing::TestDescAndFn {
             desc: testing::TestDesc {
@@ -579,7 +729,10 @@ impl Collector {
                                  &opts,
                                  maybe_sysroot,
                                  linker,
-                                 edition)
+                                 edition,
+                                 persist_doctests,
+                                 test_builder,
+                                 doctest_runner)
                     }))
                 } {
                     Ok(()) => (),
@@ -16,6 +16,7 @@ use std::mem;
 use syntax::ast;
 use syntax::attr;
 use syntax::codemap::Spanned;
+use syntax::ext::base::MacroKind;
 use syntax_pos::{self, Span};
 
 use rustc::hir::map as hir_map;
@@ -189,6 +190,49 @@ impl<'a, 'tcx, 'rcx, 'cstore> RustdocVisitor<'a, 'tcx, 'rcx, 'cstore> {
         }
     }
 
+    /// Returns the procedural macro defined by the given function, if it is one.
+    pub fn visit_proc_macro(&self, item: &hir::Item, name: ast::Name) -> Option<ProcMacro> {
+        let kind = item.attrs.iter().filter_map(|a| {
+            if a.check_name("proc_macro") {
+                Some(MacroKind::Bang)
+            } else if a.check_name("proc_macro_derive") {
+                Some(MacroKind::Derive)
+            } else if a.check_name("proc_macro_attribute") {
+                Some(MacroKind::Attr)
+            } else {
+                None
+            }
+        }).next()?;
+        debug!("Visiting proc-macro");
+
+        // Derives are named by their attribute rather than by their function, and can declare
+        // helper attributes: `#[proc_macro_derive(Name, attributes(helper))]`
+        let mut name = name;
+        let mut helpers = Vec::new();
+        if kind == MacroKind::Derive {
+            for mi in item.attrs.lists("proc_macro_derive") {
+                if mi.check_name("attributes") {
+                    for helper in mi.meta_item_list().into_iter().flat_map(|list| list) {
+                        helpers.extend(helper.name());
+                    }
+                } else if let Some(derive_name) = mi.name() {
+                    name = derive_name;
+                }
+            }
+        }
+
+        Some(ProcMacro {
+            name,
+            id: item.id,
+            kind,
+            helpers,
+            attrs: item.attrs.clone(),
+            whence: item.span,
+            stab: self.stability(item.id),
+            depr: self.deprecation(item.id),
+        })
+    }
+
     pub fn visit_mod_contents(&mut self, span: Span, attrs: hir::HirVec<ast::Attribute>,
                               vis: hir::Visibility, id: ast::NodeId,
                               m: &hir::Mod,
@@ -421,8 +465,12 @@ impl<'a, 'tcx, 'rcx, 'cstore> RustdocVisitor<'a, 'tcx, 'rcx, 'cstore> {
                 om.structs.push(self.visit_variant_data(item, name, sd, gen)),
             hir::ItemKind::Union(ref sd, ref gen) =>
                 om.unions.push(self.visit_union_data(item, name, sd, gen)),
-            hir::ItemKind::Fn(ref fd, header, ref gen, body) =>
-                om.fns.push(self.visit_fn(item, name, &**fd, header, gen, body)),
+            hir::ItemKind::Fn(ref fd, header, ref gen, body) => {
+                match self.visit_proc_macro(item, name) {
+                    Some(proc_macro) => om.proc_macros.push(proc_macro),
+                    None => om.fns.push(self.visit_fn(item, name, &**fd, header, gen, body)),
+                }
+            }
             hir::ItemKind::Ty(ref ty, ref gen) => {
                 let t = Typedef {
                     ty: ty.clone(),
@@ -499,8 +547,19 @@ impl<'a, 'tcx, 'rcx, 'cstore> RustdocVisitor<'a, 'tcx, 'rcx, 'cstore> {
                 };
                 om.traits.push(t);
             },
-            hir::ItemKind::TraitAlias(..) => {
-                unimplemented!("trait objects are not yet implemented")
+            hir::ItemKind::TraitAlias(ref gen, ref b) => {
+                let t = TraitAlias {
+                    name,
+                    generics: gen.clone(),
+                    bounds: b.iter().cloned().collect(),
+                    id: item.id,
+                    attrs: item.attrs.clone(),
+                    whence: item.span,
+                    vis: item.vis.clone(),
+                    stab: self.stability(item.id),
+                    depr: self.deprecation(item.id),
+                };
+                om.trait_aliases.push(t);
             },
 
             hir::ItemKind::Impl(unsafety,
@@ -360,7 +360,7 @@ declare_features! (
     (active, doc_cfg, "1.21.0", Some(43781), None),
     // #[doc(masked)]
     (active, doc_masked, "1.21.0", Some(44027), None),
-    // #[doc(spotlight)]
+    // #[doc(spotlight)] and #[doc(notable_trait)]
     (active, doc_spotlight, "1.22.0", Some(45040), None),
     // #[doc(include="some-file")]
     (active, external_doc, "1.22.0", Some(44732), None),
@@ -1498,6 +1498,10 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                     gate_feature_post!(&self, doc_spotlight, attr.span,
                         "#[doc(spotlight)] is experimental"
                     );
+                } else if content.iter().any(|c| c.check_name("notable_trait")) {
+                    gate_feature_post!(&self, doc_spotlight, attr.span,
+                        "#[doc(notable_trait)] is experimental"
+                    );
                 } else if content.iter().any(|c| c.check_name("alias")) {
                     gate_feature_post!(&self, doc_alias, attr.span,
                         "#[doc(alias = \"...\")] is experimental"
@@ -0,0 +1,25 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// ```
+/// let lock = std::env::var("DOCTEST_LOCK").unwrap();
+/// std::fs::create_dir(&lock).expect("another test is running");
+/// std::thread::sleep(std::time::Duration::from_millis(500));
+/// std::fs::remove_dir(&lock).unwrap();
+/// ```
+pub struct First;
+
+/// ```
+/// let lock = std::env::var("DOCTEST_LOCK").unwrap();
+/// std::fs::create_dir(&lock).expect("another test is running");
+/// std::thread::sleep(std::time::Duration::from_millis(500));
+/// std::fs::remove_dir(&lock).unwrap();
+/// ```
+pub struct Second;
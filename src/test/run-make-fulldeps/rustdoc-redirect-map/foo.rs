@@ -0,0 +1,38 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// This impl is seen before `Square`, so its method is only indexed once the crate is folded.
+mod impls {
+    impl ::shapes::Square {
+        pub fn perimeter(&self) -> f64 {
+            4.0 * self.side
+        }
+    }
+}
+
+pub mod shapes {
+    pub struct Square {
+        pub side: f64,
+    }
+
+    impl Square {
+        pub fn area(&self) -> f64 {
+            self.side * self.side
+        }
+    }
+}
+
+pub trait Shape {
+    fn sides(&self) -> usize;
+}
+
+pub fn draw(_: &Shape) {}
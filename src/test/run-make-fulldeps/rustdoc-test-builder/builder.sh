@@ -0,0 +1,4 @@
+#!/bin/sh
+# Records the arguments it is called with, then builds the doctest with rustc.
+echo "$@" >> "$BUILDER_LOG"
+exec "$REAL_RUSTC" "$@"
@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "good"]
+
+//! Links to [the method](struct.Foo.html#method.new) and [the list of structs](#structs) work.
+
+/// A struct.
+pub struct Foo;
+
+impl Foo {
+    /// Creates a `Foo`.
+    pub fn new() -> Foo {
+        Foo
+    }
+}
+
+/// A trait.
+pub trait Bar {
+    /// Does something.
+    fn bar(&self) {}
+}
+
+impl Bar for Foo {}
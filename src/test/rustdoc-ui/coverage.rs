@@ -0,0 +1,52 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Z unstable-options --show-coverage
+// compile-pass
+
+//! The crate documentation, with an example:
+//!
+//! ```
+//! let answer = 42;
+//! ```
+
+/// Documented.
+pub struct Documented;
+
+pub struct Undocumented;
+
+impl Documented {
+    /// Documented method.
+    pub fn documented(&self) {}
+
+    pub fn undocumented(&self) {}
+}
+
+// Trait implementations don't count.
+impl Clone for Documented {
+    fn clone(&self) -> Self {
+        Documented
+    }
+}
+
+pub mod inner {
+    //! Inner module.
+
+    /// Documented, with an example:
+    ///
+    /// ```
+    /// inner_fn();
+    /// ```
+    pub fn inner_fn() {}
+
+    pub fn undocumented_fn() {}
+
+    fn private_fn() {}
+}
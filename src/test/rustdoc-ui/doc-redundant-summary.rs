@@ -0,0 +1,26 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options -Z doc-redundant-summary
+// compile-pass
+
+/// Thing.
+pub struct Thing;
+
+/// `Parse`
+pub trait Parse {}
+
+/// Frobs the things.
+pub fn frob() {}
+
+/// cache
+///
+/// Keeps the frobbed things around.
+pub struct Cache;
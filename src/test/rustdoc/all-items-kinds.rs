@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/all.html '//h3[@id="Traits"]' 'Traits'
+// @has - '//ul[@class="traits docblock"]//a[@href="trait.Trait.html"]' 'Trait'
+// @has - '//h3[@id="Macros"]' 'Macros'
+// @has - '//ul[@class="macros docblock"]//a[@href="macro.mac.html"]' 'mac'
+// @has - '//h3[@id="Typedefs"]' 'Typedefs'
+// @has - '//ul[@class="typedefs docblock"]//a[@href="type.Alias.html"]' 'Alias'
+// @has - '//ul[@class="structs docblock"]//a[@href="inner/struct.Nested.html"]' 'inner::Nested'
+
+pub trait Trait {}
+
+#[macro_export]
+macro_rules! mac {
+    () => {}
+}
+
+pub type Alias = u8;
+
+pub mod inner {
+    pub struct Nested;
+}
@@ -0,0 +1,24 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --annotate-phantom-data
+
+#![crate_name = "foo"]
+
+use std::marker::PhantomData;
+
+// @has foo/struct.Handle.html
+// @has - '//*[@class="stab phantom-data"]' 'This field holds no data at runtime: it tells the \
+//      compiler that the type acts as if it owned a T, for its variance and drop checking.'
+// @count - '//*[@class="stab phantom-data"]' 1
+pub struct Handle<T> {
+    pub id: u32,
+    pub marker: PhantomData<T>,
+}
@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/trait.Identify.html '//*[@class="rust trait"]' 'const ID: u32 = 0;'
+// @has - '//*[@class="rust trait"]' "const NAME: &'static str;"
+// @has - '//*[@id="associatedconstant.ID"]' 'const ID: u32'
+// @!has - '//*[@id="associatedconstant.ID"]' 'const ID: u32 = 0'
+// @has - '//*[@class="docblock"]' 'ID: u32 = 0'
+pub trait Identify {
+    const ID: u32 = 0;
+    const NAME: &'static str;
+}
+
+pub struct Anonymous;
+
+// @has foo/struct.Anonymous.html '//*[@id="associatedconstant.ID"]' 'const ID: u32'
+// @has - '//*[@class="docblock"]' 'ID: u32 = 0'
+impl Identify for Anonymous {
+    const NAME: &'static str = "anonymous";
+}
@@ -11,7 +11,8 @@
 
 pub trait Foo {
     // @has assoc_consts/trait.Foo.html '//*[@class="rust trait"]' \
-    //      'const FOO: usize;'
+    //      'const FOO: usize = 12;'
+    // @has - '//*[@class="rust trait"]' 'const FOO_NO_DEFAULT: bool;'
     // @has - '//*[@id="associatedconstant.FOO"]' 'const FOO: usize'
     // @has - '//*[@class="docblock"]' 'FOO: usize = 12'
     const FOO: usize = 12;
@@ -57,8 +58,8 @@ pub fn f(_: &(ToString + 'static)) {}
 
 impl Bar {
     // @has assoc_consts/struct.Bar.html '//*[@id="associatedconstant.F"]' \
-    //      "const F: fn(_: &(ToString + 'static))"
-    // @has - '//*[@class="docblock"]' "F: fn(_: &(ToString + 'static)) = f"
+    //      "const F: fn(_: &(dyn ToString + 'static))"
+    // @has - '//*[@class="docblock"]' "F: fn(_: &(dyn ToString + 'static)) = f"
     pub const F: fn(_: &(ToString + 'static)) = f;
 }
 
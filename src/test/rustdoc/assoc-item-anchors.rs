@@ -0,0 +1,62 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+use std::fmt;
+
+pub struct Foo;
+
+impl Foo {
+    pub fn new() -> Foo { Foo }
+}
+
+// Moving `bar` to the first impl block must not change its anchor.
+// @has foo/struct.Foo.html '//h4[@id="method.bar"]' 'fn bar(&self)'
+impl Foo {
+    pub fn bar(&self) {}
+
+    pub fn fmt(&self) {}
+}
+
+// The inherent `fmt` keeps the simple anchor, the trait one gets an alias qualified by its impl.
+// @has foo/struct.Foo.html '//h4[@id="method.fmt"]' 'pub fn fmt(&self)'
+// @has - '//h4[@id="method.fmt-1"]' 'fn fmt(&self, f: &mut Formatter) -> Result'
+// @has - '//a[@name="impl-Debug-for-Foo.method.fmt"]'
+// @!has - '//a[@name="impl-Foo.method.bar"]'
+impl fmt::Debug for Foo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
+}
+
+pub trait Convert {
+    fn convert(&self) -> u32;
+}
+
+pub struct Wrapper<T>(pub T);
+
+// The aliases of the items of two impls of the same trait are told apart by their type, not by
+// the order of the impls.
+// @has foo/struct.Wrapper.html '//h4[@id="method.convert"]' 'fn convert(&self) -> u32'
+// @has - '//h4[@id="method.convert-1"]' 'fn convert(&self) -> u32'
+// @has - '//a[@name="impl-Convert-for-Wrapper%3Cu16%3E.method.convert"]'
+// @!has - '//a[@name="impl-Convert-1.method.convert"]'
+impl Convert for Wrapper<u8> {
+    fn convert(&self) -> u32 {
+        self.0 as u32
+    }
+}
+
+impl Convert for Wrapper<u16> {
+    fn convert(&self) -> u32 {
+        self.0 as u32
+    }
+}
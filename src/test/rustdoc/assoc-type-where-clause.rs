@@ -0,0 +1,22 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(generic_associated_types)]
+#![crate_name = "foo"]
+
+// @has foo/trait.Container.html
+// @has - '//pre[@class="rust trait"]' 'type Item: Clone where Self: Sized;'
+// @has - '//*[@id="associatedtype.Item"]//code' 'type Item: Clone where Self: Sized'
+// @has - '//pre[@class="rust trait"]' 'type Plain;'
+// @!has - '//*[@id="associatedtype.Plain"]//code' 'where'
+pub trait Container {
+    type Item: Clone where Self: Sized;
+    type Plain;
+}
@@ -0,0 +1,40 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// edition:2018
+// compile-flags: -Z unstable-options --show-async-desugaring
+
+#![feature(async_await, futures_api)]
+#![crate_name = "foo"]
+
+// @has foo/fn.get.html '//pre[@class="rust fn"]' 'pub async fn get(x: u32) -> u32'
+// @has - '//pre[@class="rust fn async-desugaring"]' 'impl Future<Output = u32>'
+pub async fn get(x: u32) -> u32 {
+    x
+}
+
+// @has foo/fn.unit.html '//pre[@class="rust fn async-desugaring"]' 'impl Future<Output = ()>'
+pub async fn unit() {}
+
+// @!has foo/fn.sync.html '//pre[@class="rust fn async-desugaring"]'
+pub fn sync() {}
+
+pub struct Client;
+
+// @has foo/struct.Client.html '//h4[@id="method.fetch"]' 'pub async fn fetch(&self) -> u8'
+// @has - '//*[@class="async-desugaring"]' 'pub fn fetch(&self) -> impl Future<Output = u8>'
+// @!has - '//*[@class="async-desugaring"]' 'fn sync'
+impl Client {
+    pub async fn fetch(&self) -> u8 {
+        0
+    }
+
+    pub fn sync(&self) {}
+}
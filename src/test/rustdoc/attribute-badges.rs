@@ -0,0 +1,42 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --show-attributes=inline,cold
+
+#![feature(custom_attribute)]
+#![crate_name = "foo"]
+
+// @has foo/fn.located.html '//div[@class="stab attribute"]' '#[track_caller]'
+#[track_caller]
+pub fn located() {}
+
+// @has foo/fn.hot.html '//div[@class="stab attribute"]' '#[inline]'
+// @!has - '//div[@class="stab attribute"]' '#[track_caller]'
+#[inline]
+pub fn hot() {}
+
+// @has foo/fn.rare.html '//div[@class="stab attribute"]' '#[cold]'
+// @has - '//div[@class="stab attribute"]' '#[track_caller]'
+#[cold]
+#[track_caller]
+pub fn rare() {}
+
+pub struct Bar;
+
+impl Bar {
+    // @has foo/struct.Bar.html '//div[@class="stab attribute"]' '#[inline]'
+    #[inline]
+    pub fn method(&self) {}
+}
+
+// @has foo/fn.plain.html
+// @!has - '//div[@class="stab attribute"]'
+#[must_use]
+pub fn plain() -> u8 { 0 }
@@ -0,0 +1,20 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+pub fn takes_fn(f: impl Fn(u32) -> bool) {}
+
+pub fn returns_fn() -> impl Fn(u32) -> bool {
+    |x| x > 0
+}
+
+pub fn boxed_fn(f: Box<dyn FnMut(u32, &str) -> bool>) {}
+
+pub fn unit_fn(f: Box<dyn FnOnce(u8)>) {}
+
+pub fn takes_ptr(f: fn(u32) -> bool, (a, b): (u8, u8)) {}
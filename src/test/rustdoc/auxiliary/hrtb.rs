@@ -0,0 +1,14 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+pub trait Borrows<'a> {}
+
+pub fn external_hrtb<T>(t: T) where T: for<'a> Borrows<'a> {}
+
+pub fn external_fn_hrtb<F>(f: F) where F: for<'a> Fn(&'a u8, &'a u8) {}
@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Wrap {
+    type Wrapped;
+    type Pair;
+}
+
+impl<T> Wrap for T {
+    type Wrapped = Option<T>;
+    type Pair = (T, u8);
+}
+
+// @has foo/struct.Value.html
+// @has - '//*[@id="blanket-implementations-list"]//code' 'impl<T> Wrap for T'
+// @has - '//*[@id="blanket-implementations-list"]//h4[@id="associatedtype.Wrapped"]//code' \
+//      'type Wrapped = Option<Value>'
+// @has - '//*[@id="blanket-implementations-list"]//h4[@id="associatedtype.Pair"]//code' \
+//      'type Pair = (Value, u8)'
+pub struct Value;
@@ -0,0 +1,38 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: --document-private-items
+
+#![crate_name = "foo"]
+
+mod imp {
+    // @has foo/imp/struct.Inner.html
+    // @has - '//*[@id="blanket-implementations-list"]//code' 'impl<T> Blanket for T'
+    pub struct Inner;
+
+    // Types which aren't reachable in any way still don't get them.
+    // @has foo/imp/struct.Hidden.html
+    // @!has - '//*[@id="blanket-implementations-list"]//code' 'impl<T> Blanket for T'
+    pub struct Hidden;
+}
+
+pub trait Blanket {}
+
+impl<T> Blanket for T {}
+
+pub struct Outer;
+
+impl Iterator for Outer {
+    type Item = imp::Inner;
+
+    fn next(&mut self) -> Option<imp::Inner> {
+        None
+    }
+}
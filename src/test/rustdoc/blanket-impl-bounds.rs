@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Duplicate {}
+
+impl<T: Clone> Duplicate for T {}
+
+pub trait Show {}
+
+impl<T> Show for T where T: Copy + Default {}
+
+pub trait Plain {}
+
+impl<T> Plain for T {}
+
+// @has foo/struct.MyStruct.html
+// @!has - '//*[@class="blanket-bounds"]' 'T:'
+// @has - '//*[@class="blanket-bounds"]' 'Applies since MyStruct: Clone'
+// @has - '//*[@class="blanket-bounds"]' 'Applies since MyStruct: Copy + Default'
+#[derive(Clone, Copy, Default)]
+pub struct MyStruct;
+
+// @has foo/struct.Wrapper.html
+// @has - '//*[@class="blanket-bounds"]' 'Applies since Wrapper<U>: Clone'
+#[derive(Clone)]
+pub struct Wrapper<U>(pub U);
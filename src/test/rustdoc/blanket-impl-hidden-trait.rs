@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+mod traits {
+    #[doc(hidden)]
+    pub trait Hidden {}
+
+    impl<T> Hidden for T {}
+
+    pub trait Shown {}
+
+    impl<T> Shown for T {}
+}
+
+pub use traits::{Hidden, Shown};
+
+// @has foo/struct.Foo.html
+// @has - '//*[@id="blanket-implementations-list"]//code' 'impl<T> Shown for T'
+// @!has - '//*[@id="blanket-implementations-list"]//code' 'impl<T> Hidden for T'
+// @!has - '//*[@class="sidebar"]//a' 'Hidden'
+pub struct Foo;
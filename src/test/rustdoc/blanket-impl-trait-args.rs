@@ -0,0 +1,37 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Convert<T> {
+    fn convert(&self) -> T;
+}
+
+pub struct Wrapper;
+
+// @has foo/struct.Wrapper.html '//*[@id="blanket-implementations-list"]//code' \
+//      'impl<T> Convert<Wrapper> for T'
+impl<T: Clone> Convert<T> for T {
+    fn convert(&self) -> T {
+        self.clone()
+    }
+}
+
+pub trait Pair<A, B> {}
+
+// @has foo/struct.Wrapper.html '//*[@id="blanket-implementations-list"]//code' \
+//      'impl<T, U> Pair<Wrapper, U> for T'
+impl<T, U> Pair<T, U> for T {}
+
+impl Clone for Wrapper {
+    fn clone(&self) -> Wrapper {
+        Wrapper
+    }
+}
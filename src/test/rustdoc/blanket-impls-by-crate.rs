@@ -0,0 +1,33 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:blanket-impls-dep.rs
+// build-aux-docs
+// ignore-cross-compile
+
+#![crate_name = "foo"]
+
+extern crate blanket_impls_dep;
+
+pub trait Local {}
+
+impl<T> Local for T {}
+
+// @has foo/struct.Foo.html
+// @has - '//*[@id="blanket-implementations-list"]/h3[@id="blanket-implementations-blanket_impls_dep"]' \
+//      'From blanket_impls_dep'
+// @has - '//*[@id="blanket-implementations-list"]/h3[@id="blanket-implementations-foo"]' 'From foo'
+// @has - '//*[@id="blanket-implementations-list"]/h3[@id="blanket-implementations-core"]' 'From core'
+// @has - '//h3[@id="blanket-implementations-blanket_impls_dep"]/following-sibling::h3[1]//code' \
+//      'impl<T> Describe for T'
+// @has - '//h3[@id="blanket-implementations-foo"]/following-sibling::h3[1]//code' \
+//      'impl<T> Local for T'
+// @!has - '//h3[@id="blanket-implementations-"]'
+pub struct Foo;
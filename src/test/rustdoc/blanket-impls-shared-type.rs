@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:shared-blanket-type.rs
+// build-aux-docs
+// ignore-cross-compile
+
+#![crate_name = "foo"]
+
+extern crate shared_blanket_type;
+
+// @has shared_blanket_type/struct.Shared.html
+// @has - '//h3[@id="impl-Describe"]//code' 'impl<T> Describe for T'
+// @!has - '//h3[@id="impl-Describe-1"]'
+
+// @has foo/struct.Shared.html
+// @has - '//h3[@id="impl-Describe"]//code' 'impl<T> Describe for T'
+// @!has - '//h3[@id="impl-Describe-1"]'
+#[doc(inline)]
+pub use shared_blanket_type::Shared;
@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --bounds-style where
+
+#![crate_name = "foo"]
+
+use std::fmt::Debug;
+
+// @has foo/fn.mixed.html '//pre[@class="rust fn"]' 'pub fn mixed<T>(t: T)'
+// @has - '//pre[@class="rust fn"]' 'where T: Clone + Debug'
+pub fn mixed<T: Clone>(t: T) where T: Debug {}
+
+// @has foo/fn.other.html '//pre[@class="rust fn"]' 'pub fn other<T>(t: T)'
+// @has - '//pre[@class="rust fn"]' 'where T: Debug, Vec<T>: Clone'
+pub fn other<T: Debug>(t: T) where Vec<T>: Clone {}
+
+// @has foo/struct.Wrapper.html '//pre[@class="rust struct"]' 'pub struct Wrapper<T> where T: Clone'
+pub struct Wrapper<T> where T: Clone {
+    pub inner: T,
+}
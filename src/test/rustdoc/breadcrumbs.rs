@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub mod outer {
+    pub mod inner {
+        // @has foo/outer/inner/struct.Deep.html
+        // @has - '//h1[@class="fqn"]/span[@class="in-band"]' 'Struct foo::outer::inner::Deep'
+        // @has - '//h1[@class="fqn"]//a[1]/@href' '../../index.html'
+        // @has - '//h1[@class="fqn"]//a[1]' 'foo'
+        // @has - '//h1[@class="fqn"]//a[2]/@href' '../index.html'
+        // @has - '//h1[@class="fqn"]//a[2]' 'outer'
+        // @has - '//h1[@class="fqn"]//a[3]/@href' 'index.html'
+        // @has - '//h1[@class="fqn"]//a[3]' 'inner'
+        // @has - '//h1[@class="fqn"]//a[@class="struct"]' 'Deep'
+        pub struct Deep;
+    }
+
+    // @has foo/outer/index.html
+    // @has - '//h1[@class="fqn"]/span[@class="in-band"]' 'Module foo::outer'
+    // @has - '//h1[@class="fqn"]//a[1]/@href' '../index.html'
+    // @has - '//h1[@class="fqn"]//a[@class="mod"]' 'outer'
+}
@@ -0,0 +1,42 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: --cfg feature="enabled" --cfg custom
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Enabled.html
+#[cfg(feature = "enabled")]
+pub struct Enabled;
+
+// @!has foo/struct.Disabled.html
+#[cfg(feature = "disabled")]
+pub struct Disabled;
+
+// @has foo/fn.custom.html
+#[cfg(custom)]
+pub fn custom() {}
+
+// @!has foo/fn.not_custom.html
+#[cfg(not(custom))]
+pub fn not_custom() {}
+
+// @has foo/struct.Both.html
+// @has - '//*[@id="method.enabled"]' 'fn enabled()'
+// @!has - '//*[@id="method.disabled"]' 'fn disabled()'
+pub struct Both;
+
+impl Both {
+    #[cfg(all(custom, feature = "enabled"))]
+    pub fn enabled() {}
+
+    #[cfg(any(feature = "disabled", not(custom)))]
+    pub fn disabled() {}
+}
@@ -0,0 +1,36 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --changed-files {{src-base}}/changed-files/list.txt
+
+#![crate_name = "foo"]
+
+// @has foo/index.html
+// @has - '//a[@href="changed/index.html"]' 'changed'
+// @!has - '//a[@href="unchanged/index.html"]' 'unchanged'
+// @!has - '//a[@href="struct.Root.html"]' 'Root'
+
+// @has foo/changed/struct.Changed.html
+// @has - '//code' 'pub fn new() -> Changed'
+// @has foo/changed/fn.changed.html
+#[path = "changed-files/changed.rs"]
+pub mod changed;
+
+// @!has foo/unchanged/index.html
+// @!has foo/unchanged/struct.Unchanged.html
+// @!has foo/unchanged/fn.unchanged.html
+#[path = "changed-files/unchanged.rs"]
+pub mod unchanged;
+
+// @!has foo/struct.Root.html
+pub struct Root;
+
+// @!has foo/struct.Unchanged.html
+pub use unchanged::Unchanged;
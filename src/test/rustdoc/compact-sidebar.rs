@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --compact-sidebar
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Foo.html
+// @has - '//*[@class="sidebar-elems compact"]//*[@class="sidebar-links"]/a[@href="#method.first"]' 'first'
+// @has - '//*[@class="sidebar-elems compact"]//*[@class="sidebar-links"]/a[@href="#method.second"]' 'second'
+// @!has - '//*[@class="sidebar-links"]//code' 'fn first'
+pub struct Foo;
+
+impl Foo {
+    pub fn first(&self) -> u32 { 0 }
+    pub fn second(&self, _: u32) {}
+}
+
+// @has foo/trait.Bar.html
+// @has - '//*[@class="sidebar-elems compact"]//*[@class="sidebar-links"]/a[@href="#tymethod.required"]' 'required'
+pub trait Bar {
+    fn required(&self);
+}
@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --crate-features {{src-base}}/crate-features.json
+
+#![crate_name = "foo"]
+
+// @has foo/index.html
+// @has - '//a[@id="crate-features"]/@href' 'features.html'
+
+// @has foo/features.html
+// @has - '//h1' 'Features of foo'
+// @has - '//h3[@class="feature"][1]' 'default'
+// @has - '//h3[@id="feature.default"]/following-sibling::p[1]/a[@href="#feature.std"]' 'std'
+// @has - '//h3[@id="feature.std"]/following-sibling::p[1]/a[@href="#feature.alloc"]' 'alloc'
+// @has - '//h3[@id="feature.std"]/following-sibling::div[1]' \
+//      'Implements the traits of std for the types of this crate.'
+// @has - '//h3[@id="feature.alloc"]/following-sibling::div[1]' 'Uses the heap.'
+// @has - '//h3[@id="feature.serde"]/following-sibling::p[1]/code' 'dep:serde'
+// @count - '//h3[@class="feature"]' 4
+
+pub struct Foo;
@@ -11,3 +11,7 @@
 // compile-flags: --crate-version=1.3.37 -Z unstable-options
 
 // @has 'crate_version/index.html' '//div[@class="block version"]/p' 'Version 1.3.37'
+// @has - '//meta[@name="crate-version"]/@content' '1.3.37'
+
+// @has 'crate_version/struct.Foo.html' '//meta[@name="crate-version"]/@content' '1.3.37'
+pub struct Foo;
@@ -0,0 +1,33 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --css-class-prefix rd-
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Foo.html
+// @has - '//body/@class' 'rd-rustdoc'
+// @has - '//nav[@class="rd-sidebar"]'
+// @has - '//div[@class="rd-docblock"]' 'Some documentation.'
+// @count - '//div[@class="docblock"]' 0
+// @has - '//h3[@class="rd-impl"]' 'impl Foo'
+// @has - '//script' 'window.classPrefix = "rd-";'
+// @has rustdoc.css '.rd-docblock'
+// @!has rustdoc.css '.docblock'
+// @has light.css '.rd-sidebar'
+// @!has light.css '.sidebar'
+/// Some documentation.
+pub struct Foo;
+
+impl Foo {
+    pub fn new() -> Foo {
+        Foo
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Frobnicate {
+    /// Required method docs.
+    fn required(&self);
+
+    /// Frobnicates the value with the default strategy.
+    fn provided(&self) {}
+}
+
+pub struct Widget;
+
+// @has foo/struct.Widget.html
+// @has - '//*[@id="method.provided"]' 'fn provided(&self)'
+// @has - '//*[@class="docblock"]' 'Frobnicates the value with the default strategy.'
+impl Frobnicate for Widget {
+    fn required(&self) {}
+}
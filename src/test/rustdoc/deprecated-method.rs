@@ -0,0 +1,45 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Shape {
+    // @has foo/trait.Shape.html '//*[@id="tymethod.old_area"]//code[@class="deprecated"]' \
+    //      'fn old_area(&self)'
+    // @has - '//*[@class="stab deprecated"]' 'Deprecated since 1.0.0: use area'
+    #[deprecated(since = "1.0.0", note = "use area")]
+    fn old_area(&self) -> f64;
+
+    // @!has - '//*[@id="tymethod.area"]//code[@class="deprecated"]' ''
+    fn area(&self) -> f64;
+
+    fn perimeter(&self) -> f64;
+}
+
+pub struct Square;
+
+impl Square {
+    // @has foo/struct.Square.html '//*[@id="method.side"]//code[@class="deprecated"]' \
+    //      'fn side(&self)'
+    // @has - '//*[@class="stab deprecated"]' 'Deprecated since 0.5.0: use width'
+    #[deprecated(since = "0.5.0", note = "use width")]
+    pub fn side(&self) -> f64 { 1.0 }
+}
+
+impl Shape for Square {
+    #[allow(deprecated)]
+    fn old_area(&self) -> f64 { 1.0 }
+    fn area(&self) -> f64 { 1.0 }
+
+    // @has - '//*[@id="method.perimeter"]//code[@class="deprecated"]' 'fn perimeter(&self)'
+    // @has - '//*[@class="stab deprecated"]' 'Deprecated since 0.9.0: measure it instead'
+    #[deprecated(since = "0.9.0", note = "measure it instead")]
+    fn perimeter(&self) -> f64 { 4.0 }
+}
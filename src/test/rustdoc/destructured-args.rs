@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --destructured-args type
+
+#![crate_name = "foo"]
+
+// @has foo/fn.pair.html '//pre[@class="rust fn"]' 'pub fn pair(x: u32, (u32, u32))'
+// @!has - '//pre[@class="rust fn"]' '(a, b)'
+pub fn pair(x: u32, (a, b): (u32, u32)) -> u32 {
+    x + a + b
+}
+
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+// @has foo/fn.norm.html '//pre[@class="rust fn"]' 'pub fn norm(_: u32, Point)'
+pub fn norm(_: u32, Point { x, y }: Point) -> u32 {
+    x * x + y * y
+}
@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(doc_cfg)]
+#![doc(cfg_hide(feature = "std"))]
+#![crate_name = "doc_cfg_hide"]
+
+// @has doc_cfg_hide/struct.Hidden.html
+// @!has - '//*[@class="stab portability"]' ''
+#[doc(cfg(feature = "std"))]
+pub struct Hidden;
+
+// @has doc_cfg_hide/struct.Shown.html
+// @has - '//*[@class="stab portability"]' 'This is supported on Unix only.'
+#[doc(cfg(unix))]
+pub struct Shown;
+
+// @has doc_cfg_hide/struct.Combined.html
+// @has - '//*[@class="stab portability"]' 'This is supported on Unix only.'
+#[doc(cfg(all(feature = "std", unix)))]
+pub struct Combined;
+
+// @has doc_cfg_hide/index.html
+// @count - '//*[@class=" module-item"]//*[@class="stab portability"]' 2
@@ -0,0 +1,40 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(doc_spotlight)]
+
+#![crate_name = "foo"]
+
+#[doc(notable_trait)]
+pub trait Notable {
+    type Out;
+}
+
+pub struct Tally;
+
+impl Notable for Tally {
+    type Out = u32;
+}
+
+// @has foo/fn.tally.html
+// @has - '//*[@class="important-traits"]//*[@class="tooltiptext"]' 'Important traits for Tally'
+// @has - '//code[@class="content"]' 'impl Notable for Tally'
+// @has - '//code[@class="content"]' 'type Out = u32;'
+pub fn tally() -> Tally {
+    Tally
+}
+
+pub struct Plain;
+
+// @has foo/fn.plain.html
+// @count - '//*[@class="important-traits"]' 0
+pub fn plain() -> Plain {
+    Plain
+}
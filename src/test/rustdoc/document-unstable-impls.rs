@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --document-unstable-impls
+
+#![feature(staged_api)]
+#![stable(feature = "rust1", since = "1.0.0")]
+#![crate_name = "foo"]
+
+#[unstable(feature = "shiny", issue = "0")]
+pub trait Shiny {}
+
+impl<T> Shiny for T {}
+
+// @has foo/struct.Foo.html
+// @has - '//*[@id="blanket-implementations-list"]//code' 'impl<T> Shiny for T'
+// @has - '//*[@id="blanket-implementations-list"]//*[@class="stab unstable"]' \
+//      '🔬 This is a nightly-only experimental API. (shiny)'
+#[stable(feature = "rust1", since = "1.0.0")]
+pub struct Foo;
@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Guard.html
+// @has - '//*[@class="stab drop"]' 'This type implements Drop: some code runs when its values \
+//      go out of scope.'
+// @has - '//*[@class="stab drop"]/a/@href' '#impl-Drop'
+// @has - '//h3[@id="impl-Drop"]//code' 'impl Drop for Guard'
+pub struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+// @has foo/enum.Resource.html '//*[@class="stab drop"]' 'This type implements Drop'
+pub enum Resource {
+    File,
+}
+
+impl Drop for Resource {
+    fn drop(&mut self) {}
+}
+
+// @has foo/struct.Plain.html
+// @count - '//*[@class="stab drop"]' 0
+pub struct Plain;
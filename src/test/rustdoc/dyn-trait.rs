@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Handler {}
+
+// @has foo/fn.register.html '//pre[@class="rust fn"]' \
+//      "pub fn register<'a>(handler: Box<dyn Handler + Send + 'a>)"
+// @has - '//pre[@class="rust fn"]//a[@href="trait.Handler.html"]' 'Handler'
+pub fn register<'a>(handler: Box<dyn Handler + Send + 'a>) {}
+
+// @has foo/fn.bare.html '//pre[@class="rust fn"]' 'pub fn bare(handler: &dyn Handler)'
+// @has - '//pre[@class="rust fn"]//a[@href="trait.Handler.html"]' 'Handler'
+#[allow(bare_trait_objects)]
+pub fn bare(handler: &Handler) {}
+
+// @has foo/fn.borrowed.html '//pre[@class="rust fn"]' \
+//      "pub fn borrowed(handler: &(dyn Handler + 'static))"
+pub fn borrowed(handler: &(dyn Handler + 'static)) {}
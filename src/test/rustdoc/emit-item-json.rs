@@ -0,0 +1,37 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --emit-item-json
+
+#![crate_name = "foo"]
+
+// @has foo/fn.bar.html
+// @has foo/fn.bar.json '"name":"bar"'
+// @has - '"kind":"fn"'
+// @has - '"path":"foo::bar"'
+// @has - '"docs":"Makes a [`Baz`]."'
+// @has - '"links":[{"href":"struct.Baz.html","text":"Baz"}]'
+// @has - '"inner":{"variant":"FunctionItem"'
+/// Makes a [`Baz`].
+pub fn bar() -> Baz {
+    Baz
+}
+
+// @has foo/struct.Baz.json '"name":"Baz"'
+// @has - '"kind":"struct"'
+// @has - '"links":[]'
+pub struct Baz;
+
+// @has foo/inner/index.html
+// @!has foo/inner/index.json
+// @has foo/inner/fn.f.json '"path":"foo::inner::f"'
+pub mod inner {
+    pub fn f() {}
+}
@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --emit-metadata-only
+
+#![crate_name = "foo"]
+
+// @has search-index.js Foo
+// @has - Bar
+// @has implementors/core/clone/trait.Clone.js
+// @has - 'impl Clone for Foo'
+// @!has foo/index.html
+// @!has foo/struct.Foo.html
+// @!has foo/trait.Bar.html
+// @!has src/foo/emit-metadata-only.rs.html
+
+#[derive(Clone)]
+pub struct Foo;
+
+pub trait Bar {}
@@ -0,0 +1,37 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/fn.greet.html
+// @has - '//div[@class="example-output"]/pre[@class="example-output"]' 'Hello, world!'
+// @has - '//div[@class="example-output"]/preceding-sibling::pre[1]' 'foo::greet();'
+// @has - '//p[@class="example-output-header"]' 'Output:'
+// @count - '//div[@class="example-output"]' 1
+// @has - '//*[@class="docblock"]/pre/code[@class="language-output"]' 'not attached'
+
+/// Prints a greeting.
+///
+/// ```no_run
+/// foo::greet();
+/// ```
+///
+/// ```output
+/// Hello, world!
+/// ```
+///
+/// An output block without an example in front of it is displayed as a regular block:
+///
+/// ```output
+/// not attached
+/// ```
+pub fn greet() {
+    println!("Hello, world!");
+}
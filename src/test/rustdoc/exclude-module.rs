@@ -0,0 +1,38 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --exclude-module internal --exclude-module api::detail
+
+#![crate_name = "foo"]
+
+// @has foo/index.html
+// @!has - '//a/@href' 'internal/index.html'
+// @!has foo/internal/index.html
+// @!has foo/internal/struct.Hidden.html
+// @!has foo/internal/nested/fn.deep.html
+pub mod internal {
+    pub struct Hidden;
+
+    pub mod nested {
+        pub fn deep() {}
+    }
+}
+
+// @has foo/api/index.html
+// @has foo/api/struct.Kept.html
+// @!has foo/api/detail/index.html
+// @!has foo/api/detail/fn.helper.html
+pub mod api {
+    pub struct Kept;
+
+    pub mod detail {
+        pub fn helper() {}
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --expand-type-aliases
+
+#![crate_name = "foo"]
+
+// @has foo/type.Bytes.html '//pre[@class="rust typedef"]' 'type Bytes = Vec<u8>;'
+// @has - '//h2[@id="methods"]' 'Methods'
+// @has - '//h4[@id="method.push"]' 'fn push(&mut self, value: T)'
+// @has - '//h2[@id="implementations"]' 'Trait Implementations'
+pub type Bytes = Vec<u8>;
+
+// @has foo/type.Buffer.html '//h4[@id="method.push"]' 'fn push(&mut self, value: T)'
+pub type Buffer = Bytes;
+
+// @has foo/type.Grid.html '//h4[@id="method.len"]' 'fn len(&self) -> usize'
+pub type Grid<T> = Vec<Vec<T>>;
+
+// The impls of `Vec` are only shown on the pages of the aliases.
+// @has implementors/core/clone/trait.Clone.js 'impl Clone for Local'
+// @!has - 'Vec'
+#[derive(Clone)]
+pub struct Local;
+
+impl Local {
+    pub fn local_method(&self) {}
+}
+
+// @has foo/type.Alias.html '//h4[@id="method.local_method"]' 'fn local_method(&self)'
+pub type Alias = Local;
@@ -0,0 +1,19 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+// aux-build:extern-locations-dep.rs
+// compile-flags: -Z unstable-options --extern-locations {{src-base}}/auxiliary/extern-locations.txt
+
+#![crate_name = "foo"]
+
+extern crate extern_locations_dep;
+
+// @has foo/fn.located.html
+// @has - '//a[@href="https://example.com/docs/extern_locations_dep/struct.Located.html"]' 'Located'
+pub fn located(_: extern_locations_dep::Located) {}
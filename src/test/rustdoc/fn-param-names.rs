@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/fn.connect.html '//pre[@class="rust fn"]' 'pub fn connect(host: &str, port: u16)'
+pub fn connect(host: &str, port: u16) {}
+
+// @has foo/fn.ignored.html '//pre[@class="rust fn"]' 'pub fn ignored(_: u32)'
+pub fn ignored(_: u32) {}
+
+// @has foo/fn.destructure.html '//pre[@class="rust fn"]' 'pub fn destructure((a, b): (u8, u8))'
+pub fn destructure((a, b): (u8, u8)) {}
+
+pub struct Server;
+
+pub trait Listen {
+    // @has foo/trait.Listen.html '//*[@id="tymethod.listen"]//code' 'fn listen(&self, backlog: usize)'
+    fn listen(&self, backlog: usize);
+}
+
+// @has foo/struct.Server.html '//*[@id="method.listen"]//code' 'fn listen(&self, backlog: usize)'
+impl Listen for Server {
+    fn listen(&self, backlog: usize) {}
+}
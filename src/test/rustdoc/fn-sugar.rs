@@ -0,0 +1,41 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+// aux-build:fn-sugar.rs
+
+#![crate_name = "foo"]
+
+extern crate fn_sugar;
+
+// @has 'foo/fn.local_takes_fn.html' '//*[@class="rust fn"]' 'f: impl Fn(u32) -> bool'
+pub fn local_takes_fn(f: impl Fn(u32) -> bool) {}
+
+// @has 'foo/fn.local_returns_fn.html' '//*[@class="rust fn"]' '-> impl Fn(u32) -> bool'
+pub fn local_returns_fn() -> impl Fn(u32) -> bool {
+    |x| x > 0
+}
+
+// @has 'foo/fn.takes_fn.html' '//*[@class="rust fn"]' 'Fn(u32) -> bool'
+// @!has - '//*[@class="rust fn"]' 'Output'
+pub use fn_sugar::takes_fn;
+
+// @has 'foo/fn.returns_fn.html' '//*[@class="rust fn"]' '-> impl Fn(u32) -> bool'
+// @!has - '//*[@class="rust fn"]' 'Output'
+pub use fn_sugar::returns_fn;
+
+// @has 'foo/fn.boxed_fn.html' '//*[@class="rust fn"]' 'Box<dyn FnMut(u32, &str) -> bool>'
+pub use fn_sugar::boxed_fn;
+
+// @has 'foo/fn.unit_fn.html' '//*[@class="rust fn"]' 'Box<dyn FnOnce(u8)>'
+// @!has - '//*[@class="rust fn"]' '->'
+pub use fn_sugar::unit_fn;
+
+// @has 'foo/fn.takes_ptr.html' '//*[@class="rust fn"]' 'f: fn(u32) -> bool, _: (u8, u8)'
+// @!has - '//*[@class="rust fn"]' 'fn(_: u32)'
+pub use fn_sugar::takes_ptr;
@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:generic-param-defaults.rs
+
+#![crate_name = "foo"]
+
+extern crate generic_param_defaults;
+
+// @has foo/struct.Buffer.html '//pre[@class="rust struct"]' \
+//      'pub struct Buffer<T = u8, S = [T; 4]>'
+pub struct Buffer<T = u8, S = [T; 4]> {
+    pub data: S,
+    pub first: Option<T>,
+}
+
+// @has foo/trait.Combine.html '//pre[@class="rust trait"]' 'pub trait Combine<Rhs = Self>'
+pub trait Combine<Rhs = Self> {
+    fn combine(self, rhs: Rhs) -> Self;
+}
+
+// @has foo/struct.Storage.html '//pre[@class="rust struct"]' \
+//      'pub struct Storage<T = u8, S = [T; 4]>'
+pub use generic_param_defaults::Storage;
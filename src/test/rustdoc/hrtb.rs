@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+// aux-build:hrtb.rs
+
+#![crate_name = "foo"]
+
+extern crate hrtb;
+
+// @has foo/fn.bound.html '//*[@class="rust fn"]' "F: for<'a> Fn(&'a u8)"
+pub fn bound<F>(f: F) where F: for<'a> Fn(&'a u8) {}
+
+// @has foo/fn.predicate.html '//*[@class="rust fn"]' "F: for<'a> Fn(&'a u8)"
+pub fn predicate<F>(f: F) where for<'a> F: Fn(&'a u8) {}
+
+// @has foo/fn.external_hrtb.html '//*[@class="rust fn"]' "T: for<'a> Borrows<'a>"
+pub use hrtb::external_hrtb;
+
+// @has foo/fn.external_fn_hrtb.html '//*[@class="rust fn"]' "F: for<'a> Fn(&'a u8, &'a u8)"
+// @!has - '//*[@class="rust fn"]' "for<'a, 'a>"
+pub use hrtb::external_fn_hrtb;
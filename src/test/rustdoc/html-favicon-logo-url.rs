@@ -0,0 +1,21 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --html-favicon-url https://example.com/favicon.ico
+// compile-flags: --html-logo-url https://example.com/logo.png
+
+#![doc(html_logo_url = "https://example.com/attr-logo.png")]
+#![crate_name = "foo"]
+
+// @has foo/index.html
+// @has - '//link[@rel="shortcut icon"]/@href' 'https://example.com/favicon.ico'
+// @has - '//img[@alt="logo"]/@src' 'https://example.com/logo.png'
+// @!has - '//img[@alt="logo"]/@src' 'https://example.com/attr-logo.png'
+pub struct Foo;
@@ -0,0 +1,29 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --html-no-source
+
+#![crate_name = "foo"]
+
+// @!has src/foo/html-no-source.rs.html
+// @!has foo/index.html '//a[@class="srclink"]' '[src]'
+
+/// Dox
+// @!has foo/struct.Bar.html '//a[@class="srclink"]' '[src]'
+pub struct Bar;
+
+// @!has foo/struct.Bar.html '//*[@class="impl"]//a[@class="srclink"]' '[src]'
+impl Bar {
+    // @!has foo/struct.Bar.html '//*[@id="method.baz"]//a[@class="srclink"]' '[src]'
+    pub fn baz(&self) {}
+}
+
+// @!has foo/fn.qux.html '//a[@class="srclink"]' '[src]'
+pub fn qux() {}
@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --html-root-path /docs/v2
+
+#![crate_name = "foo"]
+
+pub mod inner {
+    // @has foo/inner/struct.Foo.html
+    // @has - '//link[@rel="stylesheet"]/@href' '/docs/v2/rustdoc.css'
+    // @has - '//script/@src' '/docs/v2/main.js'
+    // @has - '//a[@id="settings-menu"]/@href' '/docs/v2/settings.html'
+    // @has - '//a[@class="srclink"]/@href' '/docs/v2/src/foo/html-root-path.rs.html'
+    // @!has - '//link[@rel="stylesheet"]/@href' '../../rustdoc.css'
+    pub struct Foo;
+}
+
+// The crate pages keep the sidebar listing the crates, which main.js draws from the depth of the
+// page since the root path doesn't tell it any more.
+// @has foo/index.html '//script' 'window.rootPath = "/docs/v2/";'
+// @has - '//script' 'window.rootDepth = 1;'
+// @has foo/inner/struct.Foo.html '//script' 'window.rootDepth = 2;'
+// @has foo/all.html '//script/@src' '/docs/v2/main.js'
+// @has settings.html '//script/@src' '/docs/v2/main.js'
+// @has src/foo/html-root-path.rs.html '//script/@src' '/docs/v2/main.js'
@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Foo.html
+// @has - '//section[@id="main"]/div[@class="docblock"]' 'Constructors of Foo.'
+// @has - '//div[@id="implementations-list"]/div[@class="docblock"]' 'Clones nothing at all.'
+pub struct Foo;
+
+/// Constructors of `Foo`.
+impl Foo {
+    pub fn new() -> Foo {
+        Foo
+    }
+}
+
+/// Clones nothing at all.
+impl Clone for Foo {
+    fn clone(&self) -> Foo {
+        Foo
+    }
+}
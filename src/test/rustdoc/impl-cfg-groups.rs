@@ -0,0 +1,45 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+#![feature(doc_cfg)]
+
+pub struct Foo;
+
+// @has foo/struct.Foo.html
+// @count - '//*[@class="impl-items"]/h5[@class="impl-items-group cfg-group"]' 2
+// @matches - '//h5[@class="impl-items-group cfg-group"][1]' '\AUnix\Z'
+// @matches - '//h5[@class="impl-items-group cfg-group"][2]' '\AWindows\Z'
+// @has - '//*[@class="impl-items"]/h4[1]/@id' 'method.everywhere'
+// @has - '//*[@class="impl-items"]/h4[2]/@id' 'method.everywhere_too'
+// @has - '//*[@class="impl-items"]/h4[3]/@id' 'method.unix_one'
+// @has - '//*[@class="impl-items"]/h4[4]/@id' 'method.unix_two'
+// @has - '//*[@class="impl-items"]/h4[5]/@id' 'method.windows_only'
+impl Foo {
+    pub fn everywhere() {}
+    #[doc(cfg(unix))]
+    pub fn unix_one() {}
+    #[doc(cfg(windows))]
+    pub fn windows_only() {}
+    pub fn everywhere_too() {}
+    #[doc(cfg(unix))]
+    pub fn unix_two() {}
+}
+
+pub struct Bar;
+
+// @has foo/struct.Bar.html
+// @!has - '//h5[@class="impl-items-group cfg-group"]' ''
+impl Bar {
+    #[doc(cfg(unix))]
+    pub fn a() {}
+    #[doc(cfg(unix))]
+    pub fn b() {}
+}
@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Arity {
+    fn arity() -> usize;
+}
+
+macro_rules! tuple_impls {
+    ($($len:expr => ($($name:ident),+))+) => {
+        $(
+            impl<$($name),+> Arity for ($($name,)+) {
+                fn arity() -> usize { $len }
+            }
+        )+
+    }
+}
+
+// @has foo/trait.Arity.html
+// @count - '//summary' 1
+// @has - '//summary' 'Implemented for tuples of 1 up to 4 elements'
+tuple_impls! {
+    1 => (A)
+    2 => (A, B)
+    3 => (A, B, C)
+    4 => (A, B, C, D)
+}
+
+impl Arity for (u8, u8) {
+    fn arity() -> usize { 2 }
+}
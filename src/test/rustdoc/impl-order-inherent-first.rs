@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+// compile-flags: -Z unstable-options --impl-order inherent-first
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Foo.html
+// @matches - '//section[@id="main"]/h2[1]/@id' '\Amethods\Z'
+// @matches - '//section[@id="main"]/h2[2]/@id' '\Aimplementations\Z'
+pub struct Foo;
+
+impl Clone for Foo {
+    fn clone(&self) -> Foo {
+        Foo
+    }
+}
+
+impl Foo {
+    pub fn new() -> Foo {
+        Foo
+    }
+}
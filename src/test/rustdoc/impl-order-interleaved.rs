@@ -0,0 +1,33 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+// compile-flags: -Z unstable-options --impl-order interleaved
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Foo.html
+// @count - '//h2[@id="methods"]' 0
+// @has - '//h2[@id="implementations"]' 'Implementations'
+// @matches - '//div[@id="implementations-list"]/h3[1]/@id' '\Aimpl-Clone\Z'
+// @matches - '//div[@id="implementations-list"]/h3[2]/@id' '\Aimpl\Z'
+// @has - '//h4[@id="method.new"]' 'fn new() -> Foo'
+// @has - '//a[@class="sidebar-title"][@href="#implementations"]' 'Methods'
+pub struct Foo;
+
+impl Clone for Foo {
+    fn clone(&self) -> Foo {
+        Foo
+    }
+}
+
+impl Foo {
+    pub fn new() -> Foo {
+        Foo
+    }
+}
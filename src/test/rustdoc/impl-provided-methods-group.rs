@@ -0,0 +1,88 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Shape {
+    fn area(&self) -> u32;
+
+    fn perimeter(&self) -> u32 {
+        0
+    }
+
+    fn describe(&self) -> String {
+        String::new()
+    }
+}
+
+// @has foo/struct.Square.html
+// @has - '//*[@class="impl-items"]/h5[@class="impl-items-group"][1]' 'Implemented Methods'
+// @has - '//*[@class="impl-items"]/h5[@class="impl-items-group"][2]' 'Provided Methods'
+// @has - '//h5[.="Implemented Methods"]/following-sibling::h4[1]//code' 'fn area'
+// @has - '//h5[.="Implemented Methods"]/following-sibling::h4[2]//code' 'fn perimeter'
+// @has - '//h5[.="Provided Methods"]/following-sibling::h4[1]//code' 'fn describe'
+// @count - '//h5[.="Provided Methods"]/following-sibling::h4' 1
+pub struct Square;
+
+impl Shape for Square {
+    fn area(&self) -> u32 {
+        1
+    }
+
+    fn perimeter(&self) -> u32 {
+        4
+    }
+}
+
+// Without any provided method left, the methods aren't split.
+// @has foo/struct.Circle.html
+// @count - '//h5[@class="impl-items-group"]' 0
+pub struct Circle;
+
+impl Shape for Circle {
+    fn area(&self) -> u32 {
+        3
+    }
+
+    fn perimeter(&self) -> u32 {
+        6
+    }
+
+    fn describe(&self) -> String {
+        String::new()
+    }
+}
+
+pub trait Container {
+    type Item;
+
+    const CAPACITY: usize = 1;
+
+    fn get(&self) -> Option<Self::Item>;
+
+    fn is_empty(&self) -> bool {
+        self.get().is_none()
+    }
+}
+
+// The groups holding associated types or consts aren't called methods.
+// @has foo/struct.Boxed.html
+// @has - '//*[@class="impl-items"]/h5[@class="impl-items-group"][1]' 'Implemented Items'
+// @has - '//*[@class="impl-items"]/h5[@class="impl-items-group"][2]' 'Provided Items'
+// @!has - '//h5[@class="impl-items-group"]' 'Methods'
+pub struct Boxed;
+
+impl Container for Boxed {
+    type Item = u8;
+
+    fn get(&self) -> Option<u8> {
+        None
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --inline-search-index
+
+#![crate_name = "foo"]
+
+// @has foo/index.html '//script' 'searchIndex["foo"]'
+// @has - '//script' 'initSearch(searchIndex);'
+// @!has - '//script[@src="../search-index.js"]' ''
+// @has foo/struct.Foo.html '//script' 'searchIndex["foo"]'
+// @!has - '//script[@src="../search-index.js"]' ''
+// @has search-index.js
+/// Not the end of the script: </script>
+pub struct Foo;
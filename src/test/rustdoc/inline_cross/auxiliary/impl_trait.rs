@@ -0,0 +1,21 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt::Display;
+
+pub fn func(_x: impl Display) {}
+
+pub fn func2<T>(_x: impl Iterator<Item = T>, _y: impl Clone + Send) {}
+
+pub struct Foo;
+
+impl Foo {
+    pub fn method(&self, _x: impl Display) {}
+}
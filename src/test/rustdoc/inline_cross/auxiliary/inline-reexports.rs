@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// Docs for Thing.
+pub struct Thing {
+    /// Docs for the field.
+    pub field: u32,
+}
+
+impl Thing {
+    /// Docs for the method.
+    pub fn method(&self) -> u32 {
+        self.field
+    }
+}
+
+/// Docs for Shape.
+pub trait Shape {
+    /// Docs for area.
+    fn area(&self) -> u32;
+}
+
+impl Shape for Thing {
+    fn area(&self) -> u32 {
+        self.field
+    }
+}
+
+/// Docs for make.
+pub fn make() -> Thing {
+    Thing { field: 0 }
+}
@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:impl_trait.rs
+// ignore-cross-compile
+// ignore-tidy-linelength
+
+#![crate_name = "foo"]
+
+extern crate impl_trait;
+
+// @has foo/fn.func.html
+// @has - '//pre[@class="rust fn"]' 'pub fn func(_x: impl Display)'
+// @!has - '//pre[@class="rust fn"]' 'func<'
+// @!has - '//pre[@class="rust fn"]' 'where'
+pub use impl_trait::func;
+
+// @has foo/fn.func2.html
+// @has - '//pre[@class="rust fn"]' 'func2<T>('
+// @has - '//pre[@class="rust fn"]' '_x: impl Iterator<Item = T>'
+// @has - '//pre[@class="rust fn"]' '_y: impl Clone + Send'
+// @!has - '//pre[@class="rust fn"]' 'where'
+pub use impl_trait::func2;
+
+// @has foo/struct.Foo.html
+// @has - '//code' 'pub fn method(&self, _x: impl Display)'
+// @!has - '//code' 'method<'
+pub use impl_trait::Foo;
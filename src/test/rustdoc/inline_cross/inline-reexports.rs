@@ -0,0 +1,61 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:inline-reexports.rs
+// aux-build:inline-reexports-macros.rs
+// ignore-cross-compile
+// ignore-stage1
+
+#![crate_name = "foo"]
+
+extern crate inline_reexports;
+extern crate inline_reexports_macros;
+
+// @has foo/struct.Thing.html
+// @has - '//*[@class="docblock"]' 'Docs for Thing.'
+// @has - '//*[@id="structfield.field"]' 'field: u32'
+// @has - '//*[@class="docblock"]' 'Docs for the field.'
+// @has - '//*[@id="method.method"]' 'pub fn method(&self) -> u32'
+// @has - '//*[@class="docblock"]' 'Docs for the method.'
+// @has - '//*[@class="impl"]//code' 'impl Shape for Thing'
+#[doc(inline)]
+pub use inline_reexports::Thing;
+
+// @has foo/trait.Shape.html
+// @has - '//*[@class="docblock"]' 'Docs for Shape.'
+// @has - '//*[@id="tymethod.area"]' 'fn area(&self) -> u32'
+// @has - '//*[@id="implementors-list"]//code' 'impl Shape for Thing'
+#[doc(inline)]
+pub use inline_reexports::Shape;
+
+// @has foo/fn.make.html
+// @has - '//pre[@class="rust fn"]' 'pub fn make() -> Thing'
+// @has - '//*[@class="docblock"]' 'Docs for make.'
+#[doc(inline)]
+pub use inline_reexports::make;
+
+// @has foo/macro.bang.html
+// @has - '//pre' 'bang!() { /* proc-macro */ }'
+#[doc(inline)]
+pub use inline_reexports_macros::bang;
+
+// @has foo/attr.attribute.html
+// @has - '//pre' '#[attribute]'
+#[doc(inline)]
+pub use inline_reexports_macros::attribute;
+
+// @has foo/derive.Derived.html
+// @has - '//pre' '#[derive(Derived)]'
+// @has - '//pre' '#[helper]'
+#[doc(inline)]
+pub use inline_reexports_macros::Derived;
+
+// @has foo/index.html
+// @!has - '//code' 'pub use inline_reexports'
@@ -0,0 +1,50 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/fn.bytes.html '//a/@href' 'struct.Vec.html#method.new'
+// @has - '//a/@href' 'struct.Vec.html#method.with_capacity'
+/// Starts with [`Vec::new`], or [`Vec::with_capacity()`] when the length is known.
+pub fn bytes() -> Vec<u8> {
+    Vec::new()
+}
+
+// @has foo/fn.make.html '//a/@href' '../foo/struct.Foo.html#method.new'
+// @has - '//a/@href' '../foo/struct.Foo.html#method.default'
+/// Makes a [`Foo::new`], just like [`Foo::default`].
+pub fn make() -> Foo {
+    Foo::new()
+}
+
+// @has foo/struct.Foo.html '//*[@class="call-form"]' 'Called as Foo::new'
+// @has - '//*[@class="call-form"]' 'Called as Foo::with_value'
+// @!has - '//*[@class="call-form"]' 'Foo::with_value()'
+// @has - '//*[@class="call-form"]' 'Called as <Foo as Default>::default'
+// @count - '//*[@class="call-form"]' 3
+pub struct Foo;
+
+impl Foo {
+    pub fn new() -> Foo {
+        Foo
+    }
+
+    pub fn with_value(_value: u32) -> Foo {
+        Foo
+    }
+
+    pub fn get(&self) {}
+}
+
+impl Default for Foo {
+    fn default() -> Foo {
+        Foo
+    }
+}
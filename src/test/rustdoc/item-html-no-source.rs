@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has src/foo/item-html-no-source.rs.html
+
+// @has foo/fn.shown.html '//a[@class="srclink"]' '[src]'
+pub fn shown() {}
+
+// @!has foo/fn.hidden.html '//a[@class="srclink"]' '[src]'
+#[doc(html_no_source)]
+pub fn hidden() {}
+
+// @has foo/struct.Bar.html '//*[@id="method.shown"]//a[@class="srclink"]' '[src]'
+// @!has foo/struct.Bar.html '//*[@id="method.hidden"]//a[@class="srclink"]' '[src]'
+pub struct Bar;
+
+impl Bar {
+    pub fn shown(&self) {}
+
+    #[doc(html_no_source)]
+    pub fn hidden(&self) {}
+}
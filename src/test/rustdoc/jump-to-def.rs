@@ -0,0 +1,41 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --generate-link-to-definition
+
+#![crate_name = "foo"]
+
+// @has src/foo/jump-to-def.rs.html
+// @has - '//a[@href="../../foo/struct.Bar.html"]' 'Bar'
+// @has - '//a[@href="../../foo/inner/fn.baz.html"]' 'baz'
+// @has - '//a[@href="../../foo/trait.Qux.html"]' 'Qux'
+// @!has - '//a[@href="../../foo/struct.Private.html"]' 'Private'
+
+pub struct Bar;
+
+pub trait Qux {}
+
+impl Qux for Bar {}
+
+struct Private;
+
+pub mod inner {
+    pub fn baz() {}
+}
+
+pub fn make() -> Bar {
+    let _ = super_private();
+    inner::baz();
+    Bar
+}
+
+fn super_private() -> Private {
+    Private
+}
@@ -0,0 +1,33 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --link-summaries
+
+#![crate_name = "foo"]
+
+/// The bar type.
+///
+/// With more details.
+pub struct Bar;
+
+/// Undocumented links keep the link text as their title.
+pub struct Undocumented;
+
+// @has foo/struct.Foo.html
+// @has - '//*[@class="docblock"]//a[@title="The bar type."]' 'Bar'
+// @has - '//*[@class="docblock"]//a[@title="Undocumented"]' 'Undocumented'
+/// Links to [Bar] and [Undocumented].
+pub struct Foo;
+
+// @has foo/fn.make.html
+// @has - '//pre[@class="rust fn"]//a[@title="struct foo::Bar: The bar type."]' 'Bar'
+pub fn make() -> Bar {
+    Bar
+}
@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:link-to-def-src.rs
+// aux-build:link-to-def-no-src.rs
+// build-aux-docs
+// ignore-cross-compile
+// ignore-tidy-linelength
+// compile-flags: -Z unstable-options --generate-link-to-definition
+
+#![crate_name = "foo"]
+
+extern crate link_to_def_src;
+extern crate link_to_def_no_src;
+
+// @has src/foo/link-to-def-cross-crate.rs.html
+// @has - '//a[@href="../../src/link_to_def_src/link-to-def-src.rs.html#11"]' 'Foo'
+// @has - '//a[@href="../../src/link_to_def_src/link-to-def-src.rs.html#13"]' 'bar'
+// @has - '//a[@href="../../link_to_def_no_src/struct.Baz.html"]' 'Baz'
+
+pub fn make() -> link_to_def_src::Foo {
+    link_to_def_src::bar();
+    link_to_def_src::Foo
+}
+
+pub fn make_baz() -> link_to_def_no_src::Baz {
+    link_to_def_no_src::Baz
+}
@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:blanket-impls-dep.rs
+// ignore-cross-compile
+// compile-flags: -Z unstable-options --mask-crate blanket_impls_dep
+
+#![crate_name = "foo"]
+
+extern crate blanket_impls_dep;
+
+pub trait Local {}
+
+impl<T> Local for T {}
+
+// @has foo/struct.Foo.html
+// @has - '//*[@id="blanket-implementations-list"]//code' 'impl<T> Local for T'
+// @!has - '//*[@id="blanket-implementations-list"]//code' 'impl<T> Describe for T'
+// @!has - '//*[@class="sidebar"]//a' 'Describe'
+// @!has - '//*[@id="blanket-implementations-blanket_impls_dep"]' 'From blanket_impls_dep'
+pub struct Foo;
@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(rustc_attrs)]
+
+#![crate_name = "foo"]
+
+// @has foo/trait.Equal.html
+// @has - '//*[@class="stab must-implement"]' \
+//      'Implementations must define at least one of these methods: eq, ne.'
+// @has - '//*[@class="stab must-implement"]/a[@href="#method.eq"]' 'eq'
+// @has - '//*[@class="stab must-implement"]/a[@href="#method.ne"]' 'ne'
+#[rustc_must_implement_one_of(eq, ne)]
+pub trait Equal {
+    fn eq(&self, other: &Self) -> bool {
+        !self.ne(other)
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        !self.eq(other)
+    }
+}
+
+// @has foo/trait.Plain.html
+// @count - '//*[@class="stab must-implement"]' 0
+pub trait Plain {
+    fn plain(&self) {}
+}
@@ -20,3 +20,10 @@ impl !Send for Alpha {}
 
 // @matches negative_impl/struct.Bravo.html '//*[@class="impl"]//code' "impl<B> !Send for Bravo<B>"
 impl<B> !Send for Bravo<B> {}
+
+pub auto trait Local {}
+
+// @matches negative_impl/trait.Local.html '//*[@id="implementors-list"]//code' \
+//     "impl !Local for Alpha"
+// @matches negative_impl/struct.Alpha.html '//*[@class="impl"]//code' "impl !Local for Alpha"
+impl !Local for Alpha {}
@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --no-search
+
+#![crate_name = "foo"]
+
+// @!has search-index.js
+// @!has aliases.js
+// @has foo/index.html
+// @count - '//input[@class="search-input"]' 0
+// @!has - '//script/@src' 'search-index.js'
+// @!has - '//script/@src' 'aliases.js'
+// @!has - '//*[@id="help"]//h2' 'Search Tricks'
+// @has - '//*[@class="sidebar"]//a[@href="#structs"]' 'Structs'
+// @has foo/struct.Foo.html
+// @count - '//input[@class="search-input"]' 0
+// @!has - '//script/@src' 'search-index.js'
+
+/// Some struct.
+pub struct Foo;
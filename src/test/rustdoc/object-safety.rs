@@ -0,0 +1,38 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/trait.NotObjectSafe.html
+// @has - '//*[@id="object-safety"]' 'Object Safety'
+// @has - '//*[@class="docblock"]//li' 'method `generic` has generic type parameters'
+// @has - '//*[@class="docblock"]//li' 'method `returns_self` references the `Self` type'
+// @has - '//*[@class="docblock"]//li' 'method `no_receiver` has no receiver'
+// @has - '//*[@class="docblock"]//li' 'the trait cannot contain associated consts like `VALUE`'
+// @count - '//*[@class="docblock"]//li' 4
+pub trait NotObjectSafe {
+    const VALUE: u32;
+
+    fn generic<T>(&self, t: T);
+
+    fn returns_self(&self) -> Self;
+
+    fn no_receiver();
+
+    fn fine(&self);
+
+    fn excluded(&self) -> Self where Self: Sized;
+}
+
+// @has foo/trait.ObjectSafe.html
+// @!has - '//*[@id="object-safety"]' 'Object Safety'
+pub trait ObjectSafe {
+    fn method(&self);
+}
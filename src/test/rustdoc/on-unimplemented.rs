@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(on_unimplemented)]
+
+#![crate_name = "foo"]
+
+// @has foo/trait.Render.html
+// @has - '//*[@class="stab on-unimplemented"]' \
+//      "When a type doesn't implement this trait, the compiler says: \
+//      {Self} can't be rendered"
+#[rustc_on_unimplemented(message = "{Self} can't be rendered", label = "not renderable")]
+pub trait Render {}
+
+// @has foo/trait.Parse.html '//*[@class="stab on-unimplemented"]/q' 'unparseable'
+#[rustc_on_unimplemented = "unparseable"]
+pub trait Parse {}
+
+// @has foo/trait.Labelled.html '//*[@class="stab on-unimplemented"]/q' 'no label'
+#[rustc_on_unimplemented(label = "no label")]
+pub trait Labelled {}
+
+// @has foo/trait.Plain.html
+// @count - '//*[@class="stab on-unimplemented"]' 0
+pub trait Plain {}
@@ -0,0 +1,45 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+use std::ops::{Add, Neg};
+
+pub struct Meters(pub f64);
+
+// @has foo/struct.Meters.html
+// @has - '//*[@id="impl-Add%3Cf64%3E"]//code' 'impl Add<f64, Output = Meters> for Meters'
+impl Add<f64> for Meters {
+    type Output = Meters;
+
+    fn add(self, rhs: f64) -> Meters {
+        Meters(self.0 + rhs)
+    }
+}
+
+// @has - '//*[@id="impl-Neg"]//code' 'impl Neg<Output = Meters> for Meters'
+impl Neg for Meters {
+    type Output = Meters;
+
+    fn neg(self) -> Meters {
+        Meters(-self.0)
+    }
+}
+
+pub trait Output {
+    type Output;
+}
+
+// Only the operator traits get the inline binding.
+// @has - '//*[@id="impl-Output"]//code' 'impl Output for Meters'
+// @!has - '//*[@id="impl-Output"]//code' 'Output = Meters'
+impl Output for Meters {
+    type Output = Meters;
+}
@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:outlives-bounds.rs
+
+#![crate_name = "foo"]
+
+extern crate outlives_bounds;
+
+// @has foo/struct.Ref.html '//pre[@class="rust struct"]' "pub struct Ref<'a, T: 'a>"
+pub struct Ref<'a, T: 'a> {
+    pub inner: &'a T,
+}
+
+// @has foo/enum.Either.html '//pre[@class="rust enum"]' "pub enum Either<'a, 'b, T: 'a + 'b>"
+pub enum Either<'a, 'b, T: 'a + 'b> {
+    Left(&'a T),
+    Right(&'b T),
+}
+
+// @has foo/struct.Borrowed.html '//pre[@class="rust struct"]' "T: 'a"
+pub use outlives_bounds::Borrowed;
@@ -0,0 +1,55 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// no-prefer-dynamic
+// ignore-stage1
+
+#![crate_type = "proc-macro"]
+#![crate_name = "some_macros"]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+// @has some_macros/index.html
+// @has - '//h2' 'Macros'
+// @has - '//h2' 'Attribute Macros'
+// @has - '//h2' 'Derive Macros'
+// @!has - '//h2' 'Functions'
+
+// @has some_macros/macro.some_proc_macro.html
+// @has - '//h1' 'Macro some_macros::some_proc_macro'
+// @has - '//pre' 'some_proc_macro!() { /* proc-macro */ }'
+/// Makes some tokens.
+#[proc_macro]
+pub fn some_proc_macro(item: TokenStream) -> TokenStream {
+    item
+}
+
+// @has some_macros/attr.some_proc_attr.html
+// @has - '//h1' 'Attribute Macro some_macros::some_proc_attr'
+// @has - '//pre' '#[some_proc_attr]'
+/// Rewrites the item it's put on.
+#[proc_macro_attribute]
+pub fn some_proc_attr(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+// @has some_macros/derive.SomeDerive.html
+// @has - '//h1' 'Derive Macro some_macros::SomeDerive'
+// @has - '//pre' '#[derive(SomeDerive)]'
+// @has - '//pre' '#[some_helper]'
+// @has - '//pre' '#[other_helper]'
+// @!has some_macros/fn.some_derive.html
+/// Derives something.
+#[proc_macro_derive(SomeDerive, attributes(some_helper, other_helper))]
+pub fn some_derive(item: TokenStream) -> TokenStream {
+    item
+}
@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+use std::ops::Add;
+
+pub trait Make {
+    type Output;
+
+    // @has foo/trait.Make.html '//*[@id="tymethod.make"]//a[@class="type"]/@href' \
+    //      '#associatedtype.Output'
+    // @has - '//*[@id="tymethod.make"]//code' 'fn make(&self) -> Self::Output'
+    fn make(&self) -> Self::Output;
+}
+
+pub struct Foo;
+
+// @has foo/struct.Foo.html '//*[@id="method.add"]//a[@class="type"]/@href' \
+//      'trait.Add.html#associatedtype.Output'
+// @has - '//*[@id="method.add"]//code' 'fn add(self, _: Foo) -> Self::Output'
+impl Add for Foo {
+    type Output = Foo;
+    fn add(self, _: Foo) -> Self::Output { Foo }
+}
+
+// @has - '//*[@id="method.make"]//a[@class="type"]/@href' \
+//      'trait.Make.html#associatedtype.Output'
+impl Make for Foo {
+    type Output = u8;
+    fn make(&self) -> Self::Output { 0 }
+}
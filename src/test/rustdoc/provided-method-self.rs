@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Merge: Sized {
+    fn merge(self, other: Self) -> Self;
+
+    fn merge_all(self, others: Vec<Self>) -> Self {
+        others.into_iter().fold(self, Merge::merge)
+    }
+
+    fn merge_ref(&self, _other: &Self) -> Option<Self> {
+        None
+    }
+}
+
+// @has foo/struct.Foo.html
+// @has - '//*[@id="method.merge_all"]//code' 'fn merge_all(self, others: Vec<Foo>) -> Foo'
+// @has - '//*[@id="method.merge_ref"]//code' 'fn merge_ref(&self, _other: &Foo) -> Option<Foo>'
+// @!has - '//*[@id="method.merge_all"]//code' 'Vec<Self>'
+pub struct Foo;
+
+impl Merge for Foo {
+    fn merge(self, _other: Foo) -> Foo {
+        Foo
+    }
+}
+
+// The trait page keeps the generic signature.
+// @has foo/trait.Merge.html
+// @has - '//*[@id="method.merge_all"]//code' 'fn merge_all(self, others: Vec<Self>) -> Self'
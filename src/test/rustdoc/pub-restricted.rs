@@ -42,3 +42,29 @@ mod a {
         pub(in a::b) struct FooInAB;
     }
 }
+
+// @has 'foo/struct.Fields.html' '//pre' 'pub(crate) krate: u8'
+// @has - '//pre' 'pub(self) this: u8'
+// @has - '//*[@id="structfield.krate"]//code' 'pub(crate) krate: u8'
+// @has - '//*[@id="structfield.this"]//code' 'pub(self) this: u8'
+// @has - '//*[@id="structfield.public"]//code' 'public: u8'
+// @!has - '//*[@id="structfield.public"]//code' 'pub public: u8'
+// @has - '//*[@id="structfield.private"]//code' 'private: u8'
+pub struct Fields {
+    pub(crate) krate: u8,
+    pub(self) this: u8,
+    pub public: u8,
+    private: u8,
+}
+
+impl Fields {
+    // @has 'foo/struct.Fields.html' '//code' 'pub(crate) fn new() -> Fields'
+    pub(crate) fn new() -> Fields {
+        Fields { krate: 0, this: 0, public: 0, private: 0 }
+    }
+}
+
+// @has 'foo/union.Union.html' '//*[@id="structfield.field"]//code' 'pub(crate) field: u8'
+pub union Union {
+    pub(crate) field: u8,
+}
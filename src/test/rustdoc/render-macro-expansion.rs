@@ -0,0 +1,34 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --render-macro-expansion
+
+#![crate_name = "foo"]
+
+macro_rules! make_struct {
+    ($name:ident, $field:ident: $ty:ty) => {
+        /// Made by a macro.
+        pub struct $name {
+            pub $field: $ty,
+        }
+    }
+}
+
+// @has foo/struct.Generated.html
+// @has - '//a[@class="srclink"]/@href' '#macro-expansion'
+// @has - '//h2[@id="macro-expansion"]' 'Macro Expansion'
+// @has - '//pre[@class="rust macro-expansion"]' 'pub struct Generated {'
+// @has - '//pre[@class="rust macro-expansion"]' 'pub count: u32,'
+make_struct!(Generated, count: u32);
+
+// @has foo/struct.Written.html
+// @!has - '//h2[@id="macro-expansion"]' 'Macro Expansion'
+// @!has - '//a[@class="srclink"]/@href' '#macro-expansion'
+pub struct Written;
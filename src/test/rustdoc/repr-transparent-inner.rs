@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+use std::marker::PhantomData;
+
+// @has foo/struct.Meters.html
+// @has - '//*[@class="stab transparent"]' 'it has the same layout as f64'
+#[repr(transparent)]
+pub struct Meters(f64);
+
+// @has foo/struct.Wrapper.html
+// @has - '//*[@class="stab transparent"]' 'it has the same layout as Vec<T>'
+#[repr(transparent)]
+pub struct Wrapper<T> {
+    pub inner: Vec<T>,
+    marker: PhantomData<T>,
+}
+
+// @has foo/struct.Plain.html
+// @count - '//*[@class="stab transparent"]' 0
+pub struct Plain(pub f64);
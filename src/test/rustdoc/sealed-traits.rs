@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --detect-sealed-traits
+
+#![crate_name = "foo"]
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub trait Public {}
+
+// @has foo/trait.Sealed.html
+// @has - '//*[@class="stab sealed"]' 'This trait is sealed and cannot be implemented outside \
+//      this crate.'
+pub trait Sealed: private::Sealed {}
+
+// @has foo/trait.SealedByWhereClause.html
+// @has - '//*[@class="stab sealed"]' 'This trait is sealed'
+pub trait SealedByWhereClause where Self: private::Sealed {}
+
+// @has foo/trait.Open.html
+// @count - '//*[@class="stab sealed"]' 0
+pub trait Open: Public + Clone {}
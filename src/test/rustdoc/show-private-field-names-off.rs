@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Mixed.html
+// @has - '//pre[@class="rust struct"]' '// some fields omitted'
+// @!has - '//pre[@class="rust struct"]' 'private fields'
+// @!has - '//pre[@class="rust struct"]' 'len'
+pub struct Mixed {
+    pub ptr: *const u8,
+    len: usize,
+}
+
+// @has foo/struct.Opaque.html
+// @has - '//pre[@class="rust struct"]' '{ /* fields omitted */ }'
+// @!has - '//pre[@class="rust struct"]' 'inner'
+pub struct Opaque {
+    inner: u32,
+}
@@ -0,0 +1,47 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --show-private-field-names
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Mixed.html
+// @has - '//pre[@class="rust struct"]' '// private fields: len, cap'
+// @!has - '//pre[@class="rust struct"]' 'some fields omitted'
+// @!has - '//pre[@class="rust struct"]' 'u64'
+pub struct Mixed {
+    pub ptr: *const u8,
+    len: usize,
+    cap: u64,
+}
+
+// @has foo/struct.Opaque.html
+// @has - '//pre[@class="rust struct"]' '{ /* private fields: inner */ }'
+pub struct Opaque {
+    inner: u32,
+}
+
+// @has foo/struct.Hidden.html
+// @has - '//pre[@class="rust struct"]' '// private fields: secret'
+// @has - '//pre[@class="rust struct"]' '// some fields omitted'
+// @!has - '//pre[@class="rust struct"]' 'internal'
+pub struct Hidden {
+    pub visible: u8,
+    secret: u8,
+    #[doc(hidden)]
+    pub internal: u8,
+}
+
+// @has foo/union.Bits.html
+// @has - '//pre[@class="rust union"]' '// private fields: raw'
+pub union Bits {
+    pub float: f32,
+    raw: u32,
+}
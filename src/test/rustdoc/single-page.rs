@@ -0,0 +1,63 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --single-page
+
+#![crate_name = "foo"]
+
+// @has foo/index.html
+// @!has foo/struct.Bar.html
+// @!has foo/inner/index.html
+// @!has foo/inner/fn.baz.html
+
+// @has - '//section[@id="mod.foo"]//h1' 'Crate foo'
+// @has - '//section[@id="mod.foo"]//a[@href="#struct.foo::Bar"]' 'Bar'
+// @has - '//section[@id="mod.foo"]//a[@href="#mod.foo::inner"]' 'inner'
+// @has - '//section[@id="struct.foo::Bar"]//h1' 'Struct foo::Bar'
+// @has - '//section[@id="mod.foo::inner"]//h1' 'Module foo::inner'
+// @has - '//section[@id="mod.foo::inner"]//a[@href="#fn.foo::inner::baz"]' 'baz'
+// @has - '//section[@id="fn.foo::inner::baz"]//h1/a[@href="#mod.foo::inner"]' 'inner'
+// @has - '//section[@id="fn.foo::inner::baz"]//pre//a[@href="#struct.foo::Bar"]' 'Bar'
+// @has - '//section[@id="fn.foo::inner::baz"]//p/a[@href="#struct.foo::Bar"]' 'Bar'
+// @has - '//section[@id="fn.foo::inner::baz"]//p/a[@href="#struct.foo::Bar.method.new"]' 'new'
+
+// The ids of the sections are the ones of the pages of their items, scoped under the section.
+// @has - '//section[@id="struct.foo::Bar"]//h4[@id="struct.foo::Bar.method.new"]' 'fn new()'
+// @has - '//section[@id="struct.foo::Bar"]//a[@href="#struct.foo::Bar.method.new"]' 'new'
+// @has - '//section[@id="struct.foo::Qux"]//h4[@id="struct.foo::Qux.method.new"]' 'fn new()'
+// @!has - '//h4[@id="method.new-1"]'
+
+// The implementors of the traits of other crates link to the single page.
+// @has implementors/core/clone/trait.Clone.js 'foo/index.html#struct.foo::Bar'
+/// A struct.
+#[derive(Clone)]
+pub struct Bar;
+
+impl Bar {
+    pub fn new() -> Bar {
+        Bar
+    }
+}
+
+/// Another struct.
+pub struct Qux;
+
+impl Qux {
+    pub fn new() -> Qux {
+        Qux
+    }
+}
+
+pub mod inner {
+    /// Makes a [`Bar`](../struct.Bar.html), like [`new`](../struct.Bar.html#method.new).
+    pub fn baz() -> ::Bar {
+        ::Bar
+    }
+}
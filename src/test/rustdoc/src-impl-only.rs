@@ -0,0 +1,19 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// The only items of `impls.rs` are impls, which the cache takes out of the crate.
+
+// @has src/foo/src-impl-only/impls.rs.html
+// @has foo/struct.Widget.html '//a/@href' '../src/foo/src-impl-only/impls.rs.html#13'
+pub struct Widget;
+
+include!("src-impl-only/impls.rs");
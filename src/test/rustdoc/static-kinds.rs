@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+#![feature(thread_local)]
+
+// @has foo/static.PLAIN.html
+// @has - '//pre[@class="rust static"]' 'pub static PLAIN: u32'
+// @count - '//*[@class="stab thread-local"]' 0
+// @count - '//*[@class="stab mutable"]' 0
+pub static PLAIN: u32 = 0;
+
+// @has foo/static.COUNTER.html
+// @has - '//pre[@class="rust static"]' 'pub static mut COUNTER: u32'
+// @has - '//*[@class="stab mutable"]' 'This static is mutable'
+// @count - '//*[@class="stab thread-local"]' 0
+pub static mut COUNTER: u32 = 0;
+
+// @has foo/static.PER_THREAD.html
+// @has - '//pre[@class="rust static"]' '#[thread_local]'
+// @has - '//pre[@class="rust static"]' 'pub static PER_THREAD: u32'
+// @has - '//*[@class="stab thread-local"]' 'This static is thread-local'
+#[thread_local]
+pub static PER_THREAD: u32 = 0;
+
+// @has foo/index.html
+// @has - '//a[@title="mutable static"]' '⚠'
+// @count - '//a[@title="mutable static"]' 1
@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --summary-length 20
+
+#![crate_name = "foo"]
+
+// @has foo/index.html '//*[@class="docblock-short"]' 'Some words that do…'
+// @!has - '//*[@class="docblock-short"]' 'not fit'
+/// Some words that do not fit in the summary.
+pub struct Long;
+
+// @has foo/index.html '//*[@class="docblock-short"]' 'First sentence.'
+// @!has - '//*[@class="docblock-short"]' 'Second'
+/// First sentence. Second sentence.
+pub struct Sentences;
+
+// @has foo/index.html '//*[@class="docblock-short"]' 'Fits in the summary.'
+/// Fits in the summary.
+pub struct Short;
+
+// The markup doesn't count towards the length of the summary.
+// @has foo/index.html '//*[@class="docblock-short"]' 'Uses Vec to store…'
+/// Uses [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html) to store many words.
+pub struct Links;
@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(trait_alias)]
+#![crate_name = "foo"]
+
+pub trait Bar {}
+pub trait Baz {}
+
+// @has foo/traitalias.Foo.html
+// @has - '//pre[@class="rust trait-alias"]' 'trait Foo = Bar + Baz;'
+// @has - '//pre[@class="rust trait-alias"]//a[@href="../foo/trait.Bar.html"]' 'Bar'
+// @has - '//pre[@class="rust trait-alias"]//a[@href="../foo/trait.Baz.html"]' 'Baz'
+/// Both `Bar` and `Baz`.
+pub trait Foo = Bar + Baz;
+
+// @has foo/index.html '//a[@class="traitalias"]' 'Foo'
+// @has foo/all.html '//a[@href="traitalias.Foo.html"]' 'Foo'
+
+// @has foo/fn.takes_foo.html '//a[@href="../foo/traitalias.Foo.html"]' 'Foo'
+/// Takes anything implementing [`Foo`].
+pub fn takes_foo<T: Foo>(_: T) {}
+
+pub trait Convert<T> {}
+
+// @has foo/traitalias.Into.html '//pre[@class="rust trait-alias"]' \
+//      'trait Into<T> = Convert<T> where T: Bar;'
+pub trait Into<T> = Convert<T> where T: Bar;
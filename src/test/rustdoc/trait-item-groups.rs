@@ -0,0 +1,42 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/trait.Parser.html
+// @has - '//pre[@class="rust trait"]/span[@class="comment"][1]' '// Associated types'
+// @has - '//pre[@class="rust trait"]/span[@class="comment"][2]' '// Required methods'
+// @has - '//pre[@class="rust trait"]/span[@class="comment"][3]' '// Provided methods'
+// @count - '//pre[@class="rust trait"]/span[@class="comment"]' 3
+// @has - '//h2[@id="associated-types"]' 'Associated Types'
+// @has - '//h2[@id="required-methods"]' 'Required Methods'
+// @has - '//h2[@id="provided-methods"]' 'Provided Methods'
+// @has - '//*[@id="associatedtype.Output"]' 'type Output'
+// @has - '//*[@id="tymethod.parse"]' 'fn parse(&self, input: &str) -> Self::Output'
+// @has - '//*[@id="method.parse_all"]' 'fn parse_all(&self, inputs: &[&str]) -> Vec<Self::Output>'
+// @has - '//*[@class="sidebar-title"][@href="#associated-types"]' 'Associated Types'
+// @has - '//*[@class="sidebar-title"][@href="#required-methods"]' 'Required Methods'
+// @has - '//*[@class="sidebar-title"][@href="#provided-methods"]' 'Provided Methods'
+pub trait Parser {
+    type Output;
+
+    fn parse(&self, input: &str) -> Self::Output;
+
+    fn parse_all(&self, inputs: &[&str]) -> Vec<Self::Output> {
+        inputs.iter().map(|input| self.parse(input)).collect()
+    }
+}
+
+// @has foo/trait.Single.html
+// @has - '//pre[@class="rust trait"]/span[@class="comment"]' '// Required methods'
+// @count - '//pre[@class="rust trait"]/span[@class="comment"]' 1
+pub trait Single {
+    fn single(&self);
+}
@@ -0,0 +1,36 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z unstable-options --trim-auto-traits
+
+#![crate_name = "foo"]
+
+// @has foo/struct.Plain.html
+// @has - '//*[@id="synthetic-implementations-list"]/details[@class="auto-traits"]/summary' \
+//      'Implements Send + Sync'
+// @has - '//*[@id="synthetic-implementations-list"]/details//h3//code' 'impl Send for Plain'
+pub struct Plain {
+    pub value: u32,
+}
+
+// @has foo/struct.Generic.html
+// @count - '//*[@id="synthetic-implementations-list"]/details' 0
+// @has - '//*[@id="synthetic-implementations-list"]/h3//code' \
+//      'impl<T> Send for Generic<T> where T: Send'
+pub struct Generic<T> {
+    pub value: T,
+}
+
+// @has foo/struct.Pointer.html
+// @count - '//*[@id="synthetic-implementations-list"]/details' 0
+// @has - '//*[@id="synthetic-implementations-list"]/h3//code' 'impl !Send for Pointer'
+pub struct Pointer {
+    pub value: *const u8,
+}
@@ -0,0 +1,33 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/trait.Zeroable.html '//pre[@class="rust trait"]' 'pub unsafe trait Zeroable'
+pub unsafe trait Zeroable {}
+
+pub struct Bytes;
+
+// @has foo/struct.Bytes.html '//*[@class="impl"]//code' 'unsafe impl Zeroable for Bytes'
+// @has foo/trait.Zeroable.html '//*[@id="implementors-list"]//code' \
+//      'unsafe impl Zeroable for Bytes'
+unsafe impl Zeroable for Bytes {}
+
+pub unsafe trait Pod {}
+
+// @has foo/struct.Bytes.html '//*[@id="blanket-implementations-list"]//code' \
+//      'unsafe impl<T> Pod for T'
+unsafe impl<T: Zeroable> Pod for T {}
+
+pub trait Safe {}
+
+// @has foo/struct.Bytes.html '//*[@class="impl"]//code' 'impl Safe for Bytes'
+// @!has - '//*[@class="impl"]//code' 'unsafe impl Safe for Bytes'
+impl Safe for Bytes {}
@@ -0,0 +1,24 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+// @has foo/fn.fill.html '//pre[@class="rust fn"]' '[u8; 4]: Default'
+pub fn fill() -> [u8; 4] where [u8; 4]: Default {
+    Default::default()
+}
+
+// @has foo/struct.Buffer.html '//pre[@class="rust struct"]' '[(); 16]: Sized'
+pub struct Buffer<T> where [(); 16]: Sized {
+    pub data: T,
+}
+
+// @has foo/trait.Chunk.html '//pre[@class="rust trait"]' '[T; 2]: Copy'
+pub trait Chunk<T> where [T; 2]: Copy {}
@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub const SIZE: usize = 4;
+
+pub trait IsTrue {}
+
+// @has foo/fn.double.html '//pre[@class="rust fn"]' '[u8; 8]: Default'
+pub fn double() -> [u8; SIZE * 2] where [u8; SIZE * 2]: Default {
+    Default::default()
+}
+
+// @has foo/struct.Checked.html '//pre[@class="rust struct"]' '[(); 1]: IsTrue'
+pub struct Checked<T> where [(); (SIZE > 0) as usize]: IsTrue {
+    pub data: T,
+}
+
+impl IsTrue for [(); 1] {}
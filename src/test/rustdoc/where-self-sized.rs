@@ -0,0 +1,66 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "foo"]
+
+pub trait Shape {
+    // @has foo/trait.Shape.html '//*[@id="tymethod.area"]' 'fn area(&self) -> f64'
+    // @!has - '//*[@id="tymethod.area"]/following-sibling::div[1][@class="stability"]' \
+    //      'not available on trait objects'
+    fn area(&self) -> f64;
+
+    // @has - '//*[@id="tymethod.into_boxed"]/following-sibling::div[1][@class="stability"]' \
+    //      'This method is not available on trait objects because it requires Self: Sized.'
+    fn into_boxed(self) -> Box<Self> where Self: Sized;
+
+    // @has - '//*[@id="method.scaled"]/following-sibling::div[1][@class="stability"]' \
+    //      'This method is not available on trait objects because it requires Self: Sized.'
+    fn scaled(self, _factor: f64) -> Self where Self: Sized {
+        self
+    }
+}
+
+pub struct Square(pub f64);
+
+// @has foo/struct.Square.html '//*[@id="method.into_boxed"]/following-sibling::div[1]' \
+//      'This method is not available on trait objects because it requires Self: Sized.'
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn into_boxed(self) -> Box<Self> {
+        Box::new(self)
+    }
+}
+
+// @has foo/struct.Circle.html '//*[@id="method.into_boxed"]/following-sibling::div[1]' \
+//      'This method is not available on trait objects because it requires Self: Sized.'
+// @has - '//div[@class="stab sized-self"]' 'not available on trait objects'
+// @!has - '//div[@class="stab portability"]' 'not available on trait objects'
+pub struct Circle(pub f64);
+
+// The inherent methods are never called on trait objects.
+// @!has - '//*[@id="method.consume"]/following-sibling::div[1]' 'not available on trait objects'
+impl Circle {
+    pub fn consume(self) -> f64 where Self: Sized {
+        self.0
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        self.0 * self.0 * 3.14
+    }
+
+    fn into_boxed(self) -> Box<Self> {
+        Box::new(self)
+    }
+}
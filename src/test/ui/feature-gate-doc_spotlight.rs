@@ -11,4 +11,7 @@
 #[doc(spotlight)] //~ ERROR: #[doc(spotlight)] is experimental
 trait SomeTrait {}
 
+#[doc(notable_trait)] //~ ERROR: #[doc(notable_trait)] is experimental
+trait OtherTrait {}
+
 fn main() {}